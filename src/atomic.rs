@@ -0,0 +1,264 @@
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+
+/// Increment of the SplitMix64 state.
+const PHI: u64 = 0x9e3779b97f4a7c15;
+
+/// SplitMix64 RNG with an atomic state, usable through shared references.
+///
+/// Every output advances the state using a single atomic addition, so
+/// `&AtomicSplitMix64` can be used from many threads concurrently without
+/// locking. The outputs of a single thread match the `SplitMix64` RNG of
+/// `rand_xoshiro`, the interleaving between threads is unspecified.
+///
+/// Both `AtomicSplitMix64` and `&AtomicSplitMix64` implement the RNG traits of
+/// every selected `rand_core`/`rand` version, and `AtomicSplitMix64`
+/// implements their seedable RNG traits. It is not a crypto RNG.
+#[derive(Debug, Default)]
+pub struct AtomicSplitMix64 {
+    x: AtomicU64,
+}
+
+impl AtomicSplitMix64 {
+    /// Create the RNG with the given state.
+    pub const fn new(state: u64) -> AtomicSplitMix64 {
+        AtomicSplitMix64 {
+            x: AtomicU64::new(state),
+        }
+    }
+
+    fn next_state(&self) -> u64 {
+        self.x.fetch_add(PHI, Ordering::Relaxed).wrapping_add(PHI)
+    }
+
+    fn next_u32(&self) -> u32 {
+        let mut z = self.next_state();
+        z = (z ^ (z >> 33)).wrapping_mul(0x62A9D9ED799705F5);
+        z = (z ^ (z >> 28)).wrapping_mul(0xCB24D0A5C88C35B3);
+        (z >> 32) as u32
+    }
+
+    fn next_u64(&self) -> u64 {
+        let mut z = self.next_state();
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Fill `dst` like `rand_core::impls::fill_bytes_via_next`.
+    fn fill_bytes(&self, dst: &mut [u8]) {
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let rest = chunks.into_remainder();
+        if rest.len() > 4 {
+            rest.copy_from_slice(&self.next_u64().to_le_bytes()[..rest.len()]);
+        } else if !rest.is_empty() {
+            rest.copy_from_slice(&self.next_u32().to_le_bytes()[..rest.len()]);
+        }
+    }
+
+    fn from_seed(seed: [u8; 8]) -> AtomicSplitMix64 {
+        AtomicSplitMix64::new(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> AtomicSplitMix64 {
+        AtomicSplitMix64::new(state)
+    }
+}
+
+impl Clone for AtomicSplitMix64 {
+    fn clone(&self) -> AtomicSplitMix64 {
+        AtomicSplitMix64::new(self.x.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::AtomicSplitMix64;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for &AtomicSplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            AtomicSplitMix64::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            AtomicSplitMix64::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            AtomicSplitMix64::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            AtomicSplitMix64::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for AtomicSplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            AtomicSplitMix64::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            AtomicSplitMix64::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            AtomicSplitMix64::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            AtomicSplitMix64::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for AtomicSplitMix64 {
+        type Seed = [u8; 8];
+        fn from_seed(seed: [u8; 8]) -> AtomicSplitMix64 {
+            AtomicSplitMix64::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> AtomicSplitMix64 {
+            AtomicSplitMix64::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::AtomicSplitMix64;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for &AtomicSplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            AtomicSplitMix64::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            AtomicSplitMix64::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            AtomicSplitMix64::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            AtomicSplitMix64::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for AtomicSplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            AtomicSplitMix64::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            AtomicSplitMix64::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            AtomicSplitMix64::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            AtomicSplitMix64::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for AtomicSplitMix64 {
+        type Seed = [u8; 8];
+        fn from_seed(seed: [u8; 8]) -> AtomicSplitMix64 {
+            AtomicSplitMix64::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> AtomicSplitMix64 {
+            AtomicSplitMix64::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::AtomicSplitMix64;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for &AtomicSplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            AtomicSplitMix64::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            AtomicSplitMix64::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            AtomicSplitMix64::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for AtomicSplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            AtomicSplitMix64::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            AtomicSplitMix64::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            AtomicSplitMix64::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for AtomicSplitMix64 {
+        type Seed = [u8; 8];
+        fn from_seed(seed: [u8; 8]) -> AtomicSplitMix64 {
+            AtomicSplitMix64::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> AtomicSplitMix64 {
+            AtomicSplitMix64::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::AtomicSplitMix64;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for &AtomicSplitMix64 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(AtomicSplitMix64::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(AtomicSplitMix64::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            AtomicSplitMix64::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for AtomicSplitMix64 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(AtomicSplitMix64::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(AtomicSplitMix64::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            AtomicSplitMix64::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for AtomicSplitMix64 {
+        type Seed = [u8; 8];
+        fn from_seed(seed: [u8; 8]) -> AtomicSplitMix64 {
+            AtomicSplitMix64::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> AtomicSplitMix64 {
+            AtomicSplitMix64::seed_from_u64(state)
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use core::fmt;
+use serde::Serialize;
+use serde::ser;
+use serde::ser::Impossible;
+
+/// Return the `N` unsigned integers making up the serialized form of `value`,
+/// in order.
+///
+/// Panics if `value` doesn't serialize as exactly `N` unsigned integers,
+/// possibly nested in structs and tuples.
+pub(crate) fn words<T: Serialize, const N: usize>(value: &T) -> [u128; N] {
+    let mut capture = Capture {
+        words: [0; N],
+        len: 0,
+    };
+    value
+        .serialize(&mut capture)
+        .expect("RNG state should serialize as unsigned integers");
+    assert_eq!(capture.len, N, "RNG state should have {} words", N);
+    capture.words
+}
+
+/// Serializer recording unsigned integers.
+struct Capture<const N: usize> {
+    words: [u128; N],
+    len: usize,
+}
+
+impl<const N: usize> Capture<N> {
+    fn push(&mut self, word: u128) -> Result<(), Unsupported> {
+        *self.words.get_mut(self.len).ok_or(Unsupported)? = word;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+/// A value other than an unsigned integer, struct or tuple was serialized.
+#[derive(Debug)]
+struct Unsupported;
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unsupported value in RNG state")
+    }
+}
+
+impl ser::StdError for Unsupported {}
+
+impl ser::Error for Unsupported {
+    fn custom<T: fmt::Display>(_msg: T) -> Unsupported {
+        Unsupported
+    }
+}
+
+impl<const N: usize> ser::Serializer for &mut Capture<N> {
+    type Ok = ();
+    type Error = Unsupported;
+    type SerializeSeq = Impossible<(), Unsupported>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Impossible<(), Unsupported>;
+    type SerializeMap = Impossible<(), Unsupported>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), Unsupported>;
+
+    fn serialize_u8(self, v: u8) -> Result<(), Unsupported> {
+        self.push(v.into())
+    }
+    fn serialize_u16(self, v: u16) -> Result<(), Unsupported> {
+        self.push(v.into())
+    }
+    fn serialize_u32(self, v: u32) -> Result<(), Unsupported> {
+        self.push(v.into())
+    }
+    fn serialize_u64(self, v: u64) -> Result<(), Unsupported> {
+        self.push(v.into())
+    }
+    fn serialize_u128(self, v: u128) -> Result<(), Unsupported> {
+        self.push(v)
+    }
+    fn serialize_tuple(self, _len: usize) -> Result<Self, Unsupported> {
+        Ok(self)
+    }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, Unsupported> {
+        Ok(self)
+    }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, Unsupported> {
+        Ok(self)
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Unsupported> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_i8(self, _v: i8) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_i16(self, _v: i16) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_i32(self, _v: i32) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_i64(self, _v: i64) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_f32(self, _v: f32) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_f64(self, _v: f64) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_char(self, _v: char) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_str(self, _v: &str) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_bytes(self, _v: &[u8]) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_none(self) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_unit(self) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Unsupported> {
+        Err(Unsupported)
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Unsupported> {
+        Err(Unsupported)
+    }
+    fn collect_str<T: ?Sized + fmt::Display>(self, _value: &T) -> Result<(), Unsupported> {
+        Err(Unsupported)
+    }
+}
+
+impl<const N: usize> ser::SerializeTuple for &mut Capture<N> {
+    type Ok = ();
+    type Error = Unsupported;
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Unsupported> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Unsupported> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> ser::SerializeTupleStruct for &mut Capture<N> {
+    type Ok = ();
+    type Error = Unsupported;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Unsupported> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Unsupported> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> ser::SerializeStruct for &mut Capture<N> {
+    type Ok = ();
+    type Error = Unsupported;
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Unsupported> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Unsupported> {
+        Ok(())
+    }
+}
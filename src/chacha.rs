@@ -0,0 +1,300 @@
+/// State of a ChaCha RNG of `rand_chacha`, independent of its version.
+///
+/// It consists of the seed, the stream and the position in the stream in
+/// 32-bit words, which determine all further output. It can be extracted from
+/// the `ChaCha8Rng`, `ChaCha12Rng` and `ChaCha20Rng` of every selected
+/// `rand_chacha` version and converted back into them, so that a checkpoint
+/// taken with one version can be resumed with another one, e.g. from the RNG
+/// wrapped by a [`Rng06`](crate::Rng06) into a `rand_chacha 0.9` RNG. The state
+/// doesn't record the number of rounds, so it must be restored into the same
+/// variant.
+///
+/// `rand_chacha 0.2` doesn't expose the seed and the stream of its RNGs, so
+/// there is no conversion from them. Use [`ChaChaState::new`] with the seed and
+/// the stream the RNG was created with and its `get_word_pos()` instead. Before
+/// the first output, `get_word_pos()` of `rand_chacha 0.2` overflows; the
+/// position is 0 then.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChaChaState {
+    seed: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+}
+
+impl ChaChaState {
+    /// Create the state from a seed, a stream and a position in the stream
+    /// in 32-bit words.
+    ///
+    /// Only the lower 68 bits of the position are used, the generators cycle
+    /// after 2<sup>68</sup> words.
+    pub fn new(seed: [u8; 32], stream: u64, word_pos: u128) -> ChaChaState {
+        ChaChaState {
+            seed,
+            stream,
+            word_pos: word_pos & ((1 << 68) - 1),
+        }
+    }
+
+    /// Return the seed.
+    pub fn seed(&self) -> [u8; 32] {
+        self.seed
+    }
+
+    /// Return the stream.
+    pub fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Return the position in the stream in 32-bit words.
+    pub fn word_pos(&self) -> u128 {
+        self.word_pos
+    }
+}
+
+/// Serialize the state as the tuple of the seed, the stream and the word
+/// position.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChaChaState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.seed, self.stream, self.word_pos), serializer)
+    }
+}
+
+/// Deserialize the state from the tuple of the seed, the stream and the word
+/// position.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChaChaState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<ChaChaState, D::Error> {
+        let (seed, stream, word_pos): ([u8; 32], u64, u128) =
+            serde::Deserialize::deserialize(deserializer)?;
+        Ok(ChaChaState::new(seed, stream, word_pos))
+    }
+}
+
+#[cfg(feature = "rand_chacha_0_2")]
+mod v0_2 {
+    use super::ChaChaState;
+    use rand_chacha_0_2::ChaCha8Rng;
+    use rand_chacha_0_2::ChaCha12Rng;
+    use rand_chacha_0_2::ChaCha20Rng;
+    use rand_core_0_5::SeedableRng;
+
+    /// Restore a `rand_chacha 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<ChaChaState> for ChaCha8Rng {
+        fn from(state: ChaChaState) -> ChaCha8Rng {
+            let mut rng = ChaCha8Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Restore a `rand_chacha 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<ChaChaState> for ChaCha12Rng {
+        fn from(state: ChaChaState) -> ChaCha12Rng {
+            let mut rng = ChaCha12Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Restore a `rand_chacha 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<ChaChaState> for ChaCha20Rng {
+        fn from(state: ChaChaState) -> ChaCha20Rng {
+            let mut rng = ChaCha20Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+}
+
+#[cfg(feature = "rand_chacha_0_3")]
+mod v0_3 {
+    use super::ChaChaState;
+    use rand_chacha_0_3::ChaCha8Rng;
+    use rand_chacha_0_3::ChaCha12Rng;
+    use rand_chacha_0_3::ChaCha20Rng;
+    use rand_core_0_6::SeedableRng;
+
+    /// Extract the state of a `rand_chacha 0.3` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&ChaCha8Rng> for ChaChaState {
+        fn from(rng: &ChaCha8Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<ChaChaState> for ChaCha8Rng {
+        fn from(state: ChaChaState) -> ChaCha8Rng {
+            let mut rng = ChaCha8Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Extract the state of a `rand_chacha 0.3` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&ChaCha12Rng> for ChaChaState {
+        fn from(rng: &ChaCha12Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<ChaChaState> for ChaCha12Rng {
+        fn from(state: ChaChaState) -> ChaCha12Rng {
+            let mut rng = ChaCha12Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Extract the state of a `rand_chacha 0.3` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&ChaCha20Rng> for ChaChaState {
+        fn from(rng: &ChaCha20Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<ChaChaState> for ChaCha20Rng {
+        fn from(state: ChaChaState) -> ChaCha20Rng {
+            let mut rng = ChaCha20Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+}
+
+#[cfg(feature = "rand_chacha_0_9")]
+mod v0_9 {
+    use super::ChaChaState;
+    use rand_chacha_0_9::ChaCha8Rng;
+    use rand_chacha_0_9::ChaCha12Rng;
+    use rand_chacha_0_9::ChaCha20Rng;
+    use rand_core_0_9::SeedableRng;
+
+    /// Extract the state of a `rand_chacha 0.9` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&ChaCha8Rng> for ChaChaState {
+        fn from(rng: &ChaCha8Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<ChaChaState> for ChaCha8Rng {
+        fn from(state: ChaChaState) -> ChaCha8Rng {
+            let mut rng = ChaCha8Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Extract the state of a `rand_chacha 0.9` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&ChaCha12Rng> for ChaChaState {
+        fn from(rng: &ChaCha12Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<ChaChaState> for ChaCha12Rng {
+        fn from(state: ChaChaState) -> ChaCha12Rng {
+            let mut rng = ChaCha12Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Extract the state of a `rand_chacha 0.9` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&ChaCha20Rng> for ChaChaState {
+        fn from(rng: &ChaCha20Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<ChaChaState> for ChaCha20Rng {
+        fn from(state: ChaChaState) -> ChaCha20Rng {
+            let mut rng = ChaCha20Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+}
+
+#[cfg(feature = "rand_chacha_0_10")]
+mod v0_10 {
+    use super::ChaChaState;
+    use rand_chacha_0_10::ChaCha8Rng;
+    use rand_chacha_0_10::ChaCha12Rng;
+    use rand_chacha_0_10::ChaCha20Rng;
+    use rand_core_0_10::SeedableRng;
+
+    /// Extract the state of a `rand_chacha 0.10` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&ChaCha8Rng> for ChaChaState {
+        fn from(rng: &ChaCha8Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.10` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<ChaChaState> for ChaCha8Rng {
+        fn from(state: ChaChaState) -> ChaCha8Rng {
+            let mut rng = ChaCha8Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Extract the state of a `rand_chacha 0.10` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&ChaCha12Rng> for ChaChaState {
+        fn from(rng: &ChaCha12Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.10` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<ChaChaState> for ChaCha12Rng {
+        fn from(state: ChaChaState) -> ChaCha12Rng {
+            let mut rng = ChaCha12Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+
+    /// Extract the state of a `rand_chacha 0.10` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&ChaCha20Rng> for ChaChaState {
+        fn from(rng: &ChaCha20Rng) -> ChaChaState {
+            ChaChaState::new(rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+        }
+    }
+
+    /// Restore a `rand_chacha 0.10` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<ChaChaState> for ChaCha20Rng {
+        fn from(state: ChaChaState) -> ChaCha20Rng {
+            let mut rng = ChaCha20Rng::from_seed(state.seed);
+            rng.set_stream(state.stream);
+            rng.set_word_pos(state.word_pos);
+            rng
+        }
+    }
+}
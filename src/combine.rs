@@ -0,0 +1,220 @@
+/// Adapter combining the outputs of two independent RNGs by XOR.
+///
+/// The output is at least as unpredictable as the output of either of the two
+/// RNGs, as long as they are independent of each other. It implements the
+/// traits of every selected `rand_core`/`rand` version that both RNGs
+/// implement, so wrap RNGs of different versions in the wrapper structs of
+/// this crate, e.g. `Combine(Rng06(a), TryRng09(b))`.
+///
+/// The crypto RNG traits are implemented if the first RNG implements them, so
+/// pass the crypto RNG first, or use [`Combine::swap`] to put it first. Errors
+/// of both RNGs are propagated, for the `rand_core 0.9`/`rand 0.9` and
+/// `rand_core 0.10`/`rand 0.10` fallible RNG traits via
+/// [`crate::error::Error`].
+#[derive(Clone, Debug)]
+pub struct Combine<A, B>(pub A, pub B);
+
+impl<A, B> Combine<A, B> {
+    /// Swap the two RNGs.
+    ///
+    /// The output stays the same, as XOR is commutative and both RNGs are
+    /// asked for the same amount of output. Only the order in which they are
+    /// called changes. Use this to put the crypto RNG first, e.g.
+    /// `Combine(Rng06(a), Rng06(os)).swap()`, so that the crypto RNG traits
+    /// are implemented.
+    pub fn swap(self) -> Combine<B, A> {
+        Combine(self.1, self.0)
+    }
+}
+
+/// XOR `dst` with random bytes produced by `fill`.
+///
+/// Uses a fixed-size buffer to avoid allocation.
+fn xor_fill<E>(dst: &mut [u8], mut fill: impl FnMut(&mut [u8]) -> Result<(), E>) -> Result<(), E> {
+    let mut buf = [0; 64];
+    for chunk in dst.chunks_mut(buf.len()) {
+        let buf = &mut buf[..chunk.len()];
+        fill(buf)?;
+        for (d, b) in chunk.iter_mut().zip(buf.iter()) {
+            *d ^= *b;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Combine;
+    use super::xor_fill;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Errors of either RNG are returned unchanged by `try_fill_bytes`.
+    impl<A: rand_core_0_5::RngCore, B: rand_core_0_5::RngCore> rand_core_0_5::RngCore
+        for Combine<A, B>
+    {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32() ^ self.1.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64() ^ self.1.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst);
+            let result: Result<(), rand_core_0_5::Error> = xor_fill(dst, |buf| {
+                self.1.fill_bytes(buf);
+                Ok(())
+            });
+            result.unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.try_fill_bytes(dst)?;
+            xor_fill(dst, |buf| self.1.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    ///
+    /// XOR with an independent RNG doesn't make the output more predictable.
+    impl<A: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng, B: rand_core_0_5::RngCore>
+        rand_core_0_5::CryptoRng for Combine<A, B>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Combine;
+    use super::xor_fill;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Errors of either RNG are returned unchanged by `try_fill_bytes`.
+    impl<A: rand_core_0_6::RngCore, B: rand_core_0_6::RngCore> rand_core_0_6::RngCore
+        for Combine<A, B>
+    {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32() ^ self.1.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64() ^ self.1.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst);
+            let result: Result<(), rand_core_0_6::Error> = xor_fill(dst, |buf| {
+                self.1.fill_bytes(buf);
+                Ok(())
+            });
+            result.unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.try_fill_bytes(dst)?;
+            xor_fill(dst, |buf| self.1.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    ///
+    /// XOR with an independent RNG doesn't make the output more predictable.
+    impl<A: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng, B: rand_core_0_6::RngCore>
+        rand_core_0_6::CryptoRng for Combine<A, B>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Combine;
+    use super::xor_fill;
+    use crate::Error09;
+    use crate::error::Error;
+    use core::fmt;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// Since the errors of both RNGs can have different types, they're
+    /// converted to [`crate::error::Error`] via [`Error09`]. Use
+    /// [`rand_core_0_9::TryRngCore::unwrap_err`] to obtain an infallible RNG.
+    impl<A: rand_core_0_9::TryRngCore, B: rand_core_0_9::TryRngCore> rand_core_0_9::TryRngCore
+        for Combine<A, B>
+    where
+        A::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+        B::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let a = self.0.try_next_u32().map_err(Error09)?;
+            let b = self.1.try_next_u32().map_err(Error09)?;
+            Ok(a ^ b)
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let a = self.0.try_next_u64().map_err(Error09)?;
+            let b = self.1.try_next_u64().map_err(Error09)?;
+            Ok(a ^ b)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.0.try_fill_bytes(dst).map_err(Error09)?;
+            xor_fill(dst, |buf| {
+                Ok(self.1.try_fill_bytes(buf).map_err(Error09)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    ///
+    /// XOR with an independent RNG doesn't make the output more predictable.
+    impl<A: rand_core_0_9::TryCryptoRng, B: rand_core_0_9::TryRngCore> rand_core_0_9::TryCryptoRng
+        for Combine<A, B>
+    where
+        A::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+        B::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Combine;
+    use super::xor_fill;
+    use crate::Error010;
+    use crate::error::Error;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// Since the errors of both RNGs can have different types, they're
+    /// converted to [`crate::error::Error`] via [`Error010`].
+    impl<A: rand_core_0_10::TryRng, B: rand_core_0_10::TryRng> rand_core_0_10::TryRng for Combine<A, B>
+    where
+        A::Error: Send + Sync + 'static,
+        B::Error: Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let a = self.0.try_next_u32().map_err(Error010)?;
+            let b = self.1.try_next_u32().map_err(Error010)?;
+            Ok(a ^ b)
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let a = self.0.try_next_u64().map_err(Error010)?;
+            let b = self.1.try_next_u64().map_err(Error010)?;
+            Ok(a ^ b)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.0.try_fill_bytes(dst).map_err(Error010)?;
+            xor_fill(dst, |buf| {
+                Ok(self.1.try_fill_bytes(buf).map_err(Error010)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    ///
+    /// XOR with an independent RNG doesn't make the output more predictable.
+    impl<A: rand_core_0_10::TryCryptoRng, B: rand_core_0_10::TryRng> rand_core_0_10::TryCryptoRng
+        for Combine<A, B>
+    where
+        A::Error: Send + Sync + 'static,
+        B::Error: Send + Sync + 'static,
+    {
+    }
+}
@@ -1,3 +1,4 @@
+use core::convert::Infallible;
 use core::fmt;
 
 /// Wrapper struct for a `rand_core 0.10`/`rand 0.10` RNG.
@@ -28,6 +29,147 @@ impl<T: fmt::Debug + fmt::Display + Send + Sync + 'static> fmt::Display for Erro
     }
 }
 
+/// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_10::Rng> rand_core_0_10::TryRng for Rng010<T> {
+    type Error = Infallible;
+    fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+        self.0.try_next_u32()
+    }
+    fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+        self.0.try_next_u64()
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_10::CryptoRng> rand_core_0_10::TryCryptoRng for Rng010<T> {}
+
+/// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_10::Rng + rand_core_0_10::SeedableRng> rand_core_0_10::SeedableRng
+    for Rng010<T>
+{
+    type Seed = T::Seed;
+    fn from_seed(seed: T::Seed) -> Rng010<T> {
+        Rng010(T::from_seed(seed))
+    }
+    fn seed_from_u64(state: u64) -> Rng010<T> {
+        Rng010(T::seed_from_u64(state))
+    }
+}
+
+/// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for TryRng010<T> {
+    type Error = T::Error;
+    fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+        self.0.try_next_u32()
+    }
+    fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+        self.0.try_next_u64()
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_10::TryCryptoRng> rand_core_0_10::TryCryptoRng for TryRng010<T> {}
+
+/// Convert to an error of this crate.
+///
+/// If the `std` feature is enabled, then this conversion is lossless by
+/// wrapping this `Error010` struct. Otherwise, it simply returns an error code
+/// [`crate::error::UNKNOWN`].
+impl<T: fmt::Debug + fmt::Display + Send + Sync + 'static> From<Error010<T>>
+    for crate::error::Error
+{
+    fn from(error: Error010<T>) -> crate::error::Error {
+        #[cfg(feature = "std")]
+        {
+            crate::error::Error::from_inner(Box::new(error))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use crate::error;
+
+            let _ = error;
+            error::UNKNOWN.into()
+        }
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_10::Rng + serde::Serialize> serde::Serialize for Rng010<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_10::Rng + serde::Deserialize<'de>> serde::Deserialize<'de> for Rng010<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rng010<T>, D::Error> {
+        T::deserialize(deserializer).map(Rng010)
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_10::TryRng + serde::Serialize> serde::Serialize for TryRng010<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_10::TryRng + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for TryRng010<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TryRng010<T>, D::Error> {
+        T::deserialize(deserializer).map(TryRng010)
+    }
+}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_10::Rng + zeroize::Zeroize> zeroize::Zeroize for Rng010<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_10::Rng + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Rng010<T> {}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_10::TryRng + zeroize::Zeroize> zeroize::Zeroize for TryRng010<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_10::TryRng + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for TryRng010<T> {}
+
 #[cfg(feature = "rand_core_0_5")]
 mod v0_5 {
     use super::Error010;
@@ -109,6 +251,22 @@ mod v0_5 {
             }
         }
     }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.10`.
+    impl<T: rand_core_0_10::Rng + rand_core_0_10::SeedableRng> rand_core_0_5::SeedableRng
+        for Rng010<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng010<T> {
+            Rng010(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng010<T> {
+            Rng010(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_6")]
@@ -192,6 +350,22 @@ mod v0_6 {
             }
         }
     }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.10`.
+    impl<T: rand_core_0_10::Rng + rand_core_0_10::SeedableRng> rand_core_0_6::SeedableRng
+        for Rng010<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng010<T> {
+            Rng010(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng010<T> {
+            Rng010(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_9")]
@@ -242,4 +416,20 @@ mod v0_9 {
         for TryRng010<T>
     {
     }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.10`.
+    impl<T: rand_core_0_10::Rng + rand_core_0_10::SeedableRng> rand_core_0_9::SeedableRng
+        for Rng010<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng010<T> {
+            Rng010(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng010<T> {
+            Rng010(T::seed_from_u64(state))
+        }
+    }
 }
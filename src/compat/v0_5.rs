@@ -17,6 +17,167 @@ pub struct TryRng05<T: rand_core_0_5::RngCore>(pub T);
 #[derive(Debug)]
 pub struct Error05(pub rand_core_0_5::Error);
 
+/// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for Rng05<T> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng for Rng05<T> {}
+
+/// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng> rand_core_0_5::SeedableRng
+    for Rng05<T>
+{
+    type Seed = T::Seed;
+    fn from_seed(seed: T::Seed) -> Rng05<T> {
+        Rng05(T::from_seed(seed))
+    }
+    fn seed_from_u64(state: u64) -> Rng05<T> {
+        Rng05(T::seed_from_u64(state))
+    }
+}
+
+/// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for TryRng05<T> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng
+    for TryRng05<T>
+{
+}
+
+/// Convert to an error of this crate.
+///
+/// If the `std` feature is enabled, then this conversion is lossless.
+/// Otherwise, extracting and duplicating the error code is tried, and
+/// if not successful, the error code is set to [`crate::error::UNKNOWN`].
+impl From<Error05> for crate::error::Error {
+    fn from(error: Error05) -> crate::error::Error {
+        #[cfg(feature = "std")]
+        {
+            match error.0.code() {
+                Some(code) => code.into(),
+                None => crate::error::Error::from_inner(error.0.take_inner()),
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use crate::error;
+            use core::num::NonZeroU32;
+
+            if let Some(code) = error.0.code() {
+                return code.into();
+            }
+            if let Some(code) = error.0.raw_os_error() {
+                if let Some(code) = NonZeroU32::new(code as u32) {
+                    return code.into();
+                } else {
+                    return error::OS_ERROR_0.into();
+                }
+            }
+            error::UNKNOWN.into()
+        }
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_5::RngCore + serde::Serialize> serde::Serialize for Rng05<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_5::RngCore + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for Rng05<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rng05<T>, D::Error> {
+        T::deserialize(deserializer).map(Rng05)
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_5::RngCore + serde::Serialize> serde::Serialize for TryRng05<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_5::RngCore + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for TryRng05<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TryRng05<T>, D::Error> {
+        T::deserialize(deserializer).map(TryRng05)
+    }
+}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_5::RngCore + zeroize::Zeroize> zeroize::Zeroize for Rng05<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_5::RngCore + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Rng05<T> {}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_5::RngCore + zeroize::Zeroize> zeroize::Zeroize for TryRng05<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_5::RngCore + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for TryRng05<T> {}
+
 #[cfg(feature = "rand_core_0_6")]
 mod v0_6 {
     use super::Error05;
@@ -102,6 +263,22 @@ mod v0_6 {
             }
         }
     }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.5`.
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng> rand_core_0_6::SeedableRng
+        for Rng05<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng05<T> {
+            Rng05(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng05<T> {
+            Rng05(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_9")]
@@ -160,6 +337,23 @@ mod v0_9 {
         for TryRng05<T>
     {
     }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.5`.
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng> rand_core_0_9::SeedableRng for Rng05<T>
+    where
+        T::Seed: Clone + AsRef<[u8]>,
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng05<T> {
+            Rng05(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng05<T> {
+            Rng05(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_10")]
@@ -223,4 +417,22 @@ mod v0_10 {
         for TryRng05<T>
     {
     }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.5`.
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng> rand_core_0_10::SeedableRng
+        for Rng05<T>
+    where
+        T::Seed: Clone + AsRef<[u8]>,
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng05<T> {
+            Rng05(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng05<T> {
+            Rng05(T::seed_from_u64(state))
+        }
+    }
 }
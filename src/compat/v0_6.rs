@@ -17,6 +17,167 @@ pub struct TryRng06<T: rand_core_0_6::RngCore>(pub T);
 #[derive(Debug)]
 pub struct Error06(pub rand_core_0_6::Error);
 
+/// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for Rng06<T> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng for Rng06<T> {}
+
+/// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng> rand_core_0_6::SeedableRng
+    for Rng06<T>
+{
+    type Seed = T::Seed;
+    fn from_seed(seed: T::Seed) -> Rng06<T> {
+        Rng06(T::from_seed(seed))
+    }
+    fn seed_from_u64(state: u64) -> Rng06<T> {
+        Rng06(T::seed_from_u64(state))
+    }
+}
+
+/// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for TryRng06<T> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng
+    for TryRng06<T>
+{
+}
+
+/// Convert to an error of this crate.
+///
+/// If the `std` feature is enabled, then this conversion is lossless.
+/// Otherwise, extracting and duplicating the error code is tried, and
+/// if not successful, the error code is set to [`crate::error::UNKNOWN`].
+impl From<Error06> for crate::error::Error {
+    fn from(error: Error06) -> crate::error::Error {
+        #[cfg(feature = "std")]
+        {
+            match error.0.code() {
+                Some(code) => code.into(),
+                None => crate::error::Error::from_inner(error.0.take_inner()),
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use crate::error;
+            use core::num::NonZeroU32;
+
+            if let Some(code) = error.0.code() {
+                return code.into();
+            }
+            if let Some(code) = error.0.raw_os_error() {
+                if let Some(code) = NonZeroU32::new(code as u32) {
+                    return code.into();
+                } else {
+                    return error::OS_ERROR_0.into();
+                }
+            }
+            error::UNKNOWN.into()
+        }
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_6::RngCore + serde::Serialize> serde::Serialize for Rng06<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_6::RngCore + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for Rng06<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rng06<T>, D::Error> {
+        T::deserialize(deserializer).map(Rng06)
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_6::RngCore + serde::Serialize> serde::Serialize for TryRng06<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_6::RngCore + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for TryRng06<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TryRng06<T>, D::Error> {
+        T::deserialize(deserializer).map(TryRng06)
+    }
+}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_6::RngCore + zeroize::Zeroize> zeroize::Zeroize for Rng06<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_6::RngCore + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Rng06<T> {}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_6::RngCore + zeroize::Zeroize> zeroize::Zeroize for TryRng06<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_6::RngCore + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for TryRng06<T> {}
+
 #[cfg(feature = "rand_core_0_5")]
 mod v0_5 {
     use super::Error06;
@@ -102,6 +263,22 @@ mod v0_5 {
             }
         }
     }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.6`.
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng> rand_core_0_5::SeedableRng
+        for Rng06<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng06<T> {
+            Rng06(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng06<T> {
+            Rng06(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_9")]
@@ -160,6 +337,23 @@ mod v0_9 {
         for TryRng06<T>
     {
     }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.6`.
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng> rand_core_0_9::SeedableRng for Rng06<T>
+    where
+        T::Seed: Clone + AsRef<[u8]>,
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng06<T> {
+            Rng06(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng06<T> {
+            Rng06(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_10")]
@@ -223,4 +417,22 @@ mod v0_10 {
         for TryRng06<T>
     {
     }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.6`.
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng> rand_core_0_10::SeedableRng
+        for Rng06<T>
+    where
+        T::Seed: Clone + AsRef<[u8]>,
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng06<T> {
+            Rng06(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng06<T> {
+            Rng06(T::seed_from_u64(state))
+        }
+    }
 }
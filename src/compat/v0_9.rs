@@ -28,6 +28,148 @@ impl<T: fmt::Debug + fmt::Display + Send + Sync + 'static> fmt::Display for Erro
     }
 }
 
+/// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_9::RngCore> rand_core_0_9::RngCore for Rng09<T> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_9::CryptoRng> rand_core_0_9::CryptoRng for Rng09<T> {}
+
+/// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_9::RngCore + rand_core_0_9::SeedableRng> rand_core_0_9::SeedableRng
+    for Rng09<T>
+{
+    type Seed = T::Seed;
+    fn from_seed(seed: T::Seed) -> Rng09<T> {
+        Rng09(T::from_seed(seed))
+    }
+    fn seed_from_u64(state: u64) -> Rng09<T> {
+        Rng09(T::seed_from_u64(state))
+    }
+}
+
+/// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+///
+/// This forwards to the wrapped RNG, so that the wrapper can be used with all
+/// selected `rand_core`/`rand` versions, including its own.
+impl<T: rand_core_0_9::TryRngCore> rand_core_0_9::TryRngCore for TryRng09<T> {
+    type Error = T::Error;
+    fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+        self.0.try_next_u32()
+    }
+    fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+        self.0.try_next_u64()
+    }
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+/// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+///
+/// This forwards to the wrapped RNG.
+impl<T: rand_core_0_9::TryCryptoRng> rand_core_0_9::TryCryptoRng for TryRng09<T> {}
+
+/// Convert to an error of this crate.
+///
+/// If the `std` feature is enabled, then this conversion is lossless by
+/// wrapping this `Error09` struct. Otherwise, it simply returns an error code
+/// [`crate::error::UNKNOWN`].
+impl<T: fmt::Debug + fmt::Display + Send + Sync + 'static> From<Error09<T>>
+    for crate::error::Error
+{
+    fn from(error: Error09<T>) -> crate::error::Error {
+        #[cfg(feature = "std")]
+        {
+            crate::error::Error::from_inner(Box::new(error))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use crate::error;
+
+            let _ = error;
+            error::UNKNOWN.into()
+        }
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_9::RngCore + serde::Serialize> serde::Serialize for Rng09<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_9::RngCore + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for Rng09<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rng09<T>, D::Error> {
+        T::deserialize(deserializer).map(Rng09)
+    }
+}
+
+/// Serialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<T: rand_core_0_9::TryRngCore + serde::Serialize> serde::Serialize for TryRng09<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserialize the wrapped RNG as if it wasn't wrapped.
+#[cfg(feature = "serde")]
+impl<'de, T: rand_core_0_9::TryRngCore + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for TryRng09<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TryRng09<T>, D::Error> {
+        T::deserialize(deserializer).map(TryRng09)
+    }
+}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_9::RngCore + zeroize::Zeroize> zeroize::Zeroize for Rng09<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_9::RngCore + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Rng09<T> {}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_9::TryRngCore + zeroize::Zeroize> zeroize::Zeroize for TryRng09<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: rand_core_0_9::TryRngCore + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for TryRng09<T> {}
+
 #[cfg(feature = "rand_core_0_5")]
 mod v0_5 {
     use super::Error09;
@@ -109,6 +251,22 @@ mod v0_5 {
             }
         }
     }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.9`.
+    impl<T: rand_core_0_9::RngCore + rand_core_0_9::SeedableRng> rand_core_0_5::SeedableRng
+        for Rng09<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng09<T> {
+            Rng09(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng09<T> {
+            Rng09(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_6")]
@@ -192,6 +350,22 @@ mod v0_6 {
             }
         }
     }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.9`.
+    impl<T: rand_core_0_9::RngCore + rand_core_0_9::SeedableRng> rand_core_0_6::SeedableRng
+        for Rng09<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng09<T> {
+            Rng09(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng09<T> {
+            Rng09(T::seed_from_u64(state))
+        }
+    }
 }
 
 #[cfg(feature = "rand_core_0_10")]
@@ -253,4 +427,20 @@ mod v0_10 {
         T::Error: Error,
     {
     }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    ///
+    /// The seed type is the one of the wrapped RNG and `seed_from_u64` is
+    /// forwarded, so seeding works the same as with `rand_core 0.9`.
+    impl<T: rand_core_0_9::RngCore + rand_core_0_9::SeedableRng> rand_core_0_10::SeedableRng
+        for Rng09<T>
+    {
+        type Seed = T::Seed;
+        fn from_seed(seed: T::Seed) -> Rng09<T> {
+            Rng09(T::from_seed(seed))
+        }
+        fn seed_from_u64(state: u64) -> Rng09<T> {
+            Rng09(T::seed_from_u64(state))
+        }
+    }
 }
@@ -0,0 +1,276 @@
+use core::fmt;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Size of the output blocks of the conditioning function in bytes.
+const BLOCK_LEN: usize = 32;
+
+/// Minimum number of input bytes per output block.
+///
+/// Claiming full entropy for the 256 bits of output requires at least 320
+/// bits of min-entropy in the input, which needs at least 40 bytes.
+const MIN_INPUT_LEN: usize = 40;
+
+/// Adapter conditioning the output of a raw entropy source using SHA-256.
+///
+/// Every 32 bytes of output are the SHA-256 hash of a configurable number of
+/// bytes read from the wrapped RNG. This is a vetted conditioning function as
+/// per NIST SP 800-90B, Section 3.1.5.1.1, turning biased input into output
+/// with (close to) full entropy, provided that the input contains enough
+/// entropy. To claim full entropy, each block of input must contain at least
+/// 320 bits of min-entropy, so at least 40 bytes are read per block, and more
+/// for sources with less than 8 bits of min-entropy per byte.
+///
+/// It implements the fallible and crypto RNG traits of every selected
+/// `rand_core`/`rand` version that the wrapped RNG implements, so wrap RNGs in
+/// the wrapper structs of this crate, e.g. `Conditioned::new(TryRng09(t), 64)`.
+/// Errors of the wrapped RNG are returned unchanged.
+#[derive(Clone)]
+pub struct Conditioned<T> {
+    inner: T,
+    input_len: usize,
+    block: Block,
+}
+
+/// Output block of the conditioning function.
+///
+/// It zeroizes itself on drop with the `zeroize` feature, rather than
+/// [`Conditioned`], so that [`Conditioned::into_inner`] can move the wrapped
+/// RNG out.
+#[derive(Clone)]
+struct Block {
+    bytes: [u8; BLOCK_LEN],
+    /// Number of already returned bytes, which are zeroed.
+    used: usize,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Block {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes);
+    }
+}
+
+/// Prints the wrapped RNG and the input length, but not the buffered output.
+impl<T: fmt::Debug> fmt::Debug for Conditioned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Conditioned")
+            .field("inner", &self.inner)
+            .field("input_len", &self.input_len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Conditioned<T> {
+    /// Wrap a raw entropy source.
+    ///
+    /// `input_len` is the number of bytes read from `inner` for every 32 bytes
+    /// of output, i.e. the input/output ratio is `input_len / 32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input_len` is less than 40, as the output couldn't have full
+    /// entropy then.
+    pub fn new(inner: T, input_len: usize) -> Conditioned<T> {
+        assert!(
+            input_len >= MIN_INPUT_LEN,
+            "input_len must be at least {MIN_INPUT_LEN}"
+        );
+        Conditioned {
+            inner,
+            input_len,
+            block: Block {
+                bytes: [0; BLOCK_LEN],
+                used: BLOCK_LEN,
+            },
+        }
+    }
+
+    /// Reference the wrapped RNG.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the wrapped RNG, discarding buffered output.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Fill `dst` with conditioned output, reading raw input using `raw`.
+    fn try_fill_with<E>(
+        &mut self,
+        dst: &mut [u8],
+        mut raw: impl FnMut(&mut T, &mut [u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut filled = 0;
+        while filled < dst.len() {
+            if self.block.used == BLOCK_LEN {
+                self.block.bytes = self.condition(&mut raw)?;
+                self.block.used = 0;
+            }
+            let used = self.block.used;
+            let n = (BLOCK_LEN - used).min(dst.len() - filled);
+            dst[filled..filled + n].copy_from_slice(&self.block.bytes[used..used + n]);
+            self.block.bytes[used..used + n].fill(0);
+            self.block.used += n;
+            filled += n;
+        }
+        Ok(())
+    }
+
+    /// Hash `input_len` bytes of raw input into a new output block.
+    fn condition<E>(
+        &mut self,
+        raw: &mut impl FnMut(&mut T, &mut [u8]) -> Result<(), E>,
+    ) -> Result<[u8; BLOCK_LEN], E> {
+        let mut hasher = Sha256::new();
+        let mut buf = [0; 64];
+        let mut remaining = self.input_len;
+        while remaining > 0 {
+            let buf = &mut buf[..remaining.min(64)];
+            raw(&mut self.inner, buf)?;
+            hasher.update(&*buf);
+            remaining -= buf.len();
+        }
+        buf.fill(0);
+        Ok(hasher.finalize().into())
+    }
+}
+
+/// Zeroize the wrapped RNG and discard the buffered output.
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for Conditioned<T> {
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+        self.block.bytes.zeroize();
+        self.block.used = BLOCK_LEN;
+    }
+}
+
+/// The buffered output is zeroized on drop, and so is the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Conditioned<T> {}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Conditioned;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for Conditioned<T> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_5::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_5::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.try_fill_with(dst, |inner, buf| inner.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    ///
+    /// The conditioned output is suitable for cryptographic use, provided
+    /// that the input contains enough entropy.
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::CryptoRng for Conditioned<T> {}
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Conditioned;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for Conditioned<T> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_6::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_6::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.try_fill_with(dst, |inner, buf| inner.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    ///
+    /// The conditioned output is suitable for cryptographic use, provided
+    /// that the input contains enough entropy.
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::CryptoRng for Conditioned<T> {}
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Conditioned;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, taking the bytes from the conditioned output.
+    impl<T: rand_core_0_9::TryRngCore> rand_core_0_9::TryRngCore for Conditioned<T> {
+        type Error = T::Error;
+        fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+            let mut buf = [0; 4];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+            self.try_fill_with(dst, |inner, buf| inner.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    ///
+    /// The conditioned output is suitable for cryptographic use, provided
+    /// that the input contains enough entropy.
+    impl<T: rand_core_0_9::TryRngCore> rand_core_0_9::TryCryptoRng for Conditioned<T> {}
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Conditioned;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, taking the bytes from the conditioned output.
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for Conditioned<T> {
+        type Error = T::Error;
+        fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+            let mut buf = [0; 4];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+            self.try_fill_with(dst, |inner, buf| inner.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    ///
+    /// The conditioned output is suitable for cryptographic use, provided
+    /// that the input contains enough entropy.
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryCryptoRng for Conditioned<T> {}
+}
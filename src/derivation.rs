@@ -0,0 +1,211 @@
+use core::fmt;
+use hmac::Hmac;
+use hmac::Mac;
+use sha2::Sha256;
+
+/// Size of the output blocks of HMAC-SHA256 in bytes.
+const BLOCK_LEN: usize = 32;
+
+/// Maximum length of a derived seed in bytes, as limited by HKDF.
+const MAX_SEED_LEN: usize = 255 * BLOCK_LEN;
+
+/// Key deriving independent child RNGs from labels.
+///
+/// The seed of the child RNG for a label, e.g. `"agent/42"`, is
+/// HKDF-Expand of RFC 5869 using HMAC-SHA256, with the key as the
+/// pseudorandom key, the label as the info and the length of the seed of the
+/// child RNG as the output length. That is, the seed consists of the blocks
+/// `T(1)`, `T(2)`, ..., truncated to its length, with `T(i)` being the
+/// HMAC-SHA256 using the key of the concatenation of `T(i - 1)` (empty for
+/// `T(1)`), the label and the byte `i`. This derivation is stable, so the
+/// same key, label and seed length always give the same seed, independent of
+/// the `rand_core`/`rand` version of the child RNG. Seeds for different labels
+/// are independent, as long as the key is secret and random.
+///
+/// The key is created from a seed using [`DerivationKey::new`] or from a
+/// master RNG using the seedable RNG traits of every selected
+/// `rand_core`/`rand` version, which read 32 bytes from the RNG. The child
+/// RNGs are created using [`DerivationKey::fill_seed`] or the `child*`
+/// methods of the selected versions, which return them in the wrapper structs
+/// of this crate, so that they implement the traits of every selected version.
+#[derive(Clone, Eq, PartialEq)]
+pub struct DerivationKey([u8; 32]);
+
+/// Doesn't print the key.
+impl fmt::Debug for DerivationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivationKey").finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the key.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for DerivationKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for DerivationKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+/// The key is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for DerivationKey {}
+
+impl DerivationKey {
+    /// Create the key from a seed.
+    pub const fn new(seed: [u8; 32]) -> DerivationKey {
+        DerivationKey(seed)
+    }
+
+    /// Fill `seed` with the seed derived for `label`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seed` is longer than 8160 bytes, the maximum output length of
+    /// HKDF using SHA-256.
+    pub fn fill_seed(&self, label: impl AsRef<[u8]>, seed: &mut [u8]) {
+        assert!(
+            seed.len() <= MAX_SEED_LEN,
+            "seed must not be longer than {MAX_SEED_LEN} bytes"
+        );
+        let label = label.as_ref();
+        let mut block = [0; BLOCK_LEN];
+        for (i, chunk) in seed.chunks_mut(BLOCK_LEN).enumerate() {
+            let mut mac =
+                Hmac::<Sha256>::new_from_slice(&self.0).expect("HMAC accepts keys of any length");
+            if i > 0 {
+                mac.update(&block);
+            }
+            mac.update(label);
+            mac.update(&[i as u8 + 1]);
+            block = mac.finalize().into_bytes().into();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::DerivationKey;
+    use crate::Rng05;
+
+    impl DerivationKey {
+        /// Create the `rand_core 0.5`/`rand 0.7` child RNG for `label`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the seed of `G` is longer than 8160 bytes.
+        pub fn child05<G>(&self, label: impl AsRef<[u8]>) -> Rng05<G>
+        where
+            G: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng,
+        {
+            let mut seed = G::Seed::default();
+            self.fill_seed(label, seed.as_mut());
+            Rng05(G::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for DerivationKey {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> DerivationKey {
+            DerivationKey::new(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::DerivationKey;
+    use crate::Rng06;
+
+    impl DerivationKey {
+        /// Create the `rand_core 0.6`/`rand 0.8` child RNG for `label`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the seed of `G` is longer than 8160 bytes.
+        pub fn child06<G>(&self, label: impl AsRef<[u8]>) -> Rng06<G>
+        where
+            G: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng,
+        {
+            let mut seed = G::Seed::default();
+            self.fill_seed(label, seed.as_mut());
+            Rng06(G::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for DerivationKey {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> DerivationKey {
+            DerivationKey::new(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::DerivationKey;
+    use crate::Rng09;
+
+    impl DerivationKey {
+        /// Create the `rand_core 0.9`/`rand 0.9` child RNG for `label`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the seed of `G` is longer than 8160 bytes.
+        pub fn child09<G>(&self, label: impl AsRef<[u8]>) -> Rng09<G>
+        where
+            G: rand_core_0_9::RngCore + rand_core_0_9::SeedableRng,
+        {
+            let mut seed = G::Seed::default();
+            self.fill_seed(label, seed.as_mut());
+            Rng09(G::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for DerivationKey {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> DerivationKey {
+            DerivationKey::new(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::DerivationKey;
+    use crate::Rng010;
+
+    impl DerivationKey {
+        /// Create the `rand_core 0.10`/`rand 0.10` child RNG for `label`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the seed of `G` is longer than 8160 bytes.
+        pub fn child010<G>(&self, label: impl AsRef<[u8]>) -> Rng010<G>
+        where
+            G: rand_core_0_10::Rng + rand_core_0_10::SeedableRng,
+        {
+            let mut seed = G::Seed::default();
+            self.fill_seed(label, seed.as_mut());
+            Rng010(G::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for DerivationKey {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> DerivationKey {
+            DerivationKey::new(seed)
+        }
+    }
+}
@@ -0,0 +1,223 @@
+/// Wrapper struct for a `rand 0.7` distribution.
+///
+/// It implements the `Distribution` trait of every selected `rand` version
+/// for the types the wrapped distribution samples, adapting the RNG passed to
+/// `sample` with [`crate::Rng05`]. Select the `rand` versions using the
+/// features `rand_0_7`, `rand_0_8`, `rand_0_9` and `rand_0_10`.
+#[cfg(feature = "rand_0_7")]
+#[derive(Clone, Copy, Debug)]
+pub struct Dist07<D>(pub D);
+
+/// Wrapper struct for a `rand 0.8` distribution.
+///
+/// It implements the `Distribution` trait of every selected `rand` version
+/// for the types the wrapped distribution samples, adapting the RNG passed to
+/// `sample` with [`crate::Rng06`]. Select the `rand` versions using the
+/// features `rand_0_7`, `rand_0_8`, `rand_0_9` and `rand_0_10`.
+#[cfg(feature = "rand_0_8")]
+#[derive(Clone, Copy, Debug)]
+pub struct Dist08<D>(pub D);
+
+/// Wrapper struct for a `rand 0.9` distribution.
+///
+/// It implements the `Distribution` trait of every selected `rand` version
+/// for the types the wrapped distribution samples, adapting the RNG passed to
+/// `sample` with [`crate::Rng09`]. Select the `rand` versions using the
+/// features `rand_0_7`, `rand_0_8`, `rand_0_9` and `rand_0_10`.
+#[cfg(feature = "rand_0_9")]
+#[derive(Clone, Copy, Debug)]
+pub struct Dist09<D>(pub D);
+
+/// Wrapper struct for a `rand 0.10` distribution.
+///
+/// It implements the `Distribution` trait of every selected `rand` version
+/// for the types the wrapped distribution samples, adapting the RNG passed to
+/// `sample` with [`crate::Rng010`]. Select the `rand` versions using the
+/// features `rand_0_7`, `rand_0_8`, `rand_0_9` and `rand_0_10`.
+#[cfg(feature = "rand_0_10")]
+#[derive(Clone, Copy, Debug)]
+pub struct Dist010<D>(pub D);
+
+#[cfg(feature = "rand_0_7")]
+mod v0_7 {
+    use super::Dist07;
+    #[cfg(feature = "rand_0_8")]
+    use super::Dist08;
+    #[cfg(feature = "rand_0_9")]
+    use super::Dist09;
+    #[cfg(feature = "rand_0_10")]
+    use super::Dist010;
+    use rand_0_7::distributions::Distribution;
+
+    /// Implement the `rand 0.7` distribution trait.
+    ///
+    /// This forwards to the wrapped distribution, so that the wrapper can be
+    /// used with all selected `rand` versions, including its own.
+    impl<T, D: Distribution<T>> Distribution<T> for Dist07<D> {
+        fn sample<R: rand_0_7::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(rng)
+        }
+    }
+
+    /// Implement the `rand 0.7` distribution trait.
+    #[cfg(feature = "rand_0_8")]
+    impl<T, D: rand_0_8::distributions::Distribution<T>> Distribution<T> for Dist08<D> {
+        fn sample<R: rand_0_7::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng05(rng))
+        }
+    }
+
+    /// Implement the `rand 0.7` distribution trait.
+    #[cfg(feature = "rand_0_9")]
+    impl<T, D: rand_0_9::distr::Distribution<T>> Distribution<T> for Dist09<D> {
+        fn sample<R: rand_0_7::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng05(rng))
+        }
+    }
+
+    /// Implement the `rand 0.7` distribution trait.
+    #[cfg(feature = "rand_0_10")]
+    impl<T, D: rand_0_10::distr::Distribution<T>> Distribution<T> for Dist010<D> {
+        fn sample<R: rand_0_7::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng05(rng))
+        }
+    }
+}
+
+#[cfg(feature = "rand_0_8")]
+mod v0_8 {
+    #[cfg(feature = "rand_0_7")]
+    use super::Dist07;
+    use super::Dist08;
+    #[cfg(feature = "rand_0_9")]
+    use super::Dist09;
+    #[cfg(feature = "rand_0_10")]
+    use super::Dist010;
+    use rand_0_8::distributions::Distribution;
+
+    /// Implement the `rand 0.8` distribution trait.
+    #[cfg(feature = "rand_0_7")]
+    impl<T, D: rand_0_7::distributions::Distribution<T>> Distribution<T> for Dist07<D> {
+        fn sample<R: rand_0_8::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng06(rng))
+        }
+    }
+
+    /// Implement the `rand 0.8` distribution trait.
+    ///
+    /// This forwards to the wrapped distribution, so that the wrapper can be
+    /// used with all selected `rand` versions, including its own.
+    impl<T, D: Distribution<T>> Distribution<T> for Dist08<D> {
+        fn sample<R: rand_0_8::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(rng)
+        }
+    }
+
+    /// Implement the `rand 0.8` distribution trait.
+    #[cfg(feature = "rand_0_9")]
+    impl<T, D: rand_0_9::distr::Distribution<T>> Distribution<T> for Dist09<D> {
+        fn sample<R: rand_0_8::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng06(rng))
+        }
+    }
+
+    /// Implement the `rand 0.8` distribution trait.
+    #[cfg(feature = "rand_0_10")]
+    impl<T, D: rand_0_10::distr::Distribution<T>> Distribution<T> for Dist010<D> {
+        fn sample<R: rand_0_8::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng06(rng))
+        }
+    }
+}
+
+#[cfg(feature = "rand_0_9")]
+mod v0_9 {
+    #[cfg(feature = "rand_0_7")]
+    use super::Dist07;
+    #[cfg(feature = "rand_0_8")]
+    use super::Dist08;
+    use super::Dist09;
+    #[cfg(feature = "rand_0_10")]
+    use super::Dist010;
+    use rand_0_9::distr::Distribution;
+
+    /// Implement the `rand 0.9` distribution trait.
+    #[cfg(feature = "rand_0_7")]
+    impl<T, D: rand_0_7::distributions::Distribution<T>> Distribution<T> for Dist07<D> {
+        fn sample<R: rand_0_9::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng09(rng))
+        }
+    }
+
+    /// Implement the `rand 0.9` distribution trait.
+    #[cfg(feature = "rand_0_8")]
+    impl<T, D: rand_0_8::distributions::Distribution<T>> Distribution<T> for Dist08<D> {
+        fn sample<R: rand_0_9::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng09(rng))
+        }
+    }
+
+    /// Implement the `rand 0.9` distribution trait.
+    ///
+    /// This forwards to the wrapped distribution, so that the wrapper can be
+    /// used with all selected `rand` versions, including its own.
+    impl<T, D: Distribution<T>> Distribution<T> for Dist09<D> {
+        fn sample<R: rand_0_9::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(rng)
+        }
+    }
+
+    /// Implement the `rand 0.9` distribution trait.
+    #[cfg(feature = "rand_0_10")]
+    impl<T, D: rand_0_10::distr::Distribution<T>> Distribution<T> for Dist010<D> {
+        fn sample<R: rand_0_9::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng09(rng))
+        }
+    }
+}
+
+#[cfg(feature = "rand_0_10")]
+mod v0_10 {
+    #[cfg(feature = "rand_0_7")]
+    use super::Dist07;
+    #[cfg(feature = "rand_0_8")]
+    use super::Dist08;
+    #[cfg(feature = "rand_0_9")]
+    use super::Dist09;
+    use super::Dist010;
+    use rand_0_10::distr::Distribution;
+
+    /// Implement the `rand 0.10` distribution trait.
+    #[cfg(feature = "rand_0_7")]
+    impl<T, D: rand_0_7::distributions::Distribution<T>> Distribution<T> for Dist07<D> {
+        fn sample<R: rand_0_10::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng010(rng))
+        }
+    }
+
+    /// Implement the `rand 0.10` distribution trait.
+    #[cfg(feature = "rand_0_8")]
+    impl<T, D: rand_0_8::distributions::Distribution<T>> Distribution<T> for Dist08<D> {
+        fn sample<R: rand_0_10::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng010(rng))
+        }
+    }
+
+    /// Implement the `rand 0.10` distribution trait.
+    #[cfg(feature = "rand_0_9")]
+    impl<T, D: rand_0_9::distr::Distribution<T>> Distribution<T> for Dist09<D> {
+        fn sample<R: rand_0_10::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(&mut crate::Rng010(rng))
+        }
+    }
+
+    /// Implement the `rand 0.10` distribution trait.
+    ///
+    /// This forwards to the wrapped distribution, so that the wrapper can be
+    /// used with all selected `rand` versions, including its own.
+    impl<T, D: Distribution<T>> Distribution<T> for Dist010<D> {
+        fn sample<R: rand_0_10::Rng + ?Sized>(&self, rng: &mut R) -> T {
+            self.0.sample(rng)
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use super::Mechanism;
+use aes::Aes256;
+use aes::cipher::BlockEncrypt;
+use aes::cipher::KeyInit;
+use core::fmt;
+
+/// Length of the block cipher blocks in bytes.
+const BLOCK_LEN: usize = 16;
+/// Length of the key and the block together in bytes.
+const SEED_LEN: usize = 48;
+
+/// The CTR_DRBG mechanism of NIST SP 800-90A, Section 10.2.1, using AES-256
+/// and the derivation function.
+#[derive(Clone)]
+pub struct CtrAes256 {
+    cipher: Aes256,
+    v: [u8; BLOCK_LEN],
+}
+
+fn encrypt(cipher: &Aes256, block: [u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+    let mut block = block.into();
+    cipher.encrypt_block(&mut block);
+    block.into()
+}
+
+fn increment(v: &mut [u8; BLOCK_LEN]) {
+    *v = u128::from_be_bytes(*v).wrapping_add(1).to_be_bytes();
+}
+
+/// The BCC function, fed incrementally.
+struct Bcc<'a> {
+    cipher: &'a Aes256,
+    chaining_value: [u8; BLOCK_LEN],
+    len: usize,
+}
+
+impl Bcc<'_> {
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.chaining_value[self.len] ^= byte;
+            self.len += 1;
+            if self.len == BLOCK_LEN {
+                self.chaining_value = encrypt(self.cipher, self.chaining_value);
+                self.len = 0;
+            }
+        }
+    }
+
+    /// Pad the input with zeros to a multiple of the block length.
+    fn finish(mut self) -> [u8; BLOCK_LEN] {
+        if self.len != 0 {
+            self.chaining_value = encrypt(self.cipher, self.chaining_value);
+        }
+        self.chaining_value
+    }
+}
+
+/// Block_Cipher_df, with the input being the concatenation of the given
+/// slices.
+fn block_cipher_df(input: &[&[u8]]) -> [u8; SEED_LEN] {
+    let input_len: usize = input.iter().map(|data| data.len()).sum();
+    let l = (input_len as u32).to_be_bytes();
+    let n = (SEED_LEN as u32).to_be_bytes();
+    let mut key = [0; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let cipher = Aes256::new(&key.into());
+    let mut temp = [0; SEED_LEN];
+    for (i, chunk) in temp.chunks_mut(BLOCK_LEN).enumerate() {
+        let mut bcc = Bcc {
+            cipher: &cipher,
+            chaining_value: [0; BLOCK_LEN],
+            len: 0,
+        };
+        let mut iv = [0; BLOCK_LEN];
+        iv[..4].copy_from_slice(&(i as u32).to_be_bytes());
+        bcc.update(&iv);
+        bcc.update(&l);
+        bcc.update(&n);
+        for data in input {
+            bcc.update(data);
+        }
+        bcc.update(&[0x80]);
+        chunk.copy_from_slice(&bcc.finish());
+    }
+    let cipher = Aes256::new_from_slice(&temp[..32]).unwrap();
+    let mut x = [0; BLOCK_LEN];
+    x.copy_from_slice(&temp[32..]);
+    let mut result = [0; SEED_LEN];
+    for chunk in result.chunks_mut(BLOCK_LEN) {
+        x = encrypt(&cipher, x);
+        chunk.copy_from_slice(&x);
+    }
+    result
+}
+
+impl CtrAes256 {
+    /// CTR_DRBG_Update.
+    fn update(&mut self, provided_data: &[u8; SEED_LEN]) {
+        let mut temp = [0; SEED_LEN];
+        for chunk in temp.chunks_mut(BLOCK_LEN) {
+            increment(&mut self.v);
+            chunk.copy_from_slice(&encrypt(&self.cipher, self.v));
+        }
+        for (t, data) in temp.iter_mut().zip(provided_data.iter()) {
+            *t ^= *data;
+        }
+        self.cipher = Aes256::new_from_slice(&temp[..32]).unwrap();
+        self.v.copy_from_slice(&temp[32..]);
+    }
+}
+
+impl Mechanism for CtrAes256 {
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+        let mut state = CtrAes256 {
+            cipher: Aes256::new(&[0; 32].into()),
+            v: [0; BLOCK_LEN],
+        };
+        state.update(&block_cipher_df(&[
+            entropy_input,
+            nonce,
+            personalization_string,
+        ]));
+        state
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        self.update(&block_cipher_df(&[entropy_input, additional_input]));
+    }
+
+    fn generate(&mut self, dst: &mut [u8], additional_input: &[u8], _reseed_counter: u64) {
+        let additional_input = if additional_input.is_empty() {
+            [0; SEED_LEN]
+        } else {
+            let additional_input = block_cipher_df(&[additional_input]);
+            self.update(&additional_input);
+            additional_input
+        };
+        for chunk in dst.chunks_mut(BLOCK_LEN) {
+            increment(&mut self.v);
+            chunk.copy_from_slice(&encrypt(&self.cipher, self.v)[..chunk.len()]);
+        }
+        self.update(&additional_input);
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for CtrAes256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CtrAes256").finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the internal state, replacing the key by the all-zero key.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for CtrAes256 {
+    fn zeroize(&mut self) {
+        self.cipher = Aes256::new(&[0; 32].into());
+        self.v.zeroize();
+    }
+}
+
+/// The cipher zeroizes its key schedule on drop itself.
+#[cfg(feature = "zeroize")]
+impl Drop for CtrAes256 {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.v);
+    }
+}
+
+/// The internal state is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for CtrAes256 {}
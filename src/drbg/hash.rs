@@ -0,0 +1,107 @@
+use super::Mechanism;
+use core::fmt;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Length of the state values in bytes (`seedlen` of SHA-256).
+const SEED_LEN: usize = 55;
+
+/// The Hash_DRBG mechanism of NIST SP 800-90A, Section 10.1.1, using SHA-256.
+#[derive(Clone)]
+pub struct HashSha256 {
+    v: [u8; SEED_LEN],
+    c: [u8; SEED_LEN],
+}
+
+/// Hash_df, with the input being the concatenation of the given slices.
+fn hash_df(input: &[&[u8]]) -> [u8; SEED_LEN] {
+    let mut result = [0; SEED_LEN];
+    let bits = (SEED_LEN as u32 * 8).to_be_bytes();
+    for (counter, chunk) in (1u8..).zip(result.chunks_mut(32)) {
+        let mut hasher = Sha256::new();
+        hasher.update([counter]);
+        hasher.update(bits);
+        for data in input {
+            hasher.update(data);
+        }
+        chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+    }
+    result
+}
+
+/// Hash of the concatenation of the given slices.
+fn hash(input: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for data in input {
+        hasher.update(data);
+    }
+    hasher.finalize().into()
+}
+
+/// Add the big-endian number `x` to `v`, modulo 2^seedlen.
+fn add(v: &mut [u8; SEED_LEN], x: &[u8]) {
+    let mut carry = 0;
+    for (i, byte) in v.iter_mut().rev().enumerate() {
+        let x = if i < x.len() { x[x.len() - 1 - i] } else { 0 };
+        let sum = u16::from(*byte) + u16::from(x) + carry;
+        *byte = sum as u8;
+        carry = sum >> 8;
+    }
+}
+
+impl Mechanism for HashSha256 {
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+        let v = hash_df(&[entropy_input, nonce, personalization_string]);
+        let c = hash_df(&[&[0x00], &v]);
+        HashSha256 { v, c }
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        self.v = hash_df(&[&[0x01], &self.v, entropy_input, additional_input]);
+        self.c = hash_df(&[&[0x00], &self.v]);
+    }
+
+    fn generate(&mut self, dst: &mut [u8], additional_input: &[u8], reseed_counter: u64) {
+        if !additional_input.is_empty() {
+            let w = hash(&[&[0x02], &self.v, additional_input]);
+            add(&mut self.v, &w);
+        }
+        let mut data = self.v;
+        for chunk in dst.chunks_mut(32) {
+            chunk.copy_from_slice(&hash(&[&data])[..chunk.len()]);
+            add(&mut data, &[1]);
+        }
+        let h = hash(&[&[0x03], &self.v]);
+        let c = self.c;
+        add(&mut self.v, &h);
+        add(&mut self.v, &c);
+        add(&mut self.v, &reseed_counter.to_be_bytes());
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for HashSha256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HashSha256").finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the internal state.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HashSha256 {
+    fn zeroize(&mut self) {
+        self.v.zeroize();
+        self.c.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for HashSha256 {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+/// The internal state is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for HashSha256 {}
@@ -0,0 +1,94 @@
+use super::Mechanism;
+use core::fmt;
+use hmac::Hmac;
+use hmac::Mac;
+use sha2::Sha256;
+
+/// The HMAC_DRBG mechanism of NIST SP 800-90A, Section 10.1.2, using SHA-256.
+#[derive(Clone)]
+pub struct HmacSha256 {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl HmacSha256 {
+    fn mac(&self) -> Hmac<Sha256> {
+        Hmac::new_from_slice(&self.k).expect("HMAC accepts keys of any length")
+    }
+
+    /// HMAC_DRBG_Update, with `provided_data` being the concatenation of the
+    /// given slices.
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        let empty = provided_data.iter().all(|data| data.is_empty());
+        for byte in [0x00, 0x01] {
+            let mut mac = self.mac();
+            mac.update(&self.v);
+            mac.update(&[byte]);
+            for data in provided_data {
+                mac.update(data);
+            }
+            self.k = mac.finalize().into_bytes().into();
+            let mut mac = self.mac();
+            mac.update(&self.v);
+            self.v = mac.finalize().into_bytes().into();
+            if empty {
+                break;
+            }
+        }
+    }
+}
+
+impl Mechanism for HmacSha256 {
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+        let mut state = HmacSha256 {
+            k: [0x00; 32],
+            v: [0x01; 32],
+        };
+        state.update(&[entropy_input, nonce, personalization_string]);
+        state
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        self.update(&[entropy_input, additional_input]);
+    }
+
+    fn generate(&mut self, dst: &mut [u8], additional_input: &[u8], _reseed_counter: u64) {
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in dst.chunks_mut(32) {
+            let mut mac = self.mac();
+            mac.update(&self.v);
+            self.v = mac.finalize().into_bytes().into();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for HmacSha256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacSha256").finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the internal state.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HmacSha256 {
+    fn zeroize(&mut self) {
+        self.k.zeroize();
+        self.v.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for HmacSha256 {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+/// The internal state is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for HmacSha256 {}
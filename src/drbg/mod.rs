@@ -0,0 +1,567 @@
+//! Deterministic random bit generators (DRBGs) of NIST SP 800-90A.
+//!
+//! [`HmacDrbg`], [`HashDrbg`] and [`CtrDrbg`] implement HMAC_DRBG and
+//! Hash_DRBG using SHA-256 and CTR_DRBG using AES-256 with the derivation
+//! function, all with a security strength of 256 bits. They implement the RNG,
+//! crypto RNG and seedable RNG traits of every selected `rand_core`/`rand`
+//! version.
+//!
+//! The DRBGs don't reseed themselves. Once the reseed interval is exhausted,
+//! they return errors with code [`error::RESEED_REQUIRED`] until
+//! [`Drbg::reseed`] is called. Use [`PredictionResistant`] to reseed from an
+//! entropy source before every request.
+
+use crate::error;
+use crate::error::Error;
+
+pub use self::ctr::CtrAes256;
+pub use self::hash::HashSha256;
+pub use self::hmac::HmacSha256;
+
+mod ctr;
+mod hash;
+mod hmac;
+
+/// HMAC_DRBG using SHA-256.
+pub type HmacDrbg = Drbg<HmacSha256>;
+/// Hash_DRBG using SHA-256.
+pub type HashDrbg = Drbg<HashSha256>;
+/// CTR_DRBG using AES-256 and the derivation function.
+pub type CtrDrbg = Drbg<CtrAes256>;
+
+/// Security strength of the DRBGs in bytes.
+///
+/// This is also the minimum length of entropy inputs.
+pub const SECURITY_STRENGTH: usize = 32;
+
+/// Maximum number of requests between reseeds.
+pub const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+
+/// Maximum number of bytes generated per request.
+///
+/// Larger outputs are split into multiple requests.
+pub const MAX_REQUEST_LEN: usize = 1 << 16;
+
+/// A DRBG mechanism of NIST SP 800-90A.
+///
+/// This covers the instantiate, reseed and generate algorithms of a mechanism,
+/// without the checks of the reseed counter and input lengths, which are
+/// done by [`Drbg`].
+pub trait Mechanism {
+    /// Instantiate the mechanism.
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self
+    where
+        Self: Sized;
+    /// Reseed the mechanism.
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]);
+    /// Generate `dst.len()` bytes of output.
+    ///
+    /// `reseed_counter` is the number of requests since the last reseed,
+    /// including this one.
+    fn generate(&mut self, dst: &mut [u8], additional_input: &[u8], reseed_counter: u64);
+}
+
+/// Seed of the DRBGs.
+///
+/// It consists of the entropy input ([`SECURITY_STRENGTH`] bytes) followed by
+/// the nonce (16 bytes) used for instantiation. This is the seed type of the
+/// seedable RNG traits of [`Drbg`].
+#[derive(Clone)]
+pub struct Seed(pub [u8; SECURITY_STRENGTH + 16]);
+
+impl Default for Seed {
+    fn default() -> Seed {
+        Seed([0; SECURITY_STRENGTH + 16])
+    }
+}
+
+impl AsRef<[u8]> for Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Zeroize the seed.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Seed {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Seed {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+/// The seed is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Seed {}
+
+/// A DRBG of NIST SP 800-90A, using the mechanism `M`.
+///
+/// This keeps track of the reseed counter, refusing to generate output once
+/// the reseed interval is exhausted.
+#[derive(Clone, Debug)]
+pub struct Drbg<M> {
+    mechanism: M,
+    reseed_counter: u64,
+    reseed_interval: u64,
+}
+
+impl<M: Mechanism> Drbg<M> {
+    /// Instantiate the DRBG.
+    ///
+    /// The reseed interval is set to [`MAX_RESEED_INTERVAL`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entropy_input` is shorter than [`SECURITY_STRENGTH`].
+    pub fn new(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Drbg<M> {
+        assert_entropy_input(entropy_input);
+        Drbg {
+            mechanism: M::instantiate(entropy_input, nonce, personalization_string),
+            reseed_counter: 1,
+            reseed_interval: MAX_RESEED_INTERVAL,
+        }
+    }
+
+    /// Reseed the DRBG, resetting the reseed counter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entropy_input` is shorter than [`SECURITY_STRENGTH`].
+    pub fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        assert_entropy_input(entropy_input);
+        self.mechanism.reseed(entropy_input, additional_input);
+        self.reseed_counter = 1;
+    }
+
+    /// Fill `dst` with output, using the optional additional input.
+    ///
+    /// Outputs longer than [`MAX_REQUEST_LEN`] are generated using multiple
+    /// requests. If the reseed interval doesn't allow for all of them, an
+    /// error with code [`error::RESEED_REQUIRED`] is returned without
+    /// generating any output.
+    pub fn generate(&mut self, dst: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        let requests = ((dst.len() + MAX_REQUEST_LEN - 1) / MAX_REQUEST_LEN).max(1) as u64;
+        if self.reseed_counter + (requests - 1) > self.reseed_interval {
+            return Err(error::RESEED_REQUIRED.into());
+        }
+        let mut start = 0;
+        loop {
+            let end = dst.len().min(start + MAX_REQUEST_LEN);
+            self.mechanism
+                .generate(&mut dst[start..end], additional_input, self.reseed_counter);
+            self.reseed_counter += 1;
+            start = end;
+            if start == dst.len() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Return the reseed counter.
+    ///
+    /// This is the number of requests since the last reseed, plus one.
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Set the maximum number of requests between reseeds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reseed_interval` is 0 or larger than
+    /// [`MAX_RESEED_INTERVAL`].
+    pub fn set_reseed_interval(&mut self, reseed_interval: u64) {
+        assert!(
+            (1..=MAX_RESEED_INTERVAL).contains(&reseed_interval),
+            "reseed_interval must be between 1 and 2^48",
+        );
+        self.reseed_interval = reseed_interval;
+    }
+
+    fn from_seed(seed: Seed) -> Drbg<M> {
+        let (entropy_input, nonce) = seed.0.split_at(SECURITY_STRENGTH);
+        Drbg::new(entropy_input, nonce, &[])
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+        self.generate(dst, &[])
+    }
+}
+
+/// Zeroize the internal state of the mechanism.
+///
+/// The reseed counter is kept, as it isn't secret.
+#[cfg(feature = "zeroize")]
+impl<M: zeroize::Zeroize> zeroize::Zeroize for Drbg<M> {
+    fn zeroize(&mut self) {
+        self.mechanism.zeroize();
+    }
+}
+
+/// The internal state of the mechanism is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<M: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Drbg<M> {}
+
+fn assert_entropy_input(entropy_input: &[u8]) {
+    assert!(
+        entropy_input.len() >= SECURITY_STRENGTH,
+        "entropy_input must be at least {SECURITY_STRENGTH} bytes",
+    );
+}
+
+/// Adapter operating a DRBG with prediction resistance.
+///
+/// Before every request, the DRBG is reseeded with [`SECURITY_STRENGTH`]
+/// bytes read from the entropy source `E`, which must provide full entropy,
+/// e.g. a raw entropy source wrapped in [`crate::Conditioned`].
+///
+/// It implements the RNG and crypto RNG traits of every selected
+/// `rand_core`/`rand` version that the entropy source implements, so wrap it
+/// in the wrapper structs of this crate, e.g.
+/// `PredictionResistant::new(drbg, TryRng09(OsRng))`.
+#[derive(Clone, Debug)]
+pub struct PredictionResistant<M, E> {
+    drbg: Drbg<M>,
+    entropy_source: E,
+}
+
+impl<M: Mechanism, E> PredictionResistant<M, E> {
+    /// Operate `drbg` with prediction resistance using `entropy_source`.
+    pub fn new(drbg: Drbg<M>, entropy_source: E) -> PredictionResistant<M, E> {
+        PredictionResistant {
+            drbg,
+            entropy_source,
+        }
+    }
+
+    /// Unwrap the DRBG and the entropy source.
+    pub fn into_inner(self) -> (Drbg<M>, E) {
+        (self.drbg, self.entropy_source)
+    }
+
+    /// Fill `dst` with output, reading entropy using `read_entropy`.
+    fn try_fill_with<F: From<Error>>(
+        &mut self,
+        dst: &mut [u8],
+        mut read_entropy: impl FnMut(&mut E, &mut [u8]) -> Result<(), F>,
+    ) -> Result<(), F> {
+        let mut start = 0;
+        loop {
+            let end = dst.len().min(start + MAX_REQUEST_LEN);
+            let mut entropy_input = [0; SECURITY_STRENGTH];
+            read_entropy(&mut self.entropy_source, &mut entropy_input)?;
+            self.drbg.reseed(&entropy_input, &[]);
+            entropy_input.fill(0);
+            self.drbg.generate(&mut dst[start..end], &[])?;
+            start = end;
+            if start == dst.len() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Drbg;
+    use super::Mechanism;
+    use super::PredictionResistant;
+    use super::Seed;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<M: Mechanism> rand_core_0_5::RngCore for Drbg<M> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_5::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_5::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Drbg::try_fill_bytes(self, dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            Ok(Drbg::try_fill_bytes(self, dst)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl<M: Mechanism> rand_core_0_5::CryptoRng for Drbg<M> {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    ///
+    /// The seed is split into entropy input and nonce.
+    impl<M: Mechanism> rand_core_0_5::SeedableRng for Drbg<M> {
+        type Seed = Seed;
+        fn from_seed(seed: Seed) -> Drbg<M> {
+            Drbg::from_seed(seed)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<M: Mechanism, E: rand_core_0_5::RngCore> rand_core_0_5::RngCore for PredictionResistant<M, E> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_5::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_5::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.try_fill_with(dst, |entropy_source, buf| {
+                entropy_source.try_fill_bytes(buf)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl<M: Mechanism, E: rand_core_0_5::RngCore> rand_core_0_5::CryptoRng
+        for PredictionResistant<M, E>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Drbg;
+    use super::Mechanism;
+    use super::PredictionResistant;
+    use super::Seed;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<M: Mechanism> rand_core_0_6::RngCore for Drbg<M> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_6::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_6::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Drbg::try_fill_bytes(self, dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            Ok(Drbg::try_fill_bytes(self, dst)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl<M: Mechanism> rand_core_0_6::CryptoRng for Drbg<M> {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    ///
+    /// The seed is split into entropy input and nonce.
+    impl<M: Mechanism> rand_core_0_6::SeedableRng for Drbg<M> {
+        type Seed = Seed;
+        fn from_seed(seed: Seed) -> Drbg<M> {
+            Drbg::from_seed(seed)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<M: Mechanism, E: rand_core_0_6::RngCore> rand_core_0_6::RngCore for PredictionResistant<M, E> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_6::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_6::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.try_fill_with(dst, |entropy_source, buf| {
+                entropy_source.try_fill_bytes(buf)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl<M: Mechanism, E: rand_core_0_6::RngCore> rand_core_0_6::CryptoRng
+        for PredictionResistant<M, E>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Drbg;
+    use super::Mechanism;
+    use super::PredictionResistant;
+    use super::Seed;
+    use crate::Error09;
+    use crate::error::Error;
+    use core::fmt;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, each call being a request to the DRBG. Use
+    /// [`rand_core_0_9::TryRngCore::unwrap_err`] to obtain an infallible RNG.
+    impl<M: Mechanism> rand_core_0_9::TryRngCore for Drbg<M> {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            Drbg::try_fill_bytes(self, &mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            Drbg::try_fill_bytes(self, &mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Drbg::try_fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    impl<M: Mechanism> rand_core_0_9::TryCryptoRng for Drbg<M> {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    ///
+    /// The seed is split into entropy input and nonce.
+    impl<M: Mechanism> rand_core_0_9::SeedableRng for Drbg<M> {
+        type Seed = Seed;
+        fn from_seed(seed: Seed) -> Drbg<M> {
+            Drbg::from_seed(seed)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// Errors of the entropy source are converted to [`crate::error::Error`]
+    /// via [`Error09`].
+    impl<M: Mechanism, E: rand_core_0_9::TryRngCore> rand_core_0_9::TryRngCore
+        for PredictionResistant<M, E>
+    where
+        E::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_fill_with(dst, |entropy_source, buf| {
+                Ok(entropy_source.try_fill_bytes(buf).map_err(Error09)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    impl<M: Mechanism, E: rand_core_0_9::TryRngCore> rand_core_0_9::TryCryptoRng
+        for PredictionResistant<M, E>
+    where
+        E::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Drbg;
+    use super::Mechanism;
+    use super::PredictionResistant;
+    use super::Seed;
+    use crate::Error010;
+    use crate::error::Error;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, each call being a request to the DRBG.
+    impl<M: Mechanism> rand_core_0_10::TryRng for Drbg<M> {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            Drbg::try_fill_bytes(self, &mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            Drbg::try_fill_bytes(self, &mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Drbg::try_fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    impl<M: Mechanism> rand_core_0_10::TryCryptoRng for Drbg<M> {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    ///
+    /// The seed is split into entropy input and nonce.
+    impl<M: Mechanism> rand_core_0_10::SeedableRng for Drbg<M> {
+        type Seed = Seed;
+        fn from_seed(seed: Seed) -> Drbg<M> {
+            Drbg::from_seed(seed)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// Errors of the entropy source are converted to [`crate::error::Error`]
+    /// via [`Error010`].
+    impl<M: Mechanism, E: rand_core_0_10::TryRng> rand_core_0_10::TryRng for PredictionResistant<M, E>
+    where
+        E::Error: Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_fill_with(dst, |entropy_source, buf| {
+                Ok(entropy_source.try_fill_bytes(buf).map_err(Error010)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    impl<M: Mechanism, E: rand_core_0_10::TryRng> rand_core_0_10::TryCryptoRng
+        for PredictionResistant<M, E>
+    where
+        E::Error: Send + Sync + 'static,
+    {
+    }
+}
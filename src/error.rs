@@ -0,0 +1,171 @@
+//! Error codes and the error type of the RNGs and adapters of this crate.
+//!
+//! The error codes are also used when converting errors between versions
+//! without `std` support.
+
+use core::fmt;
+use core::num::NonZeroU32;
+
+/// Error code when we couldn't extract an error code from the original error.
+pub const UNKNOWN: NonZeroU32 = match NonZeroU32::new(3222222222) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+/// Error code when the original error code was 0.
+pub const OS_ERROR_0: NonZeroU32 = match NonZeroU32::new(3222222223) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+/// Error code when a health test of [`crate::HealthTested`] failed.
+pub const HEALTH_TEST_FAILED: NonZeroU32 = match NonZeroU32::new(3222222224) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+/// Error code when a DRBG must be reseeded before generating more output.
+pub const RESEED_REQUIRED: NonZeroU32 = match NonZeroU32::new(3222222225) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+/// Error code when an entropy pool hasn't collected enough entropy yet.
+pub const NOT_SEEDED: NonZeroU32 = match NonZeroU32::new(3222222226) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+/// Error code when no global RNG was installed using `global::install`.
+pub const NO_GLOBAL_RNG: NonZeroU32 = match NonZeroU32::new(3222222227) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+/// Error type of the RNGs and adapters of this crate.
+///
+/// It carries one of the error codes of this module, or an error code taken
+/// from the original error. If the `std` feature is enabled, errors of wrapped
+/// RNGs are additionally kept as a whole.
+pub struct Error {
+    code: NonZeroU32,
+    #[cfg(feature = "std")]
+    inner: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl Error {
+    #[cfg(feature = "std")]
+    pub(crate) fn from_inner(inner: Box<dyn std::error::Error + Send + Sync + 'static>) -> Error {
+        Error {
+            code: UNKNOWN,
+            inner: Some(inner),
+        }
+    }
+
+    /// Return the error code.
+    ///
+    /// This is [`UNKNOWN`] for errors of wrapped RNGs that didn't carry an
+    /// error code.
+    pub fn code(&self) -> NonZeroU32 {
+        self.code
+    }
+
+    /// Reference the error of the wrapped RNG, if any.
+    #[cfg(feature = "std")]
+    pub fn inner(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        self.inner.as_deref()
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        match self.code {
+            UNKNOWN => Some("unknown error"),
+            OS_ERROR_0 => Some("OS error 0"),
+            HEALTH_TEST_FAILED => Some("entropy source health test failed"),
+            RESEED_REQUIRED => Some("DRBG reseed required"),
+            NOT_SEEDED => Some("entropy pool not seeded yet"),
+            NO_GLOBAL_RNG => Some("no global RNG installed"),
+            _ => None,
+        }
+    }
+}
+
+impl From<NonZeroU32> for Error {
+    fn from(code: NonZeroU32) -> Error {
+        Error {
+            code,
+            #[cfg(feature = "std")]
+            inner: None,
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Error");
+        debug.field("code", &self.code);
+        #[cfg(feature = "std")]
+        debug.field("inner", &self.inner);
+        debug.finish()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "std")]
+        if let Some(inner) = &self.inner {
+            return fmt::Display::fmt(inner, f);
+        }
+        match self.description() {
+            Some(description) => f.write_str(description),
+            None => write!(f, "error code {}", self.code),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.inner {
+            Some(inner) => Some(&**inner),
+            None => None,
+        }
+    }
+}
+
+#[cfg(all(feature = "rand_core_0_10", not(feature = "std")))]
+impl core::error::Error for Error {}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Error;
+
+    /// Convert to an `rand_core 0.5`/`rand 0.7` error.
+    ///
+    /// The error code is kept. If the `std` feature is enabled and there is
+    /// an error of a wrapped RNG, then this conversion is lossless by wrapping
+    /// the whole error using [`rand_core_0_5::Error::new`].
+    impl From<Error> for rand_core_0_5::Error {
+        fn from(error: Error) -> rand_core_0_5::Error {
+            #[cfg(feature = "std")]
+            if error.inner.is_some() {
+                return rand_core_0_5::Error::new(error);
+            }
+            error.code.into()
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Error;
+
+    /// Convert to an `rand_core 0.6`/`rand 0.8` error.
+    ///
+    /// The error code is kept. If the `std` feature is enabled and there is
+    /// an error of a wrapped RNG, then this conversion is lossless by wrapping
+    /// the whole error using [`rand_core_0_6::Error::new`].
+    impl From<Error> for rand_core_0_6::Error {
+        fn from(error: Error) -> rand_core_0_6::Error {
+            #[cfg(feature = "std")]
+            if error.inner.is_some() {
+                return rand_core_0_6::Error::new(error);
+            }
+            error.code.into()
+        }
+    }
+}
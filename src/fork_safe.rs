@@ -0,0 +1,287 @@
+use std::sync::Once;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// Number of `fork()`s between the first process and the current one.
+static FORK_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn bump_fork_generation() {
+    FORK_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Return the fork generation of the current process, registering the
+/// `pthread_atfork` handler incrementing it on first use.
+///
+/// Panics if registering the handler fails.
+fn fork_generation() -> usize {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        // SAFETY: The handler only increments an atomic, which is
+        // async-signal-safe.
+        let result = unsafe { libc::pthread_atfork(None, None, Some(bump_fork_generation)) };
+        assert_eq!(result, 0, "pthread_atfork failed");
+    });
+    FORK_GENERATION.load(Ordering::Relaxed)
+}
+
+/// Adapter reseeding an RNG in child processes after `fork()`.
+///
+/// A child process created using `fork()` inherits the state of the RNGs of
+/// its parent, so both would generate the same output. This wrapper records
+/// a fork generation, a counter incremented in the child by a
+/// `pthread_atfork` handler, and, if it changed before generating output,
+/// replaces the RNG `G` by a new one, seeded from the entropy source `R`.
+/// Unlike the process ID, the counter also changes in a grandchild that got
+/// the process ID of its grandparent back, and reading it is cheap.
+///
+/// Child processes created without running the `pthread_atfork` handlers,
+/// e.g. by calling the `clone` system call directly, are not detected.
+///
+/// It implements the RNG and crypto RNG traits of every selected
+/// `rand_core`/`rand` version for which `G` implements the RNG and seedable
+/// RNG traits and `R` implements the RNG trait. As with [`crate::Reseeding`],
+/// wrap RNGs of different versions in the wrapper structs of this crate, e.g.
+/// `ForkSafe::new(Rng06(ChaCha20Rng::from_entropy()), TryRng09(OsRng))`.
+#[derive(Clone, Debug)]
+pub struct ForkSafe<G, R> {
+    rng: G,
+    entropy_source: R,
+    fork_generation: usize,
+}
+
+impl<G, R> ForkSafe<G, R> {
+    /// Reseed `rng` from `entropy_source` in child processes.
+    ///
+    /// Panics if registering the `pthread_atfork` handler fails.
+    pub fn new(rng: G, entropy_source: R) -> ForkSafe<G, R> {
+        ForkSafe {
+            rng,
+            entropy_source,
+            fork_generation: fork_generation(),
+        }
+    }
+
+    /// Reference the RNG and the entropy source.
+    pub fn inner(&self) -> (&G, &R) {
+        (&self.rng, &self.entropy_source)
+    }
+
+    /// Unwrap the RNG and the entropy source.
+    pub fn into_inner(self) -> (G, R) {
+        (self.rng, self.entropy_source)
+    }
+
+    /// Reseed using `reseed` if the fork generation changed, then generate
+    /// output using `generate`.
+    fn try_generate<V, E>(
+        &mut self,
+        reseed: impl FnOnce(&mut R) -> Result<G, E>,
+        generate: impl FnOnce(&mut G) -> Result<V, E>,
+    ) -> Result<V, E> {
+        let fork_generation = fork_generation();
+        if fork_generation != self.fork_generation {
+            self.rng = reseed(&mut self.entropy_source)?;
+            self.fork_generation = fork_generation;
+        }
+        generate(&mut self.rng)
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::ForkSafe;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on reseed errors if `next_u32`, `next_u64` or `fill_bytes` is
+    /// called.
+    impl<G, R> rand_core_0_5::RngCore for ForkSafe<G, R>
+    where
+        G: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng,
+        R: rand_core_0_5::RngCore,
+    {
+        fn next_u32(&mut self) -> u32 {
+            self.try_generate(
+                |entropy_source| G::from_rng(entropy_source),
+                |rng| Ok(rng.next_u32()),
+            )
+            .unwrap()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.try_generate(
+                |entropy_source| G::from_rng(entropy_source),
+                |rng| Ok(rng.next_u64()),
+            )
+            .unwrap()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.try_generate(
+                |entropy_source| G::from_rng(entropy_source),
+                |rng| rng.try_fill_bytes(dst),
+            )
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl<G, R> rand_core_0_5::CryptoRng for ForkSafe<G, R>
+    where
+        G: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng + rand_core_0_5::CryptoRng,
+        R: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::ForkSafe;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on reseed errors if `next_u32`, `next_u64` or `fill_bytes` is
+    /// called.
+    impl<G, R> rand_core_0_6::RngCore for ForkSafe<G, R>
+    where
+        G: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng,
+        R: rand_core_0_6::RngCore,
+    {
+        fn next_u32(&mut self) -> u32 {
+            self.try_generate(
+                |entropy_source| G::from_rng(entropy_source),
+                |rng| Ok(rng.next_u32()),
+            )
+            .unwrap()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.try_generate(
+                |entropy_source| G::from_rng(entropy_source),
+                |rng| Ok(rng.next_u64()),
+            )
+            .unwrap()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.try_generate(
+                |entropy_source| G::from_rng(entropy_source),
+                |rng| rng.try_fill_bytes(dst),
+            )
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl<G, R> rand_core_0_6::CryptoRng for ForkSafe<G, R>
+    where
+        G: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng + rand_core_0_6::CryptoRng,
+        R: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::ForkSafe;
+    use crate::Error09;
+    use crate::error::Error;
+    use core::fmt;
+
+    fn reseed<G, R>(entropy_source: &mut R) -> Result<G, Error>
+    where
+        G: rand_core_0_9::SeedableRng,
+        R: rand_core_0_9::TryRngCore,
+        R::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        Ok(G::try_from_rng(entropy_source).map_err(Error09)?)
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// Errors of the RNG and the entropy source are converted to
+    /// [`crate::error::Error`] via [`Error09`].
+    impl<G, R> rand_core_0_9::TryRngCore for ForkSafe<G, R>
+    where
+        G: rand_core_0_9::TryRngCore + rand_core_0_9::SeedableRng,
+        G::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+        R: rand_core_0_9::TryRngCore,
+        R::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            self.try_generate(reseed, |rng| Ok(rng.try_next_u32().map_err(Error09)?))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            self.try_generate(reseed, |rng| Ok(rng.try_next_u64().map_err(Error09)?))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_generate(reseed, |rng| {
+                Ok(rng.try_fill_bytes(dst).map_err(Error09)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    impl<G, R> rand_core_0_9::TryCryptoRng for ForkSafe<G, R>
+    where
+        G: rand_core_0_9::TryCryptoRng + rand_core_0_9::SeedableRng,
+        G::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+        R: rand_core_0_9::TryCryptoRng,
+        R::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::ForkSafe;
+    use crate::Error010;
+    use crate::error::Error;
+
+    fn reseed<G, R>(entropy_source: &mut R) -> Result<G, Error>
+    where
+        G: rand_core_0_10::SeedableRng,
+        R: rand_core_0_10::TryRng,
+        R::Error: Send + Sync + 'static,
+    {
+        Ok(G::try_from_rng(entropy_source).map_err(Error010)?)
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// Errors of the RNG and the entropy source are converted to
+    /// [`crate::error::Error`] via [`Error010`].
+    impl<G, R> rand_core_0_10::TryRng for ForkSafe<G, R>
+    where
+        G: rand_core_0_10::TryRng + rand_core_0_10::SeedableRng,
+        G::Error: Send + Sync + 'static,
+        R: rand_core_0_10::TryRng,
+        R::Error: Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            self.try_generate(reseed, |rng| Ok(rng.try_next_u32().map_err(Error010)?))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            self.try_generate(reseed, |rng| Ok(rng.try_next_u64().map_err(Error010)?))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_generate(reseed, |rng| {
+                Ok(rng.try_fill_bytes(dst).map_err(Error010)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    impl<G, R> rand_core_0_10::TryCryptoRng for ForkSafe<G, R>
+    where
+        G: rand_core_0_10::TryCryptoRng + rand_core_0_10::SeedableRng,
+        G::Error: Send + Sync + 'static,
+        R: rand_core_0_10::TryCryptoRng,
+        R::Error: Send + Sync + 'static,
+    {
+    }
+}
@@ -0,0 +1,314 @@
+use crate::error;
+use crate::error::Error;
+use aes::Aes256;
+use aes::cipher::BlockEncrypt;
+use aes::cipher::KeyInit;
+use core::fmt;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Number of entropy pools.
+const NUM_POOLS: usize = 32;
+/// Length of the blocks of the generator in bytes.
+const BLOCK_LEN: usize = 16;
+/// Maximum number of bytes generated before the generator is rekeyed.
+const MAX_REQUEST_LEN: usize = 1 << 20;
+
+/// Fortuna entropy accumulator and generator.
+///
+/// Entropy events are added using [`Fortuna::add_entropy`], which distributes
+/// the events of each source over 32 pools. Once the first pool has collected
+/// at least [`Fortuna::MIN_POOL_SIZE`] bytes, the generator is reseeded from
+/// the pools on the next request, as described by Ferguson and Schneier in
+/// "Cryptography Engineering". The generator uses AES-256 in counter mode,
+/// rekeying after every request.
+///
+/// Until the first reseed, requests fail with the error code
+/// [`error::NOT_SEEDED`]. Reseeds are limited to one every 100 ms if the
+/// `std` feature is enabled. Without it, there's no clock to limit them.
+///
+/// It implements the RNG and crypto RNG traits of every selected
+/// `rand_core`/`rand` version.
+pub struct Fortuna {
+    key: [u8; 32],
+    counter: u128,
+    pools: [Sha256; NUM_POOLS],
+    pool0_len: usize,
+    reseed_count: u32,
+    next_pool: [u8; 256],
+    #[cfg(feature = "std")]
+    last_reseed: Option<std::time::Instant>,
+}
+
+/// Finish the SHA-256d hash of Fortuna, i.e. SHA-256 applied twice.
+fn sha256d(hasher: Sha256) -> [u8; 32] {
+    Sha256::digest(hasher.finalize()).into()
+}
+
+impl Fortuna {
+    /// Number of bytes the first pool must have collected before reseeding.
+    pub const MIN_POOL_SIZE: usize = 64;
+
+    /// Maximum length of the data of a single entropy event.
+    pub const MAX_EVENT_LEN: usize = 32;
+
+    /// Create an unseeded entropy pool.
+    pub fn new() -> Fortuna {
+        Fortuna {
+            key: [0; 32],
+            counter: 0,
+            pools: core::array::from_fn(|_| Sha256::new()),
+            pool0_len: 0,
+            reseed_count: 0,
+            next_pool: [0; 256],
+            #[cfg(feature = "std")]
+            last_reseed: None,
+        }
+    }
+
+    /// Add an entropy event of the source `source_id`.
+    ///
+    /// Each source should use its own ID, so that its events are spread
+    /// evenly over the pools.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is empty or longer than [`Fortuna::MAX_EVENT_LEN`].
+    pub fn add_entropy(&mut self, source_id: u8, data: &[u8]) {
+        assert!(
+            (1..=Fortuna::MAX_EVENT_LEN).contains(&data.len()),
+            "entropy events must consist of 1 to 32 bytes",
+        );
+        let pool = usize::from(self.next_pool[usize::from(source_id)]);
+        self.next_pool[usize::from(source_id)] = ((pool + 1) % NUM_POOLS) as u8;
+        self.pools[pool].update([source_id, data.len() as u8]);
+        self.pools[pool].update(data);
+        if pool == 0 {
+            self.pool0_len += 2 + data.len();
+        }
+    }
+
+    /// Return whether the generator has been seeded.
+    pub fn is_seeded(&self) -> bool {
+        self.counter != 0
+    }
+
+    /// Return the number of reseeds so far.
+    pub fn reseed_count(&self) -> u32 {
+        self.reseed_count
+    }
+
+    fn reseed_due(&mut self) -> bool {
+        if self.pool0_len < Fortuna::MIN_POOL_SIZE {
+            return false;
+        }
+        #[cfg(feature = "std")]
+        {
+            let now = std::time::Instant::now();
+            if let Some(last_reseed) = self.last_reseed {
+                if now.duration_since(last_reseed) < std::time::Duration::from_millis(100) {
+                    return false;
+                }
+            }
+            self.last_reseed = Some(now);
+        }
+        true
+    }
+
+    /// Reseed the generator from pool `i` if 2^i divides the reseed count.
+    fn reseed(&mut self) {
+        self.reseed_count = self.reseed_count.wrapping_add(1);
+        let last_pool = (self.reseed_count.trailing_zeros() as usize).min(NUM_POOLS - 1);
+        let mut hasher = Sha256::new();
+        hasher.update(self.key);
+        for pool in &mut self.pools[..=last_pool] {
+            hasher.update(sha256d(core::mem::take(pool)));
+        }
+        self.key = sha256d(hasher);
+        self.counter = self.counter.wrapping_add(1);
+        self.pool0_len = 0;
+    }
+
+    fn generate_blocks(&mut self, cipher: &Aes256, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(BLOCK_LEN) {
+            let mut block = self.counter.to_le_bytes().into();
+            cipher.encrypt_block(&mut block);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            self.counter = self.counter.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+        if self.reseed_due() {
+            self.reseed();
+        }
+        if !self.is_seeded() {
+            return Err(error::NOT_SEEDED.into());
+        }
+        for request in dst.chunks_mut(MAX_REQUEST_LEN) {
+            let cipher = Aes256::new(&self.key.into());
+            self.generate_blocks(&cipher, request);
+            let mut key = [0; 32];
+            self.generate_blocks(&cipher, &mut key);
+            self.key = key;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Fortuna {
+    fn default() -> Fortuna {
+        Fortuna::new()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for Fortuna {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fortuna")
+            .field("reseed_count", &self.reseed_count)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the key and the counter, leaving the generator unseeded, and reset
+/// the pools. `sha2` can't zeroize hash states, so the pools are only replaced
+/// by empty ones.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fortuna {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.counter.zeroize();
+        for pool in &mut self.pools {
+            *pool = Sha256::new();
+        }
+        self.pool0_len.zeroize();
+        self.reseed_count.zeroize();
+        self.next_pool.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Fortuna {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+/// The key is zeroized and the pools are reset on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Fortuna {}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Fortuna;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl rand_core_0_5::RngCore for Fortuna {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_5::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_5::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Fortuna::try_fill_bytes(self, dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            Ok(Fortuna::try_fill_bytes(self, dst)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for Fortuna {}
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Fortuna;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl rand_core_0_6::RngCore for Fortuna {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_6::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_6::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Fortuna::try_fill_bytes(self, dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            Ok(Fortuna::try_fill_bytes(self, dst)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for Fortuna {}
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Fortuna;
+    use crate::error::Error;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, each call being a request to the generator.
+    impl rand_core_0_9::TryRngCore for Fortuna {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            Fortuna::try_fill_bytes(self, &mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            Fortuna::try_fill_bytes(self, &mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Fortuna::try_fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    impl rand_core_0_9::TryCryptoRng for Fortuna {}
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Fortuna;
+    use crate::error::Error;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, each call being a request to the generator.
+    impl rand_core_0_10::TryRng for Fortuna {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            Fortuna::try_fill_bytes(self, &mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            Fortuna::try_fill_bytes(self, &mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Fortuna::try_fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for Fortuna {}
+}
@@ -0,0 +1,246 @@
+//! A global RNG for `no_std` targets, guarded by a critical section.
+//!
+//! An RNG is installed once at boot using [`install`]. Afterwards, [`Global`]
+//! handles can be created anywhere and implement the fallible RNG traits of
+//! every selected `rand_core`/`rand` version, forwarding to the installed RNG
+//! inside a critical section. [`with_rng`] runs a closure with a handle inside
+//! a single critical section.
+//!
+//! If no RNG is installed, the handles return errors with the code
+//! [`error::NO_GLOBAL_RNG`]. Errors of the installed RNG are converted to
+//! [`Error`].
+
+use crate::error;
+use crate::error::Error;
+use core::cell::RefCell;
+
+static GLOBAL_RNG: critical_section::Mutex<RefCell<Option<&'static mut dyn GlobalRng>>> =
+    critical_section::Mutex::new(RefCell::new(None));
+
+/// An RNG that can be installed as the global RNG.
+///
+/// This is implemented for the wrapper structs of this crate, e.g. install
+/// a `rand_core 0.6`/`rand 0.8` RNG as `Rng06<T>`.
+pub trait GlobalRng: Send {
+    /// Fill `dst` with random data.
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error>;
+}
+
+/// Install `rng` as the global RNG.
+///
+/// The previously installed RNG, if any, is returned.
+pub fn install(rng: &'static mut dyn GlobalRng) -> Option<&'static mut dyn GlobalRng> {
+    critical_section::with(|cs| GLOBAL_RNG.borrow_ref_mut(cs).replace(rng))
+}
+
+/// Run `f` with a handle to the global RNG inside a single critical section.
+pub fn with_rng<V>(f: impl FnOnce(&mut Global) -> V) -> V {
+    critical_section::with(|_| f(&mut Global))
+}
+
+/// Handle to the global RNG.
+///
+/// Every call enters a critical section and forwards to the RNG installed
+/// using [`install`]. Since the type of the installed RNG isn't known, the
+/// handle doesn't implement the crypto RNG traits.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+impl Global {
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+        critical_section::with(|cs| match GLOBAL_RNG.borrow_ref_mut(cs).as_mut() {
+            Some(rng) => rng.try_fill_bytes(dst),
+            None => Err(error::NO_GLOBAL_RNG.into()),
+        })
+    }
+
+    fn try_next_u32(&mut self) -> Result<u32, Error> {
+        let mut buf = [0; 4];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Error> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Global;
+    use super::GlobalRng;
+    use crate::Error05;
+    use crate::Rng05;
+    use crate::TryRng05;
+    use crate::error::Error;
+
+    /// Install a `rand_core 0.5`/`rand 0.7` RNG.
+    impl<T: rand_core_0_5::RngCore + Send> GlobalRng for Rng05<T> {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Ok(self.0.try_fill_bytes(dst).map_err(Error05)?)
+        }
+    }
+
+    /// Install a `rand_core 0.5`/`rand 0.7` fallible RNG.
+    impl<T: rand_core_0_5::RngCore + Send> GlobalRng for TryRng05<T> {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Ok(self.0.try_fill_bytes(dst).map_err(Error05)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl rand_core_0_5::RngCore for Global {
+        fn next_u32(&mut self) -> u32 {
+            self.try_next_u32().unwrap()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.try_next_u64().unwrap()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Global::try_fill_bytes(self, dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            Ok(Global::try_fill_bytes(self, dst)?)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Global;
+    use super::GlobalRng;
+    use crate::Error06;
+    use crate::Rng06;
+    use crate::TryRng06;
+    use crate::error::Error;
+
+    /// Install a `rand_core 0.6`/`rand 0.8` RNG.
+    impl<T: rand_core_0_6::RngCore + Send> GlobalRng for Rng06<T> {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Ok(self.0.try_fill_bytes(dst).map_err(Error06)?)
+        }
+    }
+
+    /// Install a `rand_core 0.6`/`rand 0.8` fallible RNG.
+    impl<T: rand_core_0_6::RngCore + Send> GlobalRng for TryRng06<T> {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Ok(self.0.try_fill_bytes(dst).map_err(Error06)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl rand_core_0_6::RngCore for Global {
+        fn next_u32(&mut self) -> u32 {
+            self.try_next_u32().unwrap()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.try_next_u64().unwrap()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Global::try_fill_bytes(self, dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            Ok(Global::try_fill_bytes(self, dst)?)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Global;
+    use super::GlobalRng;
+    use crate::Error09;
+    use crate::Rng09;
+    use crate::TryRng09;
+    use crate::error::Error;
+    use core::fmt;
+
+    /// Install a `rand_core 0.9`/`rand 0.9` RNG.
+    impl<T: rand_core_0_9::RngCore + Send> GlobalRng for Rng09<T> {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Install a `rand_core 0.9`/`rand 0.9` fallible RNG.
+    impl<T: rand_core_0_9::TryRngCore + Send> GlobalRng for TryRng09<T>
+    where
+        T::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Ok(self.0.try_fill_bytes(dst).map_err(Error09)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, since the installed RNG only provides the latter. Use
+    /// [`rand_core_0_9::TryRngCore::unwrap_err`] to obtain an infallible RNG.
+    impl rand_core_0_9::TryRngCore for Global {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            Global::try_next_u32(self)
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            Global::try_next_u64(self)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Global::try_fill_bytes(self, dst)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Global;
+    use super::GlobalRng;
+    use crate::Error010;
+    use crate::Rng010;
+    use crate::TryRng010;
+    use crate::error::Error;
+
+    /// Install a `rand_core 0.10`/`rand 0.10` RNG.
+    impl<T: rand_core_0_10::Rng + Send> GlobalRng for Rng010<T> {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Install a `rand_core 0.10`/`rand 0.10` fallible RNG.
+    impl<T: rand_core_0_10::TryRng + Send> GlobalRng for TryRng010<T>
+    where
+        T::Error: Send + Sync + 'static,
+    {
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Ok(self.0.try_fill_bytes(dst).map_err(Error010)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// `try_next_u32`/`try_next_u64` are implemented in terms of
+    /// `try_fill_bytes`, since the installed RNG only provides the latter.
+    impl rand_core_0_10::TryRng for Global {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            Global::try_next_u32(self)
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            Global::try_next_u64(self)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            Global::try_fill_bytes(self, dst)
+        }
+    }
+}
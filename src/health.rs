@@ -0,0 +1,307 @@
+use crate::error;
+use core::num::NonZeroU32;
+
+/// Number of samples in a window of the adaptive proportion test.
+const APT_WINDOW: u32 = 512;
+
+/// Cutoffs of the repetition count test for a min-entropy of 1 to 8 bits per
+/// byte, with a false positive probability of 2^-20.
+const RCT_CUTOFFS: [u32; 8] = [21, 11, 8, 6, 5, 5, 4, 4];
+
+/// Cutoffs of the adaptive proportion test for a min-entropy of 1 to 8 bits
+/// per byte, with a false positive probability of 2^-20.
+const APT_CUTOFFS: [u32; 8] = [311, 177, 103, 62, 39, 25, 18, 13];
+
+/// Adapter running continuous health tests on the output of an entropy source.
+///
+/// The repetition count test and the adaptive proportion test of NIST SP
+/// 800-90B, Section 4.4 are run on every byte read from the wrapped RNG. Once
+/// a test fails, the adapter stays in the failed state, returning errors with
+/// code [`error::HEALTH_TEST_FAILED`] until [`HealthTested::reset`] is called.
+///
+/// It implements the fallible RNG traits of every selected `rand_core`/`rand`
+/// version that the wrapped RNG implements, so wrap RNGs in the wrapper
+/// structs of this crate, e.g. `HealthTested::new(TryRng09(t), 4)`. For
+/// `rand_core 0.9`/`rand 0.9` and `rand_core 0.10`/`rand 0.10`, errors are
+/// reported via [`crate::error::Error`].
+#[derive(Clone, Debug)]
+pub struct HealthTested<T> {
+    inner: T,
+    rct_cutoff: u32,
+    apt_cutoff: u32,
+    failed: bool,
+    rct_sample: u8,
+    /// Number of repetitions of `rct_sample`, 0 before the first sample.
+    rct_count: u32,
+    apt_sample: u8,
+    /// Number of occurrences of `apt_sample` in the current window.
+    apt_count: u32,
+    /// Number of samples in the current window.
+    apt_samples: u32,
+}
+
+impl<T> HealthTested<T> {
+    /// Wrap an entropy source with the given min-entropy per byte.
+    ///
+    /// `min_entropy` is the assessed min-entropy in bits per byte output by
+    /// `inner`. The test cutoffs are chosen for a false positive probability
+    /// of 2^-20 per test.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_entropy` is not between 1 and 8. Use
+    /// [`HealthTested::with_cutoffs`] for non-integer min-entropy.
+    pub fn new(inner: T, min_entropy: u32) -> HealthTested<T> {
+        assert!(
+            (1..=8).contains(&min_entropy),
+            "min_entropy must be between 1 and 8 bits per byte",
+        );
+        let i = min_entropy as usize - 1;
+        HealthTested::with_cutoffs(inner, RCT_CUTOFFS[i], APT_CUTOFFS[i])
+    }
+
+    /// Wrap an entropy source using explicit test cutoffs.
+    ///
+    /// `rct_cutoff` is the number of identical consecutive bytes failing the
+    /// repetition count test. `apt_cutoff` is the number of occurrences of the
+    /// first byte of a window of 512 bytes failing the adaptive proportion
+    /// test. See NIST SP 800-90B, Section 4.4 for how to compute them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rct_cutoff` or `apt_cutoff` is less than 2.
+    pub fn with_cutoffs(inner: T, rct_cutoff: u32, apt_cutoff: u32) -> HealthTested<T> {
+        assert!(rct_cutoff >= 2, "rct_cutoff must be at least 2");
+        assert!(apt_cutoff >= 2, "apt_cutoff must be at least 2");
+        HealthTested {
+            inner,
+            rct_cutoff,
+            apt_cutoff,
+            failed: false,
+            rct_sample: 0,
+            rct_count: 0,
+            apt_sample: 0,
+            apt_count: 0,
+            apt_samples: 0,
+        }
+    }
+
+    /// Check whether a health test failed.
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    /// Leave the failed state and restart the health tests.
+    pub fn reset(&mut self) {
+        self.failed = false;
+        self.rct_count = 0;
+        self.apt_samples = 0;
+    }
+
+    /// Reference the wrapped RNG.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the wrapped RNG.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Fill `dst` using `fill` and run the health tests on the result.
+    ///
+    /// On failure, `dst` is zeroed.
+    fn try_fill_with<E: From<NonZeroU32>>(
+        &mut self,
+        dst: &mut [u8],
+        fill: impl FnOnce(&mut T, &mut [u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if self.failed {
+            return Err(error::HEALTH_TEST_FAILED.into());
+        }
+        fill(&mut self.inner, dst)?;
+        for &sample in dst.iter() {
+            if !self.test(sample) {
+                self.failed = true;
+                dst.fill(0);
+                return Err(error::HEALTH_TEST_FAILED.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Run both health tests on one sample, returning whether they passed.
+    fn test(&mut self, sample: u8) -> bool {
+        if self.rct_count > 0 && sample == self.rct_sample {
+            self.rct_count += 1;
+            if self.rct_count >= self.rct_cutoff {
+                return false;
+            }
+        } else {
+            self.rct_sample = sample;
+            self.rct_count = 1;
+        }
+
+        if self.apt_samples == 0 {
+            self.apt_sample = sample;
+            self.apt_count = 1;
+        } else if sample == self.apt_sample {
+            self.apt_count += 1;
+            if self.apt_count >= self.apt_cutoff {
+                return false;
+            }
+        }
+        self.apt_samples += 1;
+        if self.apt_samples == APT_WINDOW {
+            self.apt_samples = 0;
+        }
+        true
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::HealthTested;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for HealthTested<T> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_5::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_5::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.try_fill_with(dst, |inner, buf| inner.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    ///
+    /// Since the trait has the same shape, it forwards perfectly.
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng
+        for HealthTested<T>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::HealthTested;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on error if `next_u32`, `next_u64` or `fill_bytes` is called.
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for HealthTested<T> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_6::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_6::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.try_fill_with(dst, |inner, buf| inner.try_fill_bytes(buf))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    ///
+    /// Since the trait has the same shape, it forwards perfectly.
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng
+        for HealthTested<T>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::HealthTested;
+    use crate::Error09;
+    use crate::error::Error;
+    use core::fmt;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// Errors of the wrapped RNG are converted to [`crate::error::Error`] via
+    /// [`Error09`].
+    impl<T: rand_core_0_9::TryRngCore> rand_core_0_9::TryRngCore for HealthTested<T>
+    where
+        T::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_fill_with(dst, |inner, buf| {
+                Ok(inner.try_fill_bytes(buf).map_err(Error09)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    ///
+    /// Since the trait has the same shape, it forwards perfectly.
+    impl<T: rand_core_0_9::TryCryptoRng> rand_core_0_9::TryCryptoRng for HealthTested<T> where
+        T::Error: fmt::Debug + fmt::Display + Send + Sync + 'static
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::HealthTested;
+    use crate::Error010;
+    use crate::error::Error;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// Errors of the wrapped RNG are converted to [`crate::error::Error`] via
+    /// [`Error010`].
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for HealthTested<T>
+    where
+        T::Error: Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            let mut buf = [0; 4];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u32::from_le_bytes(buf))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_fill_with(dst, |inner, buf| {
+                Ok(inner.try_fill_bytes(buf).map_err(Error010)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    ///
+    /// Since the trait has the same shape, it forwards perfectly.
+    impl<T: rand_core_0_10::TryCryptoRng> rand_core_0_10::TryCryptoRng for HealthTested<T> where
+        T::Error: Send + Sync + 'static
+    {
+    }
+}
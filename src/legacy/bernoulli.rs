@@ -0,0 +1,68 @@
+//! Sampling of booleans with a given probability, as in `rand 0.7` to
+//! `rand 0.10`.
+//!
+//! `Bernoulli` is the same in all these versions: it scales the probability to
+//! a 64-bit threshold and compares a random `u64` against it. A probability of
+//! 1 always returns `true` without using the RNG.
+
+use super::RandVersion;
+use super::WrappedRng;
+
+/// Threshold meaning a probability of 1.
+const ALWAYS_TRUE: u64 = u64::MAX;
+/// 2^64 as a float.
+const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
+
+/// Return `true` with probability `p` like `gen_bool` of the given `rand`
+/// version.
+///
+/// This reproduces `Rng::gen_bool` of `rand 0.7` and `rand 0.8` and
+/// `Rng::random_bool` of `rand 0.9` and `rand 0.10`.
+///
+/// Panics if `p` isn't in `[0, 1]`.
+pub fn gen_bool<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, p: f64) -> bool {
+    let threshold = if (0.0..1.0).contains(&p) {
+        (p * SCALE) as u64
+    } else if p == 1.0 {
+        ALWAYS_TRUE
+    } else {
+        panic!("p={:?} is outside range [0.0, 1.0]", p);
+    };
+    sample(version, rng, threshold)
+}
+
+/// Return `true` with probability `numerator / denominator` like `gen_ratio`
+/// of the given `rand` version.
+///
+/// This reproduces `Rng::gen_ratio` of `rand 0.7` and `rand 0.8` and
+/// `Rng::random_ratio` of `rand 0.9` and `rand 0.10`.
+///
+/// Panics if `numerator > denominator` or `denominator == 0`.
+pub fn gen_ratio<R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    numerator: u32,
+    denominator: u32,
+) -> bool {
+    assert!(
+        numerator <= denominator && denominator != 0,
+        "p={}/{} is outside range [0.0, 1.0]",
+        numerator,
+        denominator,
+    );
+    let threshold = if numerator == denominator {
+        ALWAYS_TRUE
+    } else {
+        ((f64::from(numerator) / f64::from(denominator)) * SCALE) as u64
+    };
+    sample(version, rng, threshold)
+}
+
+/// Sample `Bernoulli` with the given threshold.
+fn sample<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, threshold: u64) -> bool {
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => {
+            threshold == ALWAYS_TRUE || rng.next_u64() < threshold
+        }
+    }
+}
@@ -0,0 +1,1020 @@
+//! Sampling of common distributions of `rand_distr`, as used with `rand 0.7`
+//! to `rand 0.10`.
+//!
+//! The [`RandVersion`] selects the `rand_distr` version released for it:
+//! `rand_distr 0.2` for `rand 0.7`, `rand_distr 0.4` for `rand 0.8`,
+//! `rand_distr 0.5` for `rand 0.9` and `rand_distr 0.6` for `rand 0.10`. The
+//! distributions reproduce the `f64` variants; `rand_distr` samples `f32` with
+//! `f32` arithmetic, which isn't reproduced.
+//!
+//! [`Normal`] and [`Exp`] use the same ziggurat algorithm and tables in all
+//! versions and only differ in the parameters they accept. [`Gamma`] uses
+//! Marsaglia and Tsang's method, which `rand_distr 0.6` scales in a different
+//! order. For `lambda >= 12`, [`Poisson`] switched from rejection sampling
+//! with a Cauchy distribution to Ahrens and Dieter's method in
+//! `rand_distr 0.5`, which also changed how [`Binomial`] treats small `n * p`.
+//! [`Binomial`] samples floats from ranges like [`Uniform`] of the respective
+//! `rand` version.
+//!
+//! Like `rand_distr`, the distributions use `ln`, `exp` and the other
+//! functions of `f64` of the standard library, whose last bits may differ
+//! between platforms.
+
+use self::ziggurat_tables::ZigTable;
+use super::RandVersion;
+use super::WrappedRng;
+use super::float;
+use super::uniform::Uniform;
+use core::cmp::Ordering;
+use core::f64::consts::PI;
+use core::fmt;
+
+mod ziggurat_tables;
+
+/// Sample from the standard normal distribution, like `StandardNormal` of
+/// the `rand_distr` version of the given `rand` version.
+pub fn standard_normal<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R) -> f64 {
+    let zero_case = |rng: &mut R, u: f64| {
+        // Sample from the tail, with initial values entering the loop.
+        let mut x = 1.0f64;
+        let mut y = 0.0f64;
+        while -2.0 * y < x * x {
+            let x_: f64 = float::open01(version, rng);
+            let y_: f64 = float::open01(version, rng);
+            x = x_.ln() / ziggurat_tables::ZIG_NORM_R;
+            y = y_.ln();
+        }
+        if u < 0.0 {
+            x - ziggurat_tables::ZIG_NORM_R
+        } else {
+            ziggurat_tables::ZIG_NORM_R - x
+        }
+    };
+    ziggurat(
+        version,
+        rng,
+        true,
+        &ziggurat_tables::ZIG_NORM_X,
+        &ziggurat_tables::ZIG_NORM_F,
+        |x| (-x * x / 2.0).exp(),
+        zero_case,
+    )
+}
+
+/// Sample from the exponential distribution with rate 1, like `Exp1` of the
+/// `rand_distr` version of the given `rand` version.
+pub fn exp1<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R) -> f64 {
+    let zero_case = |rng: &mut R, _u: f64| {
+        ziggurat_tables::ZIG_EXP_R - float::standard::<f64, _>(version, rng).ln()
+    };
+    ziggurat(
+        version,
+        rng,
+        false,
+        &ziggurat_tables::ZIG_EXP_X,
+        &ziggurat_tables::ZIG_EXP_F,
+        |x| (-x).exp(),
+        zero_case,
+    )
+}
+
+/// Sample using the ziggurat algorithm with the given tables, like
+/// `ziggurat` of `rand_distr`.
+///
+/// A single `u64` provides the layer in its lowest 8 bits and the position in
+/// the layer in its highest 52 bits. `zero_case` samples from the tail of the
+/// base layer.
+fn ziggurat<R, P, Z>(
+    version: RandVersion,
+    rng: &mut R,
+    symmetric: bool,
+    x_tab: ZigTable,
+    f_tab: ZigTable,
+    pdf: P,
+    mut zero_case: Z,
+) -> f64
+where
+    R: WrappedRng + ?Sized,
+    P: Fn(f64) -> f64,
+    Z: FnMut(&mut R, f64) -> f64,
+{
+    loop {
+        let bits = rng.next_u64();
+        let i = bits as usize & 0xff;
+        let u = if symmetric {
+            // A float in `[2, 4)` minus 3, i.e. in `[-1, 1)`.
+            f64::from_bits((bits >> 12) | (1024 << 52)) - 3.0
+        } else {
+            // A float in `[1, 2)` minus `1 - EPSILON / 2`, i.e. in `(0, 1)`.
+            f64::from_bits((bits >> 12) | (1023 << 52)) - (1.0 - f64::EPSILON / 2.0)
+        };
+        let x = u * x_tab[i];
+        let test_x = if symmetric { x.abs() } else { x };
+        if test_x < x_tab[i + 1] {
+            return x;
+        }
+        if i == 0 {
+            return zero_case(rng, u);
+        }
+        if f_tab[i + 1] + (f_tab[i] - f_tab[i + 1]) * float::standard::<f64, _>(version, rng)
+            < pdf(x)
+        {
+            return x;
+        }
+    }
+}
+
+/// Normal distribution with a given mean and standard deviation, like
+/// `Normal` of a given `rand_distr` version.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal {
+    version: RandVersion,
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Create a normal distribution, like `Normal::new` of the `rand_distr`
+    /// version of the given `rand` version.
+    ///
+    /// `rand_distr 0.2` rejects a negative or NaN `std_dev`, later versions
+    /// one that isn't finite.
+    pub fn new(version: RandVersion, mean: f64, std_dev: f64) -> Result<Normal, NormalError> {
+        let valid = match version {
+            RandVersion::V0_7 => std_dev >= 0.0,
+            RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => std_dev.is_finite(),
+        };
+        if !valid {
+            return Err(NormalError::BadVariance);
+        }
+        Ok(Normal {
+            version,
+            mean,
+            std_dev,
+        })
+    }
+
+    /// Sample a value, like `Distribution::sample` of `Normal`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.mean + self.std_dev * standard_normal(self.version, rng)
+    }
+}
+
+/// Error of [`Normal::new`].
+///
+/// `rand_distr 0.2` calls it `StdDevTooSmall`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NormalError {
+    /// The standard deviation was invalid.
+    BadVariance,
+}
+
+impl fmt::Display for NormalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NormalError::BadVariance => {
+                "variation parameter is non-finite in (log)normal distribution"
+            }
+        })
+    }
+}
+
+impl std::error::Error for NormalError {}
+
+/// Exponential distribution with a given rate, like `Exp` of a given
+/// `rand_distr` version.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exp {
+    version: RandVersion,
+    lambda_inverse: f64,
+}
+
+impl Exp {
+    /// Create an exponential distribution, like `Exp::new` of the
+    /// `rand_distr` version of the given `rand` version.
+    ///
+    /// All versions reject a negative or NaN `lambda`. `rand_distr 0.2` also
+    /// rejects 0, and `rand_distr 0.6` also -0.
+    pub fn new(version: RandVersion, lambda: f64) -> Result<Exp, ExpError> {
+        let valid = match version {
+            RandVersion::V0_7 => lambda > 0.0,
+            RandVersion::V0_8 | RandVersion::V0_9 => lambda >= 0.0,
+            RandVersion::V0_10 => !lambda.is_sign_negative() && !lambda.is_nan(),
+        };
+        if !valid {
+            return Err(ExpError::LambdaTooSmall);
+        }
+        Ok(Exp {
+            version,
+            lambda_inverse: 1.0 / lambda,
+        })
+    }
+
+    /// Sample a value, like `Distribution::sample` of `Exp`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> f64 {
+        exp1(self.version, rng) * self.lambda_inverse
+    }
+}
+
+/// Error of [`Exp::new`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ExpError {
+    /// The rate was invalid.
+    LambdaTooSmall,
+}
+
+impl fmt::Display for ExpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExpError::LambdaTooSmall => "lambda is negative or NaN in exponential distribution",
+        })
+    }
+}
+
+impl std::error::Error for ExpError {}
+
+/// Gamma distribution with a given shape and scale, like `Gamma` of a given
+/// `rand_distr` version.
+///
+/// It samples an [`Exp`] for shape 1 and uses Marsaglia and Tsang's method
+/// otherwise, boosting shapes below 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gamma {
+    version: RandVersion,
+    repr: GammaRepr,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GammaRepr {
+    One(Exp),
+    Small { inv_shape: f64, large: LargeShape },
+    Large(LargeShape),
+}
+
+/// Parameters of Marsaglia and Tsang's method.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LargeShape {
+    scale: f64,
+    c: f64,
+    d: f64,
+}
+
+impl Gamma {
+    /// Create a gamma distribution, like `Gamma::new` of the `rand_distr`
+    /// version of the given `rand` version.
+    ///
+    /// All versions reject a shape or scale that isn't positive.
+    /// `rand_distr 0.2` also rejects an infinite scale for shape 1, and
+    /// `rand_distr 0.6` returns infinity for an infinite shape or scale.
+    pub fn new(version: RandVersion, shape: f64, scale: f64) -> Result<Gamma, GammaError> {
+        if shape <= 0.0 || shape.is_nan() {
+            return Err(GammaError::ShapeTooSmall);
+        }
+        if scale <= 0.0 || scale.is_nan() {
+            return Err(GammaError::ScaleTooSmall);
+        }
+        let repr = if version == RandVersion::V0_10
+            && (shape == f64::INFINITY || scale == f64::INFINITY)
+        {
+            GammaRepr::One(Exp::new(version, 0.0).unwrap())
+        } else if shape == 1.0 {
+            GammaRepr::One(Exp::new(version, 1.0 / scale).map_err(|_| GammaError::ScaleTooLarge)?)
+        } else if shape < 1.0 {
+            GammaRepr::Small {
+                inv_shape: 1.0 / shape,
+                large: LargeShape::new(shape + 1.0, scale),
+            }
+        } else {
+            GammaRepr::Large(LargeShape::new(shape, scale))
+        };
+        Ok(Gamma { version, repr })
+    }
+
+    /// Sample a value, like `Distribution::sample` of `Gamma`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> f64 {
+        match self.repr {
+            GammaRepr::One(exp) => exp.sample(rng),
+            GammaRepr::Small { inv_shape, large } => {
+                let u: f64 = float::open01(self.version, rng);
+                let v = large.sample_unscaled(self.version, rng);
+                match self.version {
+                    RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 => {
+                        large.d * v * large.scale * powf(self.version, u, inv_shape)
+                    }
+                    RandVersion::V0_10 => {
+                        (v * powf(self.version, u, inv_shape) * large.d) * large.scale
+                    }
+                }
+            }
+            GammaRepr::Large(large) => {
+                let v = large.sample_unscaled(self.version, rng);
+                match self.version {
+                    RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 => {
+                        large.d * v * large.scale
+                    }
+                    RandVersion::V0_10 => v * (large.d * large.scale),
+                }
+            }
+        }
+    }
+}
+
+impl LargeShape {
+    fn new(shape: f64, scale: f64) -> LargeShape {
+        let d = shape - 1.0 / 3.0;
+        LargeShape {
+            scale,
+            c: 1.0 / (9.0 * d).sqrt(),
+            d,
+        }
+    }
+
+    /// Sample `v`, which is scaled by `d` and the scale.
+    fn sample_unscaled<R: WrappedRng + ?Sized>(&self, version: RandVersion, rng: &mut R) -> f64 {
+        loop {
+            let x = standard_normal(version, rng);
+            let v_cbrt = 1.0 + self.c * x;
+            if v_cbrt <= 0.0 {
+                continue;
+            }
+            let v = v_cbrt * v_cbrt * v_cbrt;
+            let u: f64 = float::open01(version, rng);
+            let x_sqr = x * x;
+            if u < 1.0 - 0.0331 * x_sqr * x_sqr
+                || ln(version, u) < 0.5 * x_sqr + self.d * (1.0 - v + ln(version, v))
+            {
+                return v;
+            }
+        }
+    }
+}
+
+/// Error of [`Gamma::new`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GammaError {
+    /// The shape wasn't positive.
+    ShapeTooSmall,
+    /// The scale wasn't positive.
+    ScaleTooSmall,
+    /// The scale was infinite, for shape 1 and `rand_distr 0.2`.
+    ScaleTooLarge,
+}
+
+impl fmt::Display for GammaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GammaError::ShapeTooSmall => "shape is not positive in gamma distribution",
+            GammaError::ScaleTooSmall => "scale is not positive in gamma distribution",
+            GammaError::ScaleTooLarge => "scale is infinity in gamma distribution",
+        })
+    }
+}
+
+impl std::error::Error for GammaError {}
+
+/// Poisson distribution with a given mean, like `Poisson` of a given
+/// `rand_distr` version.
+///
+/// It multiplies uniform samples for `lambda < 12`, and uses rejection
+/// sampling otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Poisson {
+    version: RandVersion,
+    method: PoissonMethod,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PoissonMethod {
+    Knuth {
+        exp_lambda: f64,
+    },
+    /// Rejection sampling with a Cauchy distribution, for `rand_distr 0.4` and
+    /// earlier.
+    Cauchy {
+        lambda: f64,
+        log_lambda: f64,
+        sqrt_2lambda: f64,
+        magic_val: f64,
+    },
+    /// Ahrens and Dieter's method, for `rand_distr 0.5` and later.
+    Normal(AhrensDieter),
+}
+
+impl Poisson {
+    /// Largest `lambda` accepted by `rand_distr 0.5` and later.
+    pub const MAX_LAMBDA: f64 = 1.844e19;
+
+    /// Create a Poisson distribution, like `Poisson::new` of the `rand_distr`
+    /// version of the given `rand` version.
+    ///
+    /// All versions reject a `lambda` that isn't positive. `rand_distr 0.5`
+    /// and later also reject one that isn't finite or is larger than
+    /// [`Poisson::MAX_LAMBDA`].
+    pub fn new(version: RandVersion, lambda: f64) -> Result<Poisson, PoissonError> {
+        match version {
+            RandVersion::V0_7 | RandVersion::V0_8 => {}
+            RandVersion::V0_9 | RandVersion::V0_10 => {
+                if !lambda.is_finite() {
+                    return Err(PoissonError::NonFinite);
+                }
+            }
+        }
+        if lambda <= 0.0 || lambda.is_nan() {
+            return Err(PoissonError::ShapeTooSmall);
+        }
+        let method = if lambda < 12.0 {
+            PoissonMethod::Knuth {
+                exp_lambda: exp(version, -lambda),
+            }
+        } else {
+            match version {
+                RandVersion::V0_7 | RandVersion::V0_8 => {
+                    let log_lambda = ln(version, lambda);
+                    PoissonMethod::Cauchy {
+                        lambda,
+                        log_lambda,
+                        sqrt_2lambda: (2.0 * lambda).sqrt(),
+                        magic_val: lambda * log_lambda - log_gamma(version, 1.0 + lambda),
+                    }
+                }
+                RandVersion::V0_9 | RandVersion::V0_10 => {
+                    if lambda > Poisson::MAX_LAMBDA {
+                        return Err(PoissonError::ShapeTooLarge);
+                    }
+                    PoissonMethod::Normal(AhrensDieter::new(lambda))
+                }
+            }
+        };
+        Ok(Poisson { version, method })
+    }
+
+    /// Sample a value, like `Distribution::sample` of `Poisson`.
+    ///
+    /// The value is an integer. `rand_distr 0.2` also samples it as a `u64`,
+    /// which is the same value converted.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> f64 {
+        match self.method {
+            PoissonMethod::Knuth { exp_lambda } => knuth(self.version, rng, exp_lambda),
+            PoissonMethod::Cauchy {
+                lambda,
+                log_lambda,
+                sqrt_2lambda,
+                magic_val,
+            } => loop {
+                let mut comp_dev;
+                let mut result;
+                loop {
+                    let x: f64 = float::standard(self.version, rng);
+                    comp_dev = tan(self.version, PI * x);
+                    result = sqrt_2lambda * comp_dev + lambda;
+                    if result >= 0.0 {
+                        break;
+                    }
+                }
+                result = result.floor();
+                // The ratio of the densities, scaled to roughly `0..0.9`.
+                let check = 0.9
+                    * (1.0 + comp_dev * comp_dev)
+                    * exp(
+                        self.version,
+                        result * log_lambda - log_gamma(self.version, 1.0 + result) - magic_val,
+                    );
+                if float::standard::<f64, _>(self.version, rng) <= check {
+                    return result;
+                }
+            },
+            PoissonMethod::Normal(method) => method.sample(self.version, rng),
+        }
+    }
+}
+
+/// Sample a Poisson distributed value by multiplying uniform samples until the
+/// product is at most `exp_lambda`.
+///
+/// `rand_distr 0.4` and earlier start the product with 1, so that they return
+/// -1 if `exp_lambda` rounds to 1.
+fn knuth<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, exp_lambda: f64) -> f64 {
+    let (mut result, mut p) = match version {
+        RandVersion::V0_7 | RandVersion::V0_8 => (0.0, 1.0),
+        RandVersion::V0_9 | RandVersion::V0_10 => (1.0, float::standard(version, rng)),
+    };
+    while p > exp_lambda {
+        p *= float::standard::<f64, _>(version, rng);
+        result += 1.0;
+    }
+    result - 1.0
+}
+
+/// Parameters of Ahrens and Dieter's method.
+///
+/// It is only used by `rand_distr 0.5` and later, so that it computes with
+/// `libm` as described in [`ln`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AhrensDieter {
+    lambda: f64,
+    s: f64,
+    d: f64,
+    l: f64,
+    c: f64,
+    c0: f64,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    omega: f64,
+}
+
+impl AhrensDieter {
+    fn new(lambda: f64) -> AhrensDieter {
+        let b1 = (1.0 / 24.0) / lambda;
+        let b2 = 0.3 * b1 * b1;
+        let c3 = (1.0 / 7.0) * b1 * b2;
+        let c2 = b2 - 15.0 * c3;
+        let c1 = b1 - 6.0 * b2 + 45.0 * c3;
+        let c0 = 1.0 - b1 + 3.0 * b2 - 15.0 * c3;
+        AhrensDieter {
+            lambda,
+            s: lambda.sqrt(),
+            d: 6.0 * lambda.powi(2),
+            l: (lambda - 1.1484).floor(),
+            c: 0.1069 / lambda,
+            c0,
+            c1,
+            c2,
+            c3,
+            omega: 1.0 / (2.0 * PI).sqrt() / lambda.sqrt(),
+        }
+    }
+
+    /// Return `px`, `py`, `fx` and `fy` of step F.
+    fn step_f(&self, k: f64) -> (f64, f64, f64, f64) {
+        const FACT: [f64; 10] = [
+            1.0, 1.0, 2.0, 6.0, 24.0, 120.0, 720.0, 5040.0, 40320.0, 362880.0,
+        ];
+        const A: [f64; 10] = [
+            -0.5000000002,
+            0.3333333343,
+            -0.2499998565,
+            0.1999997049,
+            -0.1666848753,
+            0.1428833286,
+            -0.1241963125,
+            0.1101687109,
+            -0.1142650302,
+            0.1055093006,
+        ];
+        let (px, py) = if k < 10.0 {
+            (-self.lambda, libm::pow(self.lambda, k) / FACT[k as usize])
+        } else {
+            let delta = (12.0 * k).recip();
+            let delta = delta - 4.8 * delta.powi(3);
+            let v = (self.lambda - k) / k;
+            let px = if v.abs() <= 0.25 {
+                k * v.powi(2) * A.iter().rev().fold(0.0, |acc, &a| acc * v + a) - delta
+            } else {
+                k * libm::log(1.0 + v) - (self.lambda - k) - delta
+            };
+            (px, 1.0 / (2.0 * PI).sqrt() / k.sqrt())
+        };
+        let x = (k - self.lambda + 0.5) / self.s;
+        let fx = -0.5 * x * x;
+        let fy = self.omega * (((self.c3 * x * x + self.c2) * x * x + self.c1) * x * x + self.c0);
+        (px, py, fx, fy)
+    }
+
+    fn sample<R: WrappedRng + ?Sized>(&self, version: RandVersion, rng: &mut R) -> f64 {
+        // Step N: a normal sample.
+        let g = self.lambda + self.s * standard_normal(version, rng);
+        if g >= 0.0 {
+            let k1 = g.floor();
+            // Step I: immediate acceptance.
+            if k1 >= self.l {
+                return k1;
+            }
+            // Step S: squeeze acceptance.
+            let u: f64 = float::standard(version, rng);
+            if self.d * u >= (self.lambda - k1).powi(3) {
+                return k1;
+            }
+            let (px, py, fx, fy) = self.step_f(k1);
+            if fy * (1.0 - u) <= py * libm::exp(px - fx) {
+                return k1;
+            }
+        }
+        loop {
+            // Step E: a double exponential sample.
+            let e = exp1(version, rng);
+            let u = float::standard::<f64, _>(version, rng) * 2.0 - 1.0;
+            let t = 1.8 + e * u.signum();
+            if t > -0.6744 {
+                let k2 = (self.lambda + self.s * t).floor();
+                let (px, py, fx, fy) = self.step_f(k2);
+                // Step H: hat acceptance.
+                if self.c * u.abs() <= py * libm::exp(px + e) - fy * libm::exp(fx + e) {
+                    return k2;
+                }
+            }
+        }
+    }
+}
+
+/// Return the logarithm of the gamma function, using the Lanczos
+/// approximation with `g = 5` and 6 terms, like `rand_distr 0.4` and earlier.
+fn log_gamma(version: RandVersion, x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let tmp = x + 5.5;
+    let log = (x + 0.5) * ln(version, tmp) - tmp;
+    let mut a = 1.000000000190015;
+    let mut denom = x;
+    for coeff in COEFFICIENTS {
+        denom += 1.0;
+        a += coeff / denom;
+    }
+    // 2.5066... is the square root of 2 pi.
+    log + ln(version, 2.5066282746310005 * a / x)
+}
+
+/// Return the natural logarithm of `x`, as computed by code of the
+/// `rand_distr` version of the given `rand` version that is generic over the
+/// float type.
+///
+/// Since `rand_distr 0.4`, such code uses the functions of `num-traits`, which
+/// are those of `libm` unless some crate enables the `std` feature of
+/// `num-traits`, e.g. through the `std_math` feature of `rand_distr`. Code for
+/// `f64` only and `rand_distr 0.2` use those of the standard library.
+fn ln(version: RandVersion, x: f64) -> f64 {
+    match version {
+        RandVersion::V0_7 => x.ln(),
+        RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => libm::log(x),
+    }
+}
+
+/// Return `e^x` like [`ln`].
+fn exp(version: RandVersion, x: f64) -> f64 {
+    match version {
+        RandVersion::V0_7 => x.exp(),
+        RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => libm::exp(x),
+    }
+}
+
+/// Return `x^y` like [`ln`].
+fn powf(version: RandVersion, x: f64, y: f64) -> f64 {
+    match version {
+        RandVersion::V0_7 => x.powf(y),
+        RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => libm::pow(x, y),
+    }
+}
+
+/// Return the tangent of `x` like [`ln`].
+fn tan(version: RandVersion, x: f64) -> f64 {
+    match version {
+        RandVersion::V0_7 => x.tan(),
+        RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => libm::tan(x),
+    }
+}
+
+/// Error of [`Poisson::new`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PoissonError {
+    /// `lambda` wasn't positive.
+    ShapeTooSmall,
+    /// `lambda` wasn't finite, for `rand_distr 0.5` and later.
+    NonFinite,
+    /// `lambda` was larger than [`Poisson::MAX_LAMBDA`], for
+    /// `rand_distr 0.5` and later.
+    ShapeTooLarge,
+}
+
+impl fmt::Display for PoissonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PoissonError::ShapeTooSmall => "lambda is not positive in Poisson distribution",
+            PoissonError::NonFinite => "lambda is infinite or nan in Poisson distribution",
+            PoissonError::ShapeTooLarge => {
+                "lambda is too large in Poisson distribution, see Poisson::MAX_LAMBDA"
+            }
+        })
+    }
+}
+
+impl std::error::Error for PoissonError {}
+
+/// Binomial distribution with a given number of trials and success
+/// probability, like `Binomial` of a given `rand_distr` version.
+///
+/// It uses the BINV algorithm, inverting the distribution function, for
+/// `n * p < 10` and the BTPE algorithm otherwise, with `p` replaced by
+/// `1 - p` if `p > 0.5`. `rand_distr 0.4` and earlier also use BTPE for
+/// `n > i32::MAX`, and `rand_distr 0.5` and later sample a [`Poisson`]
+/// distribution if `1 - p` rounds to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binomial {
+    version: RandVersion,
+    method: BinomialMethod,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BinomialMethod {
+    Constant(u64),
+    Binv {
+        n: u64,
+        r: f64,
+        s: f64,
+        a: f64,
+        flipped: bool,
+    },
+    Btpe {
+        n: u64,
+        p: f64,
+        flipped: bool,
+    },
+    Poisson {
+        exp_lambda: f64,
+    },
+}
+
+impl Binomial {
+    /// Create a binomial distribution, like `Binomial::new` of the
+    /// `rand_distr` version of the given `rand` version.
+    pub fn new(version: RandVersion, n: u64, p: f64) -> Result<Binomial, BinomialError> {
+        if p < 0.0 || p.is_nan() {
+            return Err(BinomialError::ProbabilityTooSmall);
+        }
+        if p > 1.0 {
+            return Err(BinomialError::ProbabilityTooLarge);
+        }
+        let method = if p == 0.0 {
+            BinomialMethod::Constant(0)
+        } else if p == 1.0 {
+            BinomialMethod::Constant(n)
+        } else {
+            let flipped = p > 0.5;
+            let p = if flipped { 1.0 - p } else { p };
+            let q = 1.0 - p;
+            let s = p / q;
+            let np = n as f64 * p;
+            match version {
+                RandVersion::V0_7 | RandVersion::V0_8 => {
+                    if np < 10.0 && n <= i32::MAX as u64 {
+                        BinomialMethod::Binv {
+                            n,
+                            r: q.powi(n as i32),
+                            s,
+                            a: (n + 1) as f64 * s,
+                            flipped,
+                        }
+                    } else {
+                        BinomialMethod::Btpe { n, p, flipped }
+                    }
+                }
+                RandVersion::V0_9 | RandVersion::V0_10 => {
+                    if np >= 10.0 {
+                        BinomialMethod::Btpe { n, p, flipped }
+                    } else if q == 1.0 {
+                        // `p` is so small that this is practically a Poisson
+                        // distribution, and not flipped.
+                        BinomialMethod::Poisson {
+                            exp_lambda: exp(version, -np),
+                        }
+                    } else {
+                        BinomialMethod::Binv {
+                            n,
+                            r: q.powf(n as f64),
+                            s,
+                            a: (n as f64 + 1.0) * s,
+                            flipped,
+                        }
+                    }
+                }
+            }
+        };
+        Ok(Binomial { version, method })
+    }
+
+    /// Sample a value, like `Distribution::sample` of `Binomial`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let (n, sample, flipped) = match self.method {
+            BinomialMethod::Constant(c) => return c,
+            BinomialMethod::Binv {
+                n,
+                r,
+                s,
+                a,
+                flipped,
+            } => (n, binv(self.version, rng, r, s, a), flipped),
+            BinomialMethod::Btpe { n, p, flipped } => (n, btpe(self.version, rng, n, p), flipped),
+            BinomialMethod::Poisson { exp_lambda } => {
+                return knuth(self.version, rng, exp_lambda) as u64;
+            }
+        };
+        if flipped { n - sample } else { sample }
+    }
+}
+
+/// Sample using the BINV algorithm.
+///
+/// `rand_distr 0.5` and later start over if the result exceeds 110.
+fn binv<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, r: f64, s: f64, a: f64) -> u64 {
+    const BINV_MAX_X: u64 = 110;
+
+    let bounded = match version {
+        RandVersion::V0_7 | RandVersion::V0_8 => false,
+        RandVersion::V0_9 | RandVersion::V0_10 => true,
+    };
+    'outer: loop {
+        let mut r = r;
+        let mut u: f64 = float::standard(version, rng);
+        let mut x = 0;
+        while u > r {
+            u -= r;
+            x += 1;
+            if bounded && x > BINV_MAX_X {
+                continue 'outer;
+            }
+            r *= a / (x as f64) - s;
+        }
+        return x;
+    }
+}
+
+/// Sample using the BTPE algorithm of Kachitvichyanukul and Schmeiser, with
+/// the signs of the final test of GSL.
+///
+/// `rand_distr 0.6` computes with `u64` and earlier versions with `i64`; the
+/// `i128` values hold both. They differ in how they round the tails.
+fn btpe<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, n: u64, p: f64) -> u64 {
+    const SQUEEZE_THRESHOLD: i128 = 20;
+
+    // Convert a float to an integer like `f64_to_i64` or `f64_to_u64`.
+    let to_int = |x: f64| -> i128 {
+        if version == RandVersion::V0_10 {
+            assert!(x >= 0.0 && x < u64::MAX as f64);
+            x as u64 as i128
+        } else {
+            assert!(x < i64::MAX as f64);
+            x as i64 as i128
+        }
+    };
+
+    // Step 0: constants of the triangle, parallelograms and tails.
+    let n_f = n as f64;
+    let np = n_f * p;
+    let q = 1.0 - p;
+    let npq = np * q;
+    let f_m = np + p;
+    let m = to_int(f_m);
+    let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+    let x_m = (m as f64) + 0.5;
+    let x_l = x_m - p1;
+    let x_r = x_m + p1;
+    let c = 0.134 + 20.5 / (15.3 + (m as f64));
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let lambda = |a: f64| a * (1.0 + 0.5 * a);
+    let lambda_l = lambda((f_m - x_l) / (f_m - x_l * p));
+    let lambda_r = lambda((x_r - f_m) / (x_r * q));
+    let p3 = p2 + c / lambda_l;
+    let p4 = p3 + c / lambda_r;
+
+    let gen_u = Uniform::new(version, 0.0, p4);
+    let gen_v = Uniform::new(version, 0.0, 1.0);
+
+    let y = loop {
+        // Step 1: choose the region, and sample from the triangle.
+        let u = gen_u.sample(rng);
+        let mut v = gen_v.sample(rng);
+        if u <= p1 {
+            break to_int(x_m - p1 * v + u);
+        }
+        let y;
+        if u <= p2 {
+            // Step 2: parallelograms.
+            let x = x_l + (u - p1) / c;
+            v = v * c + 1.0 - (x - x_m).abs() / p1;
+            if v > 1.0 {
+                continue;
+            }
+            y = to_int(x);
+        } else if u <= p3 {
+            // Step 3: left exponential tail.
+            let y_tmp = x_l + v.ln() / lambda_l;
+            if version == RandVersion::V0_10 {
+                if y_tmp < 0.0 {
+                    continue;
+                }
+                y = to_int(y_tmp);
+            } else {
+                y = to_int(y_tmp);
+                if y < 0 {
+                    continue;
+                }
+            }
+            v *= (u - p2) * lambda_l;
+        } else {
+            // Step 4: right exponential tail.
+            let y_tmp = x_r - v.ln() / lambda_r;
+            y = if version == RandVersion::V0_10 {
+                // The cast saturates.
+                y_tmp as u64 as i128
+            } else {
+                to_int(y_tmp)
+            };
+            if y > n as i128 {
+                continue;
+            }
+            v *= (u - p3) * lambda_r;
+        }
+
+        // Step 5.0: choose how to evaluate the density at `y`.
+        let k = (y - m).abs();
+        if !(k > SQUEEZE_THRESHOLD && (k as f64) < 0.5 * npq - 1.0) {
+            // Step 5.1: evaluate the density recursively from the mode.
+            let s = p / q;
+            let a = s * (n_f + 1.0);
+            let mut f = 1.0;
+            match m.cmp(&y) {
+                Ordering::Less => {
+                    for i in m + 1..=y {
+                        f *= a / (i as f64) - s;
+                    }
+                }
+                Ordering::Greater => {
+                    for i in y + 1..=m {
+                        f /= a / (i as f64) - s;
+                    }
+                }
+                Ordering::Equal => {}
+            }
+            if v > f {
+                continue;
+            }
+            break y;
+        }
+
+        // Step 5.2: squeeze with bounds of the log-density.
+        let k = k as f64;
+        let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+        let t = -0.5 * k * k / npq;
+        let alpha = v.ln();
+        if alpha < t - rho {
+            break y;
+        }
+        if alpha > t + rho {
+            continue;
+        }
+
+        // Step 5.3: final comparison with Stirling's approximation.
+        let x1 = (y + 1) as f64;
+        let f1 = (m + 1) as f64;
+        let (z, w, n_sub_m) = if version == RandVersion::V0_10 {
+            let n = n as i128;
+            (((n - m) + 1) as f64, ((n - y) + 1) as f64, (n - m) as f64)
+        } else {
+            let n = to_int(n_f);
+            ((n + 1 - m) as f64, (n - y + 1) as f64, n_f - (m as f64))
+        };
+        let stirling = |a: f64| {
+            let a2 = a * a;
+            (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / a2) / a2) / a2) / a2) / a / 166320.0
+        };
+        if alpha
+            > x_m * (f1 / x1).ln()
+                + (n_sub_m + 0.5) * (z / w).ln()
+                + ((y - m) as f64) * (w * p / (x1 * q)).ln()
+                + stirling(f1)
+                + stirling(z)
+                - stirling(x1)
+                - stirling(w)
+        {
+            continue;
+        }
+        break y;
+    };
+    assert!(y >= 0);
+    y as u64
+}
+
+/// Error of [`Binomial::new`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BinomialError {
+    /// The probability was negative or NaN.
+    ProbabilityTooSmall,
+    /// The probability was larger than 1.
+    ProbabilityTooLarge,
+}
+
+impl fmt::Display for BinomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BinomialError::ProbabilityTooSmall => "p < 0 or is NaN in binomial distribution",
+            BinomialError::ProbabilityTooLarge => "p > 1 in binomial distribution",
+        })
+    }
+}
+
+impl std::error::Error for BinomialError {}
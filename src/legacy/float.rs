@@ -0,0 +1,148 @@
+//! Sampling of floating-point numbers, as in `rand 0.7` to `rand 0.10`.
+//!
+//! The conversions of `Standard` (`StandardUniform` since `rand 0.9`),
+//! `Open01` and `OpenClosed01` are the same in all these versions: `Standard`
+//! and `OpenClosed01` scale the highest 24 or 53 bits of a random `u32` or
+//! `u64`, while `Open01` uses the highest 23 or 52 bits as the fraction of a
+//! float in `[1, 2)`. Uniform sampling of floats from a range, which differs
+//! between versions, is reproduced by [`super::uniform`].
+
+use self::private::Sealed;
+use super::RandVersion;
+use super::WrappedRng;
+
+/// Floating-point type, i.e. `f32` or `f64`.
+///
+/// It is sealed, i.e. it cannot be implemented outside of this crate.
+pub trait Float: Copy + PartialOrd + Sealed {}
+
+impl Float for f32 {}
+impl Float for f64 {}
+
+/// Sample a float from `[0, 1)` like `Standard` of the given `rand` version.
+///
+/// This reproduces `Rng::gen` of `rand 0.7` and `rand 0.8` and `Rng::random`
+/// of `rand 0.9` and `rand 0.10`.
+pub fn standard<F: Float, R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R) -> F {
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => {
+            F::standard(rng)
+        }
+    }
+}
+
+/// Sample a float from `(0, 1)` like `Open01` of the given `rand` version.
+pub fn open01<F: Float, R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R) -> F {
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => {
+            F::open01(rng)
+        }
+    }
+}
+
+/// Sample a float from `(0, 1]` like `OpenClosed01` of the given `rand`
+/// version.
+pub fn open_closed01<F: Float, R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R) -> F {
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => {
+            F::open_closed01(rng)
+        }
+    }
+}
+
+pub(super) mod private {
+    use super::super::WrappedRng;
+    use core::fmt;
+    use core::ops::Add;
+    use core::ops::Div;
+    use core::ops::Mul;
+    use core::ops::Sub;
+
+    /// Conversions and arithmetic of a floating-point type.
+    pub trait Sealed:
+        Copy
+        + PartialOrd
+        + fmt::Debug
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+    {
+        /// Largest value returned by `sample0_1`, i.e. `1 - EPSILON`.
+        const MAX_RAND: Self;
+
+        fn standard<R: WrappedRng + ?Sized>(rng: &mut R) -> Self;
+        fn open01<R: WrappedRng + ?Sized>(rng: &mut R) -> Self;
+        fn open_closed01<R: WrappedRng + ?Sized>(rng: &mut R) -> Self;
+
+        /// Sample a float from `[0, 1)` like `UniformFloat`, by subtracting 1
+        /// from a float in `[1, 2)` with a random fraction.
+        fn sample0_1<R: WrappedRng + ?Sized>(rng: &mut R) -> Self;
+
+        /// Return whether `self` is neither infinite nor NaN.
+        fn finite(self) -> bool;
+
+        /// Return the next float towards 0, for positive values.
+        fn decrease(self) -> Self;
+    }
+
+    impl Sealed for f32 {
+        const MAX_RAND: f32 = 1.0 - f32::EPSILON;
+
+        fn standard<R: WrappedRng + ?Sized>(rng: &mut R) -> f32 {
+            let scale = 1.0 / (1u32 << 24) as f32;
+            scale * (rng.next_u32() >> 8) as f32
+        }
+
+        fn open01<R: WrappedRng + ?Sized>(rng: &mut R) -> f32 {
+            f32::from_bits((rng.next_u32() >> 9) | (127 << 23)) - (1.0 - f32::EPSILON / 2.0)
+        }
+
+        fn open_closed01<R: WrappedRng + ?Sized>(rng: &mut R) -> f32 {
+            let scale = 1.0 / (1u32 << 24) as f32;
+            scale * ((rng.next_u32() >> 8) + 1) as f32
+        }
+
+        fn sample0_1<R: WrappedRng + ?Sized>(rng: &mut R) -> f32 {
+            f32::from_bits((rng.next_u32() >> 9) | (127 << 23)) - 1.0
+        }
+
+        fn finite(self) -> bool {
+            self.is_finite()
+        }
+
+        fn decrease(self) -> f32 {
+            f32::from_bits(self.to_bits() - 1)
+        }
+    }
+
+    impl Sealed for f64 {
+        const MAX_RAND: f64 = 1.0 - f64::EPSILON;
+
+        fn standard<R: WrappedRng + ?Sized>(rng: &mut R) -> f64 {
+            let scale = 1.0 / (1u64 << 53) as f64;
+            scale * (rng.next_u64() >> 11) as f64
+        }
+
+        fn open01<R: WrappedRng + ?Sized>(rng: &mut R) -> f64 {
+            f64::from_bits((rng.next_u64() >> 12) | (1023 << 52)) - (1.0 - f64::EPSILON / 2.0)
+        }
+
+        fn open_closed01<R: WrappedRng + ?Sized>(rng: &mut R) -> f64 {
+            let scale = 1.0 / (1u64 << 53) as f64;
+            scale * ((rng.next_u64() >> 11) + 1) as f64
+        }
+
+        fn sample0_1<R: WrappedRng + ?Sized>(rng: &mut R) -> f64 {
+            f64::from_bits((rng.next_u64() >> 12) | (1023 << 52)) - 1.0
+        }
+
+        fn finite(self) -> bool {
+            self.is_finite()
+        }
+
+        fn decrease(self) -> f64 {
+            f64::from_bits(self.to_bits() - 1)
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use super::BlockCore;
+use super::BlockRng;
+use core::fmt;
+
+/// Number of 32-bit words in a block of output.
+const BLOCK_WORDS: usize = 16;
+
+/// The HC-128 state, as implemented by `rand_hc 0.1`: the tables P and Q,
+/// followed by each other in `t`.
+#[derive(Clone, Eq, PartialEq)]
+struct Hc128Core {
+    t: [u32; 1024],
+    counter1024: usize,
+}
+
+impl Hc128Core {
+    /// Initialize the state from a 128-bit key followed by a 128-bit IV.
+    fn init(seed: [u32; 8]) -> Hc128Core {
+        fn f1(x: u32) -> u32 {
+            x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)
+        }
+        fn f2(x: u32) -> u32 {
+            x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
+        }
+
+        let mut t = [0; 1024];
+        let (key, iv) = seed.split_at(4);
+        t[..4].copy_from_slice(key);
+        t[4..8].copy_from_slice(key);
+        t[8..12].copy_from_slice(iv);
+        t[12..16].copy_from_slice(iv);
+
+        // Expand the key and IV to the intermediate values W[16..272], the
+        // last 16 of which start P.
+        for i in 16..256 + 16 {
+            t[i] = f2(t[i - 2])
+                .wrapping_add(t[i - 7])
+                .wrapping_add(f1(t[i - 15]))
+                .wrapping_add(t[i - 16])
+                .wrapping_add(i as u32);
+        }
+        t.copy_within(256..256 + 16, 0);
+        for i in 16..1024 {
+            t[i] = f2(t[i - 2])
+                .wrapping_add(t[i - 7])
+                .wrapping_add(f1(t[i - 15]))
+                .wrapping_add(t[i - 16])
+                .wrapping_add(256 + i as u32);
+        }
+
+        // Run the cipher for 1024 steps, replacing the table entries with the
+        // output.
+        let mut core = Hc128Core { t, counter1024: 0 };
+        while core.counter1024 < 1024 {
+            for j in 0..BLOCK_WORDS {
+                core.t[core.counter1024 + j] = core.step(core.counter1024 % 512 + j);
+            }
+            core.counter1024 += BLOCK_WORDS;
+        }
+        core.counter1024 = 0;
+        core
+    }
+
+    /// Update entry `i` of P (or of Q in the second half of each 1024 steps)
+    /// and return the next output word.
+    fn step(&mut self, i: usize) -> u32 {
+        let p_block = self.counter1024 & 512 == 0;
+        let (p, q) = self.t.split_at_mut(512);
+        let (x, y) = if p_block { (p, q) } else { (q, p) };
+        let rotate = |v: u32, n| {
+            if p_block {
+                v.rotate_right(n)
+            } else {
+                v.rotate_left(n)
+            }
+        };
+        // Index `i - k` modulo 512.
+        let back = |k: usize| (i + 512 - k) % 512;
+        x[i] = x[i]
+            .wrapping_add(rotate(x[back(10)], 8))
+            .wrapping_add(rotate(x[back(511)], 23) ^ rotate(x[back(3)], 10));
+        let z = x[back(12)];
+        y[z as u8 as usize].wrapping_add(y[256 + (z >> 16) as u8 as usize]) ^ x[i]
+    }
+}
+
+impl BlockCore<BLOCK_WORDS> for Hc128Core {
+    fn generate(&mut self, results: &mut [u32; BLOCK_WORDS]) {
+        let start = self.counter1024 % 512;
+        for (j, r) in results.iter_mut().enumerate() {
+            *r = self.step(start + j);
+        }
+        self.counter1024 = (self.counter1024 + BLOCK_WORDS) % 1024;
+    }
+}
+
+/// `Hc128Rng` of `rand 0.5` and `rand_hc 0.1`, the `StdRng` of `rand 0.5` and
+/// `rand 0.6`: the HC-128 stream cipher.
+///
+/// It reproduces the output of the historical implementation bit for bit,
+/// given the same seed and the same sequence of calls. It implements the RNG,
+/// crypto RNG and seedable RNG traits of every selected `rand_core`/`rand`
+/// version.
+///
+/// Its seed is the 128-bit key followed by the 128-bit IV.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Hc128Rng(BlockRng<Hc128Core, BLOCK_WORDS>);
+
+impl Hc128Rng {
+    fn from_seed(seed: [u8; 32]) -> Hc128Rng {
+        let mut words = [0; 8];
+        for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+            *w = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Hc128Rng(BlockRng::new(Hc128Core::init(words)))
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for Hc128Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hc128Rng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Hc128Rng;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for Hc128Rng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for Hc128Rng {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for Hc128Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Hc128Rng {
+            Hc128Rng::from_seed(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Hc128Rng;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for Hc128Rng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for Hc128Rng {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for Hc128Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Hc128Rng {
+            Hc128Rng::from_seed(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Hc128Rng;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for Hc128Rng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl rand_core_0_9::CryptoRng for Hc128Rng {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for Hc128Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Hc128Rng {
+            Hc128Rng::from_seed(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Hc128Rng;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for Hc128Rng {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(self.0.next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(self.0.next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for Hc128Rng {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for Hc128Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Hc128Rng {
+            Hc128Rng::from_seed(seed)
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use super::BlockCore;
+use super::BlockRng;
+use core::fmt;
+
+/// Number of 32-bit words in the state and in a block of output.
+const RAND_SIZE: usize = 256;
+
+/// The ISAAC state, as implemented by `rand_isaac 0.1`.
+#[derive(Clone, Eq, PartialEq)]
+struct IsaacCore {
+    mem: [u32; RAND_SIZE],
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+impl IsaacCore {
+    /// Initialize the state from `mem` with `rounds` passes over it, like
+    /// `randinit` of the reference implementation.
+    fn init(mut mem: [u32; RAND_SIZE], rounds: u32) -> IsaacCore {
+        // The fractional part of the golden ratio (0x9e3779b9) with `mix`
+        // applied 4 times.
+        let mut a: u32 = 0x1367df5a;
+        let mut b: u32 = 0x95d90059;
+        let mut c: u32 = 0xc3163e4b;
+        let mut d: u32 = 0x0f421ad8;
+        let mut e: u32 = 0xd92a4a78;
+        let mut f: u32 = 0xa51a3c49;
+        let mut g: u32 = 0xc4efea1b;
+        let mut h: u32 = 0x30609119;
+        for _ in 0..rounds {
+            for chunk in mem.chunks_exact_mut(8) {
+                a = a.wrapping_add(chunk[0]);
+                b = b.wrapping_add(chunk[1]);
+                c = c.wrapping_add(chunk[2]);
+                d = d.wrapping_add(chunk[3]);
+                e = e.wrapping_add(chunk[4]);
+                f = f.wrapping_add(chunk[5]);
+                g = g.wrapping_add(chunk[6]);
+                h = h.wrapping_add(chunk[7]);
+
+                a ^= b << 11;
+                d = d.wrapping_add(a);
+                b = b.wrapping_add(c);
+                b ^= c >> 2;
+                e = e.wrapping_add(b);
+                c = c.wrapping_add(d);
+                c ^= d << 8;
+                f = f.wrapping_add(c);
+                d = d.wrapping_add(e);
+                d ^= e >> 16;
+                g = g.wrapping_add(d);
+                e = e.wrapping_add(f);
+                e ^= f << 10;
+                h = h.wrapping_add(e);
+                f = f.wrapping_add(g);
+                f ^= g >> 4;
+                a = a.wrapping_add(f);
+                g = g.wrapping_add(h);
+                g ^= h << 8;
+                b = b.wrapping_add(g);
+                h = h.wrapping_add(a);
+                h ^= a >> 9;
+                c = c.wrapping_add(h);
+                a = a.wrapping_add(b);
+
+                chunk.copy_from_slice(&[a, b, c, d, e, f, g, h]);
+            }
+        }
+        IsaacCore {
+            mem,
+            a: 0,
+            b: 0,
+            c: 0,
+        }
+    }
+}
+
+impl BlockCore<RAND_SIZE> for IsaacCore {
+    /// Generate the next block. Like `rand_isaac`, fill `results` in reverse,
+    /// as the reference implementation reads them in reverse.
+    fn generate(&mut self, results: &mut [u32; RAND_SIZE]) {
+        self.c = self.c.wrapping_add(1);
+        let mut a = self.a;
+        let mut b = self.b.wrapping_add(self.c);
+        for i in 0..RAND_SIZE {
+            let mix = match i % 4 {
+                0 => a ^ (a << 13),
+                1 => a ^ (a >> 6),
+                2 => a ^ (a << 2),
+                _ => a ^ (a >> 16),
+            };
+            let x = self.mem[i];
+            a = mix.wrapping_add(self.mem[(i + RAND_SIZE / 2) % RAND_SIZE]);
+            let y = a
+                .wrapping_add(b)
+                .wrapping_add(self.mem[(x >> 2) as usize % RAND_SIZE]);
+            self.mem[i] = y;
+            b = x.wrapping_add(self.mem[(y >> 10) as usize % RAND_SIZE]);
+            results[RAND_SIZE - 1 - i] = b;
+        }
+        self.a = a;
+        self.b = b;
+    }
+}
+
+/// `IsaacRng` of `rand 0.5` and `rand_isaac 0.1`: the 32-bit ISAAC RNG.
+///
+/// It reproduces the output of the historical implementation bit for bit,
+/// given the same seed and the same sequence of calls. It implements the RNG
+/// and seedable RNG traits of every selected `rand_core`/`rand` version.
+///
+/// Its seed is the first 8 words of the ISAAC key. `seed_from_u64` uses the
+/// value as the first 2 words of the key and a single initialization pass, so
+/// that `seed_from_u64(0)` matches the unseeded reference implementation.
+#[derive(Clone, Eq, PartialEq)]
+pub struct IsaacRng(BlockRng<IsaacCore, RAND_SIZE>);
+
+impl IsaacRng {
+    fn from_seed(seed: [u8; 32]) -> IsaacRng {
+        let mut mem = [0; RAND_SIZE];
+        for (m, chunk) in mem.iter_mut().zip(seed.chunks_exact(4)) {
+            *m = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        IsaacRng(BlockRng::new(IsaacCore::init(mem, 2)))
+    }
+
+    fn seed_from_u64(state: u64) -> IsaacRng {
+        let mut mem = [0; RAND_SIZE];
+        mem[0] = state as u32;
+        mem[1] = (state >> 32) as u32;
+        IsaacRng(BlockRng::new(IsaacCore::init(mem, 1)))
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for IsaacRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IsaacRng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::IsaacRng;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for IsaacRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for IsaacRng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> IsaacRng {
+            IsaacRng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> IsaacRng {
+            IsaacRng::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::IsaacRng;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for IsaacRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for IsaacRng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> IsaacRng {
+            IsaacRng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> IsaacRng {
+            IsaacRng::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::IsaacRng;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for IsaacRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for IsaacRng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> IsaacRng {
+            IsaacRng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> IsaacRng {
+            IsaacRng::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::IsaacRng;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for IsaacRng {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(self.0.next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(self.0.next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for IsaacRng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> IsaacRng {
+            IsaacRng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> IsaacRng {
+            IsaacRng::seed_from_u64(state)
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use core::fmt;
+
+/// Number of 64-bit words in the state and in a block of output.
+const RAND_SIZE: usize = 256;
+
+/// The ISAAC-64 state, as implemented by `rand_isaac 0.1`.
+#[derive(Clone, Eq, PartialEq)]
+struct Isaac64Core {
+    mem: [u64; RAND_SIZE],
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl Isaac64Core {
+    /// Initialize the state from `mem` with `rounds` passes over it, like
+    /// `randinit` of the reference implementation.
+    fn init(mut mem: [u64; RAND_SIZE], rounds: u32) -> Isaac64Core {
+        // The fractional part of the golden ratio (0x9e3779b97f4a7c13) with
+        // `mix` applied 4 times.
+        let mut a: u64 = 0x647c4677a2884b7c;
+        let mut b: u64 = 0xb9f8b322c73ac862;
+        let mut c: u64 = 0x8c0ea5053d4712a0;
+        let mut d: u64 = 0xb29b2e824a595524;
+        let mut e: u64 = 0x82f053db8355e0ce;
+        let mut f: u64 = 0x48fe4a0fa5a09315;
+        let mut g: u64 = 0xae985bf2cbfc89ed;
+        let mut h: u64 = 0x98f5704f6c44c0ab;
+        for _ in 0..rounds {
+            for chunk in mem.chunks_exact_mut(8) {
+                a = a.wrapping_add(chunk[0]);
+                b = b.wrapping_add(chunk[1]);
+                c = c.wrapping_add(chunk[2]);
+                d = d.wrapping_add(chunk[3]);
+                e = e.wrapping_add(chunk[4]);
+                f = f.wrapping_add(chunk[5]);
+                g = g.wrapping_add(chunk[6]);
+                h = h.wrapping_add(chunk[7]);
+
+                a = a.wrapping_sub(e);
+                f ^= h >> 9;
+                h = h.wrapping_add(a);
+                b = b.wrapping_sub(f);
+                g ^= a << 9;
+                a = a.wrapping_add(b);
+                c = c.wrapping_sub(g);
+                h ^= b >> 23;
+                b = b.wrapping_add(c);
+                d = d.wrapping_sub(h);
+                a ^= c << 15;
+                c = c.wrapping_add(d);
+                e = e.wrapping_sub(a);
+                b ^= d >> 14;
+                d = d.wrapping_add(e);
+                f = f.wrapping_sub(b);
+                c ^= e << 20;
+                e = e.wrapping_add(f);
+                g = g.wrapping_sub(c);
+                d ^= f >> 17;
+                f = f.wrapping_add(g);
+                h = h.wrapping_sub(d);
+                e ^= g << 14;
+                g = g.wrapping_add(h);
+
+                chunk.copy_from_slice(&[a, b, c, d, e, f, g, h]);
+            }
+        }
+        Isaac64Core {
+            mem,
+            a: 0,
+            b: 0,
+            c: 0,
+        }
+    }
+
+    /// Generate the next block. Like `rand_isaac`, fill `results` in reverse,
+    /// as the reference implementation reads them in reverse.
+    fn generate(&mut self, results: &mut [u64; RAND_SIZE]) {
+        self.c = self.c.wrapping_add(1);
+        let mut a = self.a;
+        let mut b = self.b.wrapping_add(self.c);
+        for i in 0..RAND_SIZE {
+            let mix = match i % 4 {
+                0 => !(a ^ (a << 21)),
+                1 => a ^ (a >> 5),
+                2 => a ^ (a << 12),
+                _ => a ^ (a >> 33),
+            };
+            let x = self.mem[i];
+            a = mix.wrapping_add(self.mem[(i + RAND_SIZE / 2) % RAND_SIZE]);
+            let y = a
+                .wrapping_add(b)
+                .wrapping_add(self.mem[(x >> 3) as usize % RAND_SIZE]);
+            self.mem[i] = y;
+            b = x.wrapping_add(self.mem[(y >> 11) as usize % RAND_SIZE]);
+            results[RAND_SIZE - 1 - i] = b;
+        }
+        self.a = a;
+        self.b = b;
+    }
+}
+
+/// `Isaac64Rng` of `rand 0.5` and `rand_isaac 0.1`: the 64-bit ISAAC-64 RNG.
+///
+/// It reproduces the output of the historical implementation bit for bit,
+/// given the same seed and the same sequence of calls. It implements the RNG
+/// and seedable RNG traits of every selected `rand_core`/`rand` version.
+///
+/// Its seed is the first 4 words of the ISAAC-64 key. `seed_from_u64` uses the
+/// value as the first word of the key and a single initialization pass, so
+/// that `seed_from_u64(0)` matches the unseeded reference implementation.
+///
+/// The buffering matches `rand_core 0.4`'s `BlockRng64`: `next_u32` returns
+/// the low and then the high half of a word, while `next_u64` and `fill_bytes`
+/// start at the next whole word.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Isaac64Rng {
+    core: Isaac64Core,
+    results: [u64; RAND_SIZE],
+    index: usize,
+    half_used: bool,
+}
+
+impl Isaac64Rng {
+    fn new(core: Isaac64Core) -> Isaac64Rng {
+        Isaac64Rng {
+            core,
+            results: [0; RAND_SIZE],
+            index: RAND_SIZE,
+            half_used: false,
+        }
+    }
+
+    fn from_seed(seed: [u8; 32]) -> Isaac64Rng {
+        let mut mem = [0; RAND_SIZE];
+        for (m, chunk) in mem.iter_mut().zip(seed.chunks_exact(8)) {
+            *m = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Isaac64Rng::new(Isaac64Core::init(mem, 2))
+    }
+
+    fn seed_from_u64(state: u64) -> Isaac64Rng {
+        let mut mem = [0; RAND_SIZE];
+        mem[0] = state;
+        Isaac64Rng::new(Isaac64Core::init(mem, 1))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.half_used {
+            self.half_used = false;
+            return (self.results[self.index - 1] >> 32) as u32;
+        }
+        let value = self.next_u64();
+        self.half_used = true;
+        value as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= RAND_SIZE {
+            self.core.generate(&mut self.results);
+            self.index = 0;
+        }
+        let value = self.results[self.index];
+        self.index += 1;
+        self.half_used = false;
+        value
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.half_used = false;
+        for chunk in dst.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for Isaac64Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Isaac64Rng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Isaac64Rng;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for Isaac64Rng {
+        fn next_u32(&mut self) -> u32 {
+            Isaac64Rng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            Isaac64Rng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Isaac64Rng::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            Isaac64Rng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for Isaac64Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Isaac64Rng {
+            Isaac64Rng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> Isaac64Rng {
+            Isaac64Rng::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Isaac64Rng;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for Isaac64Rng {
+        fn next_u32(&mut self) -> u32 {
+            Isaac64Rng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            Isaac64Rng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Isaac64Rng::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            Isaac64Rng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for Isaac64Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Isaac64Rng {
+            Isaac64Rng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> Isaac64Rng {
+            Isaac64Rng::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Isaac64Rng;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for Isaac64Rng {
+        fn next_u32(&mut self) -> u32 {
+            Isaac64Rng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            Isaac64Rng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            Isaac64Rng::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for Isaac64Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Isaac64Rng {
+            Isaac64Rng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> Isaac64Rng {
+            Isaac64Rng::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Isaac64Rng;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for Isaac64Rng {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(Isaac64Rng::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(Isaac64Rng::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            Isaac64Rng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for Isaac64Rng {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> Isaac64Rng {
+            Isaac64Rng::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> Isaac64Rng {
+            Isaac64Rng::seed_from_u64(state)
+        }
+    }
+}
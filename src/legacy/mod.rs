@@ -0,0 +1,181 @@
+//! Generators and sampling algorithms of older `rand` versions.
+//!
+//! The generators used to be part of `rand` (`rand::prng` in `rand 0.5`) or
+//! its companion crates `rand_isaac 0.1`, `rand_hc 0.1` and `rand_xorshift
+//! 0.1`, which only implement the traits of `rand_core 0.3`/`rand_core 0.4`.
+//! The types in this module reproduce their output bit for bit, given the same
+//! seed and the same sequence of calls, and implement the traits of every
+//! selected `rand_core`/`rand` version.
+//!
+//! [`Hc128Rng`] is the `StdRng` of `rand 0.5` and `rand 0.6`.
+//!
+//! `SeedableRng::from_seed` and `SeedableRng::seed_from_u64` behave like the
+//! historical implementations. The deprecated re-exports
+//! `rand::prng::IsaacRng` and `rand::prng::Isaac64Rng` of `rand 0.6` are the
+//! exception: they didn't forward `seed_from_u64` to `rand_isaac` and used the
+//! PCG32-based default of `rand_core` instead. Seeding from another RNG uses
+//! the default implementation of each version, i.e. it only generates a seed
+//! of the size of `SeedableRng::Seed`.
+//!
+//! The submodules reproduce how `rand 0.7` to `rand 0.10` turn the output of
+//! an RNG into values, for the [`RandVersion`] selected on each call. Given an
+//! RNG producing the same stream, they return the same values as the
+//! respective `rand` version, without depending on it. They take any RNG
+//! implementing [`WrappedRng`], i.e. the wrapper structs of this crate.
+
+pub use self::hc128::Hc128Rng;
+pub use self::isaac::IsaacRng;
+pub use self::isaac64::Isaac64Rng;
+pub use self::xorshift::XorShiftRng;
+
+pub mod bernoulli;
+#[cfg(feature = "std")]
+pub mod distr;
+pub mod float;
+pub mod seq;
+pub mod uniform;
+#[cfg(feature = "std")]
+pub mod weighted;
+
+mod hc128;
+mod isaac;
+mod isaac64;
+mod xorshift;
+
+/// `rand` version whose sampling algorithms to reproduce.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RandVersion {
+    /// `rand 0.7`.
+    V0_7,
+    /// `rand 0.8`.
+    V0_8,
+    /// `rand 0.9`.
+    V0_9,
+    /// `rand 0.10`.
+    V0_10,
+}
+
+/// Infallible RNG usable with the sampling reproductions of this module.
+///
+/// It is implemented by the infallible wrapper structs of the selected
+/// versions, i.e. `Rng05`, `Rng06`, `Rng09` and `Rng010`, forwarding to the
+/// wrapped RNG. The reproductions call these methods in the same order as the
+/// respective `rand` version calls the methods of its RNG trait.
+pub trait WrappedRng {
+    /// Return the next random `u32`.
+    fn next_u32(&mut self) -> u32;
+    /// Return the next random `u64`.
+    fn next_u64(&mut self) -> u64;
+}
+
+/// Core of a block RNG, generating `N` 32-bit words at once.
+trait BlockCore<const N: usize> {
+    fn generate(&mut self, results: &mut [u32; N]);
+}
+
+/// Block RNG with an output buffer of `N` 32-bit words, behaving like
+/// `rand_core 0.4`'s `BlockRng`: `next_u64` combines two consecutive words,
+/// even across blocks, and `fill_bytes` discards the unused bytes of its last
+/// word.
+#[derive(Clone, Eq, PartialEq)]
+struct BlockRng<C, const N: usize> {
+    core: C,
+    results: [u32; N],
+    index: usize,
+}
+
+impl<C: BlockCore<N>, const N: usize> BlockRng<C, N> {
+    fn new(core: C) -> BlockRng<C, N> {
+        BlockRng {
+            core,
+            results: [0; N],
+            index: N,
+        }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= N {
+            self.core.generate(&mut self.results);
+            self.index = 0;
+        }
+        let value = self.results[self.index];
+        self.index += 1;
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32();
+        let hi = self.next_u32();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(4) {
+            chunk.copy_from_slice(&self.next_u32().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::WrappedRng;
+    use crate::Rng05;
+
+    /// Forward to the wrapped `rand_core 0.5`/`rand 0.7` RNG.
+    impl<T: rand_core_0_5::RngCore> WrappedRng for Rng05<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::WrappedRng;
+    use crate::Rng06;
+
+    /// Forward to the wrapped `rand_core 0.6`/`rand 0.8` RNG.
+    impl<T: rand_core_0_6::RngCore> WrappedRng for Rng06<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::WrappedRng;
+    use crate::Rng09;
+
+    /// Forward to the wrapped `rand_core 0.9`/`rand 0.9` RNG.
+    impl<T: rand_core_0_9::RngCore> WrappedRng for Rng09<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::WrappedRng;
+    use crate::Rng010;
+
+    /// Forward to the wrapped `rand_core 0.10`/`rand 0.10` RNG.
+    impl<T: rand_core_0_10::Rng> WrappedRng for Rng010<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Sampling of distinct indices, as in `rand 0.7` to `rand 0.10`.
+//!
+//! All versions choose between Floyd's algorithm, a partial Fisher-Yates
+//! shuffle and rejection sampling depending on the length and the amount.
+//! `rand 0.9` dropped the shuffling of the result of Floyd's algorithm and
+//! samples indices from ranges longer than `u32::MAX` as `u64`. Index sampling
+//! of `rand 0.8` is that of `rand 0.7`, and index sampling of `rand 0.10` is
+//! that of `rand 0.9`.
+//!
+//! Weighted sampling of indices was added in `rand 0.8`, using keys computed
+//! by Efraimidis and Spirakis' algorithm A-Res. `rand 0.9` switched to their
+//! algorithm A-ExpJ, which skips over indices, and `rand 0.10` reports an
+//! error instead of returning arbitrary indices when there are more infinite
+//! weights than `amount`.
+
+use super::super::RandVersion;
+use super::super::WrappedRng;
+use super::super::float;
+use super::super::uniform::SampleUniform;
+use super::super::uniform::Uniform;
+use super::super::uniform::gen_range;
+use super::super::uniform::gen_range_inclusive;
+use super::super::weighted::WeightError;
+use core::cmp::Ordering;
+use core::cmp::Reverse;
+use core::hash::Hash;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+/// Sample `amount` distinct indices from `0..length` like `index::sample` of
+/// the given `rand` version.
+///
+/// It returns the indices in the same order as the `IndexVec` of `rand`.
+///
+/// Panics if `amount > length`.
+pub fn sample<R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    length: usize,
+    amount: usize,
+) -> Vec<usize> {
+    assert!(
+        amount <= length,
+        "`amount` of samples must be less than or equal to `length`",
+    );
+    if length > u32::MAX as usize {
+        return match version {
+            RandVersion::V0_7 | RandVersion::V0_8 => sample_rejection(version, rng, length, amount),
+            RandVersion::V0_9 | RandVersion::V0_10 => {
+                sample_rejection(version, rng, length as u64, amount)
+                    .into_iter()
+                    .map(|i| i as usize)
+                    .collect()
+            }
+        };
+    }
+    let amount = amount as u32;
+    let length = length as u32;
+    let indices = if amount < 163 {
+        const C: [[f32; 2]; 2] = [[1.6, 8.0 / 45.0], [10.0, 70.0 / 9.0]];
+        let j = if length < 500_000 { 0 } else { 1 };
+        let amount_fp = amount as f32;
+        let m4 = C[0][j] * amount_fp;
+        if amount > 11 && (length as f32) < (C[1][j] + m4) * amount_fp {
+            sample_inplace(version, rng, length, amount)
+        } else {
+            sample_floyd(version, rng, length, amount)
+        }
+    } else {
+        const C: [f32; 2] = [270.0, 330.0 / 9.0];
+        let j = if length < 500_000 { 0 } else { 1 };
+        if (length as f32) < C[j] * (amount as f32) {
+            sample_inplace(version, rng, length, amount)
+        } else {
+            sample_rejection(version, rng, length, amount as usize)
+        }
+    };
+    indices.into_iter().map(|i| i as usize).collect()
+}
+
+/// Sample indices using Floyd's combination algorithm.
+///
+/// `rand 0.7` and `rand 0.8` shuffle the result, by inserting for less than
+/// 50 indices and by a separate shuffle for more.
+fn sample_floyd<R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    length: u32,
+    amount: u32,
+) -> Vec<u32> {
+    let mut indices = Vec::with_capacity(amount as usize);
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 => {
+            let floyd_shuffle = amount < 50;
+            for j in length - amount..length {
+                // `rand 0.8` samples from `0..=j`, which is the same.
+                let t = gen_range(version, rng, 0, j + 1);
+                if floyd_shuffle {
+                    if let Some(pos) = indices.iter().position(|&x| x == t) {
+                        indices.insert(pos, j);
+                        continue;
+                    }
+                } else if indices.contains(&t) {
+                    indices.push(j);
+                    continue;
+                }
+                indices.push(t);
+            }
+            if !floyd_shuffle {
+                for i in (1..amount).rev() {
+                    indices.swap(i as usize, gen_range(version, rng, 0, i + 1) as usize);
+                }
+            }
+        }
+        RandVersion::V0_9 | RandVersion::V0_10 => {
+            for j in length - amount..length {
+                let t = gen_range_inclusive(version, rng, 0, j);
+                if let Some(pos) = indices.iter().position(|&x| x == t) {
+                    indices[pos] = j;
+                }
+                indices.push(t);
+            }
+        }
+    }
+    indices
+}
+
+/// Sample indices using a partial Fisher-Yates shuffle of all indices.
+fn sample_inplace<R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    length: u32,
+    amount: u32,
+) -> Vec<u32> {
+    let mut indices: Vec<u32> = (0..length).collect();
+    for i in 0..amount {
+        let j = gen_range(version, rng, i, length);
+        indices.swap(i as usize, j as usize);
+    }
+    indices.truncate(amount as usize);
+    indices
+}
+
+/// Sample indices by rejecting duplicates.
+fn sample_rejection<X, R>(version: RandVersion, rng: &mut R, length: X, amount: usize) -> Vec<X>
+where
+    X: SampleUniform + Default + Eq + Hash,
+    R: WrappedRng + ?Sized,
+{
+    let mut cache = HashSet::with_capacity(amount);
+    let distr = Uniform::new(version, X::default(), length);
+    let mut indices = Vec::with_capacity(amount);
+    for _ in 0..amount {
+        let mut pos = distr.sample(rng);
+        while !cache.insert(pos) {
+            pos = distr.sample(rng);
+        }
+        indices.push(pos);
+    }
+    indices
+}
+
+/// Sample `amount` distinct indices from `0..length`, with probabilities
+/// proportional to the weights returned by `weight`, like
+/// `index::sample_weighted` of the given `rand` version.
+///
+/// It returns the indices in the same order as the `IndexVec` of `rand`. For
+/// `rand 0.9` and later, it returns fewer indices if there are fewer than
+/// `amount` non-zero weights.
+///
+/// Panics if the version is `rand 0.7`, which didn't provide it, or if
+/// `amount > length` for `rand 0.8`.
+pub fn sample_weighted<R, F, X>(
+    version: RandVersion,
+    rng: &mut R,
+    length: usize,
+    weight: F,
+    amount: usize,
+) -> Result<Vec<usize>, WeightError>
+where
+    R: WrappedRng + ?Sized,
+    F: Fn(usize) -> X,
+    X: Into<f64>,
+{
+    if amount == 0 {
+        return Ok(Vec::new());
+    }
+    match version {
+        RandVersion::V0_7 => panic!("rand 0.7 doesn't sample weighted indices"),
+        RandVersion::V0_8 => {
+            assert!(
+                amount <= length,
+                "`amount` of samples must be less than or equal to `length`",
+            );
+            sample_a_res(version, rng, length, weight, amount)
+        }
+        RandVersion::V0_9 | RandVersion::V0_10 => {
+            sample_a_expj(version, rng, length, weight, amount)
+        }
+    }
+}
+
+/// Candidate index of weighted sampling, ordered by its key.
+struct Element {
+    index: usize,
+    key: f64,
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Element) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Element {}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Element) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Panics if a key is NaN, like `rand 0.9`. The keys of `rand 0.8` are never
+/// NaN.
+impl Ord for Element {
+    fn cmp(&self, other: &Element) -> Ordering {
+        self.key.partial_cmp(&other.key).unwrap()
+    }
+}
+
+/// Sample weighted indices using keys `u^(1 / weight)`, like `rand 0.8`.
+///
+/// Returns the indices with the largest keys, in decreasing order.
+fn sample_a_res<R, F, X>(
+    version: RandVersion,
+    rng: &mut R,
+    length: usize,
+    weight: F,
+    amount: usize,
+) -> Result<Vec<usize>, WeightError>
+where
+    R: WrappedRng + ?Sized,
+    F: Fn(usize) -> X,
+    X: Into<f64>,
+{
+    let mut candidates = BinaryHeap::with_capacity(length);
+    for index in 0..length {
+        let weight = weight(index).into();
+        if weight < 0.0 || weight.is_nan() {
+            return Err(WeightError::InvalidWeight);
+        }
+        let key = float::standard::<f64, _>(version, rng).powf(1.0 / weight);
+        candidates.push(Element { index, key });
+    }
+    let mut result = Vec::with_capacity(amount);
+    while result.len() < amount {
+        result.push(candidates.pop().unwrap().index);
+    }
+    Ok(result)
+}
+
+/// Sample weighted indices using keys `ln(u) / weight` and exponential jumps
+/// over indices, like `rand 0.9`.
+///
+/// Keeps the indices with the largest keys in a heap and returns them in the
+/// order of the heap.
+fn sample_a_expj<R, F, X>(
+    version: RandVersion,
+    rng: &mut R,
+    length: usize,
+    weight: F,
+    amount: usize,
+) -> Result<Vec<usize>, WeightError>
+where
+    R: WrappedRng + ?Sized,
+    F: Fn(usize) -> X,
+    X: Into<f64>,
+{
+    let mut candidates = BinaryHeap::with_capacity(amount);
+    let mut index = 0;
+    while index < length && candidates.len() < amount {
+        let weight = weight(index).into();
+        if weight > 0.0 {
+            let key = float::standard::<f64, _>(version, rng).ln() / weight;
+            candidates.push(Reverse(Element { index, key }));
+        } else if weight < 0.0 || weight.is_nan() {
+            return Err(WeightError::InvalidWeight);
+        }
+        index += 1;
+    }
+    if index < length {
+        let mut x = float::standard::<f64, _>(version, rng).ln() / min_key(&candidates);
+        while index < length {
+            if version == RandVersion::V0_10 && !x.is_finite() {
+                return Err(WeightError::InvalidWeight);
+            }
+            let weight = weight(index).into();
+            if weight > 0.0 {
+                x -= weight;
+                if x <= 0.0 {
+                    let Reverse(min_candidate) = candidates.pop().unwrap();
+                    let t = (min_candidate.key * weight).exp();
+                    let key = gen_range(version, rng, t, 1.0).ln() / weight;
+                    candidates.push(Reverse(Element { index, key }));
+                    x = float::standard::<f64, _>(version, rng).ln() / min_key(&candidates);
+                }
+            } else if weight < 0.0 || weight.is_nan() {
+                return Err(WeightError::InvalidWeight);
+            }
+            index += 1;
+        }
+    }
+    Ok(candidates.iter().map(|Reverse(e)| e.index).collect())
+}
+
+/// Return the smallest key of the candidates.
+fn min_key(candidates: &BinaryHeap<Reverse<Element>>) -> f64 {
+    candidates.peek().unwrap().0.key
+}
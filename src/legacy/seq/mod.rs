@@ -0,0 +1,241 @@
+//! Shuffling and choosing from slices, as in `rand 0.7` to `rand 0.10`.
+//!
+//! `rand 0.9` reversed the Fisher-Yates shuffle, placing the elements from the
+//! front instead of from the back, and draws several indices from a single
+//! random `u32`. Sequence operations of `rand 0.8` are those of `rand 0.7`,
+//! and sequence operations of `rand 0.10` are those of `rand 0.9`.
+//!
+//! The functions take slices; `rand` also implements some of them for
+//! iterators, which sample differently.
+
+use super::RandVersion;
+use super::WrappedRng;
+use super::uniform::gen_range;
+#[cfg(feature = "std")]
+use super::weighted::Weight;
+#[cfg(feature = "std")]
+use super::weighted::WeightError;
+#[cfg(feature = "std")]
+use super::weighted::WeightedIndex;
+
+#[cfg(feature = "std")]
+pub mod index;
+
+/// Return a random element of `slice` like `choose` of the given `rand`
+/// version, or `None` if `slice` is empty.
+///
+/// This reproduces `SliceRandom::choose` of `rand 0.7` and `rand 0.8` and
+/// `IndexedRandom::choose` of `rand 0.9` and `rand 0.10`.
+pub fn choose<'a, T, R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    slice: &'a [T],
+) -> Option<&'a T> {
+    if slice.is_empty() {
+        return None;
+    }
+    let index = match version {
+        RandVersion::V0_7 | RandVersion::V0_8 => gen_index(version, rng, slice.len()),
+        RandVersion::V0_9 | RandVersion::V0_10 => gen_range(version, rng, 0, slice.len()),
+    };
+    Some(&slice[index])
+}
+
+/// Return an iterator over `amount` distinct random elements of `slice` like
+/// `choose_multiple` of the given `rand` version.
+///
+/// It returns all elements if `amount` is larger than the length of `slice`.
+/// The elements are those at the indices returned by [`index::sample`], in the
+/// same order.
+///
+/// This reproduces `SliceRandom::choose_multiple` of `rand 0.7` and
+/// `rand 0.8`, `IndexedRandom::choose_multiple` of `rand 0.9` and
+/// `IndexedRandom::sample` of `rand 0.10`.
+#[cfg(feature = "std")]
+pub fn choose_multiple<'a, T, R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    slice: &'a [T],
+    amount: usize,
+) -> impl Iterator<Item = &'a T> {
+    let amount = amount.min(slice.len());
+    index::sample(version, rng, slice.len(), amount)
+        .into_iter()
+        .map(move |i| &slice[i])
+}
+
+/// Return a random element of `slice` with probabilities proportional to the
+/// weights returned by `weight`, like `choose_weighted` of the given `rand`
+/// version.
+///
+/// This samples a [`WeightedIndex`] of the weights. It reproduces
+/// `SliceRandom::choose_weighted` of `rand 0.7` and `rand 0.8` and
+/// `IndexedRandom::choose_weighted` of `rand 0.9` and `rand 0.10`.
+#[cfg(feature = "std")]
+pub fn choose_weighted<'a, T, X, R, F>(
+    version: RandVersion,
+    rng: &mut R,
+    slice: &'a [T],
+    weight: F,
+) -> Result<&'a T, WeightError>
+where
+    X: Weight,
+    R: WrappedRng + ?Sized,
+    F: Fn(&T) -> X,
+{
+    let distr = WeightedIndex::new(version, slice.iter().map(weight))?;
+    Ok(&slice[distr.sample(rng)])
+}
+
+/// Return an iterator over `amount` distinct random elements of `slice`, with
+/// probabilities proportional to the weights returned by `weight`, like
+/// `choose_multiple_weighted` of the given `rand` version.
+///
+/// The elements are those at the indices returned by
+/// [`index::sample_weighted`], in the same order. It returns all elements if
+/// `amount` is larger than the length of `slice`, and for `rand 0.9` and
+/// later, only those with non-zero weights if there are fewer than `amount`.
+///
+/// This reproduces `SliceRandom::choose_multiple_weighted` of `rand 0.8` and
+/// `IndexedRandom::choose_multiple_weighted` of `rand 0.9` and `rand 0.10`.
+///
+/// Panics if the version is `rand 0.7`, which didn't provide it.
+#[cfg(feature = "std")]
+pub fn choose_multiple_weighted<'a, T, X, R, F>(
+    version: RandVersion,
+    rng: &mut R,
+    slice: &'a [T],
+    amount: usize,
+    weight: F,
+) -> Result<impl Iterator<Item = &'a T>, WeightError>
+where
+    X: Into<f64>,
+    R: WrappedRng + ?Sized,
+    F: Fn(&T) -> X,
+{
+    let amount = amount.min(slice.len());
+    let indices = index::sample_weighted(version, rng, slice.len(), |i| weight(&slice[i]), amount)?;
+    Ok(indices.into_iter().map(move |i| &slice[i]))
+}
+
+/// Shuffle `slice` like `shuffle` of the given `rand` version.
+///
+/// This reproduces `SliceRandom::shuffle` of all versions.
+pub fn shuffle<T, R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, slice: &mut [T]) {
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 => {
+            for i in (1..slice.len()).rev() {
+                slice.swap(i, gen_index(version, rng, i + 1));
+            }
+        }
+        RandVersion::V0_9 | RandVersion::V0_10 => {
+            partial_shuffle(version, rng, slice, slice.len());
+        }
+    }
+}
+
+/// Shuffle `amount` random elements of `slice` like `partial_shuffle` of the
+/// given `rand` version.
+///
+/// It returns the `amount` random elements, or all elements if `amount` is
+/// larger than the length of `slice`, and the remaining elements. All
+/// versions move the random elements to the end of `slice`.
+///
+/// This reproduces `SliceRandom::partial_shuffle` of all versions.
+pub fn partial_shuffle<'a, T, R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    slice: &'a mut [T],
+    amount: usize,
+) -> (&'a mut [T], &'a mut [T]) {
+    let len = slice.len();
+    let end = len.saturating_sub(amount);
+    match version {
+        RandVersion::V0_7 | RandVersion::V0_8 => {
+            for i in (end..len).rev() {
+                slice.swap(i, gen_index(version, rng, i + 1));
+            }
+        }
+        RandVersion::V0_9 | RandVersion::V0_10 => {
+            if len < u32::MAX as usize {
+                let mut chooser = IncreasingUniform::new(end as u32);
+                for i in end..len {
+                    slice.swap(i, chooser.next_index(version, rng));
+                }
+            } else {
+                for i in end..len {
+                    slice.swap(i, gen_range(version, rng, 0, i + 1));
+                }
+            }
+        }
+    }
+    let (rest, shuffled) = slice.split_at_mut(end);
+    (shuffled, rest)
+}
+
+/// Sample an index from `0..ubound` like `gen_index` of `rand 0.7` and
+/// `rand 0.8`, sampling a `u32` if possible.
+fn gen_index<R: WrappedRng + ?Sized>(version: RandVersion, rng: &mut R, ubound: usize) -> usize {
+    if ubound <= u32::MAX as usize {
+        gen_range(version, rng, 0, ubound as u32) as usize
+    } else {
+        gen_range(version, rng, 0, ubound)
+    }
+}
+
+/// Sampler of the indices `0..=n`, `0..=n + 1` and so on, like
+/// `IncreasingUniform` of `rand 0.9`.
+///
+/// It samples a random number from the product of several consecutive bounds
+/// fitting into a `u32` and splits it into indices.
+struct IncreasingUniform {
+    n: u32,
+    chunk: u32,
+    chunk_remaining: u8,
+}
+
+impl IncreasingUniform {
+    fn new(n: u32) -> IncreasingUniform {
+        IncreasingUniform {
+            n,
+            chunk: 0,
+            // The first index from `0..=0` needs no random number.
+            chunk_remaining: if n == 0 { 1 } else { 0 },
+        }
+    }
+
+    /// Sample the next index from `0..=n` and increment `n`.
+    fn next_index<R: WrappedRng + ?Sized>(&mut self, version: RandVersion, rng: &mut R) -> usize {
+        let next_n = self.n + 1;
+        let next_chunk_remaining = match self.chunk_remaining.checked_sub(1) {
+            Some(remaining) => remaining,
+            None => {
+                let (bound, remaining) = bound(next_n);
+                self.chunk = gen_range(version, rng, 0, bound);
+                remaining - 1
+            }
+        };
+        let result = if next_chunk_remaining == 0 {
+            self.chunk
+        } else {
+            let r = self.chunk % next_n;
+            self.chunk /= next_n;
+            r
+        };
+        self.chunk_remaining = next_chunk_remaining;
+        self.n = next_n;
+        result as usize
+    }
+}
+
+/// Return the product of `m`, `m + 1` and so on as long as it fits into a
+/// `u32`, and the number of factors.
+fn bound(m: u32) -> (u32, u8) {
+    let mut product = m;
+    let mut current = m + 1;
+    while let Some(p) = product.checked_mul(current) {
+        product = p;
+        current += 1;
+    }
+    (product, (current - m) as u8)
+}
@@ -0,0 +1,771 @@
+//! Uniform sampling from a range, as in `rand 0.7` to `rand 0.10`.
+//!
+//! For integers, `gen_range` used a cheaper rejection zone than [`Uniform`] up
+//! to `rand 0.8`, and `rand 0.9` replaced the rejection sampling by Canon's
+//! method for single samples and by Lemire's method with an exact threshold
+//! for [`Uniform`]. Integer
+//! sampling of `rand 0.10` is that of `rand 0.9`, and `rand 0.8` only differs
+//! from `rand 0.7` in single samples from inclusive ranges, which `rand 0.7`
+//! didn't provide.
+//!
+//! The reproductions of `rand 0.9` and `rand 0.10` match the default
+//! configuration, without the `unbiased` feature of `rand`. Like these
+//! versions, they sample `usize` as a `u32` when the upper bound fits into a
+//! `u32`, independently of the pointer width. They don't support `isize`,
+//! which these versions don't sample uniformly.
+//!
+//! For floats, `rand 0.9` dropped the rejection of samples rounded up to the
+//! excluded upper bound from single samples, and lets [`Uniform::new`] return
+//! the upper bound through rounding. Floats are sampled by scaling a float in
+//! `[0, 1)` with 23 or 52 random bits in all versions, so that the samples of
+//! integers and floats differ. Uniform sampling of `rand 0.10` is that of
+//! `rand 0.9`.
+
+use self::private::Sealed;
+use super::RandVersion;
+use super::WrappedRng;
+
+/// Type which can be sampled uniformly from a range.
+///
+/// It is implemented by the primitive integer and floating-point types. It is
+/// sealed, i.e. it cannot be implemented outside of this crate.
+pub trait SampleUniform: Copy + PartialOrd + Sealed {}
+
+impl SampleUniform for i8 {}
+impl SampleUniform for i16 {}
+impl SampleUniform for i32 {}
+impl SampleUniform for i64 {}
+impl SampleUniform for i128 {}
+impl SampleUniform for isize {}
+impl SampleUniform for u8 {}
+impl SampleUniform for u16 {}
+impl SampleUniform for u32 {}
+impl SampleUniform for u64 {}
+impl SampleUniform for u128 {}
+impl SampleUniform for usize {}
+impl SampleUniform for f32 {}
+impl SampleUniform for f64 {}
+
+/// Sample a value from the range `low..high` like `gen_range` of the given
+/// `rand` version.
+///
+/// This reproduces `Rng::gen_range(low, high)` of `rand 0.7`,
+/// `Rng::gen_range(low..high)` of `rand 0.8` and `Rng::random_range(low..high)`
+/// of `rand 0.9` and `rand 0.10`.
+///
+/// Panics if `low >= high`, if `T` is `isize` and the version is `rand 0.9`
+/// or later, or if `T` is a float and `high - low` isn't finite.
+pub fn gen_range<T: SampleUniform, R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    low: T,
+    high: T,
+) -> T {
+    T::sample_single(version, rng, low, high)
+}
+
+/// Sample a value from the range `low..=high` like `gen_range` of the given
+/// `rand` version.
+///
+/// This reproduces `Rng::gen_range(low..=high)` of `rand 0.8` and
+/// `Rng::random_range(low..=high)` of `rand 0.9` and `rand 0.10`. `rand 0.7`
+/// had no single samples from inclusive ranges; for [`RandVersion::V0_7`],
+/// this reproduces `Uniform::new_inclusive(low, high).sample(rng)` instead.
+///
+/// Panics if `low > high`, if `T` is `isize` and the version is `rand 0.9`
+/// or later, or if `T` is a float and `high - low` isn't finite.
+pub fn gen_range_inclusive<T: SampleUniform, R: WrappedRng + ?Sized>(
+    version: RandVersion,
+    rng: &mut R,
+    low: T,
+    high: T,
+) -> T {
+    T::sample_single_inclusive(version, rng, low, high)
+}
+
+/// Uniform distribution over a range, like `Uniform` of a given `rand`
+/// version.
+///
+/// It precomputes the rejection threshold for sampling repeatedly from the
+/// same range. Its samples differ from those of [`gen_range`] for the same
+/// range, as in `rand`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uniform<T: SampleUniform> {
+    sampler: T::Sampler,
+}
+
+impl<T: SampleUniform> Uniform<T> {
+    /// Create a uniform distribution over `low..high`, like `Uniform::new` of
+    /// the given `rand` version.
+    ///
+    /// Panics if `low >= high`, if `T` is `isize` and the version is
+    /// `rand 0.9` or later, or if `T` is a float and `high - low` isn't
+    /// finite.
+    pub fn new(version: RandVersion, low: T, high: T) -> Uniform<T> {
+        Uniform {
+            sampler: T::new(version, low, high),
+        }
+    }
+
+    /// Create a uniform distribution over `low..=high`, like
+    /// `Uniform::new_inclusive` of the given `rand` version.
+    ///
+    /// Panics if `low > high`, if `T` is `isize` and the version is
+    /// `rand 0.9` or later, or if `T` is a float and `high - low` isn't
+    /// finite.
+    pub fn new_inclusive(version: RandVersion, low: T, high: T) -> Uniform<T> {
+        Uniform {
+            sampler: T::new_inclusive(version, low, high),
+        }
+    }
+
+    /// Sample a value, like `Distribution::sample` of `Uniform`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> T {
+        T::sample(&self.sampler, rng)
+    }
+}
+
+mod private {
+    use super::super::RandVersion;
+    use super::super::WrappedRng;
+    use super::super::float::private::Sealed as Float;
+    use core::fmt;
+
+    /// Sampling algorithms of a type.
+    pub trait Sealed: Sized {
+        /// State of a `Uniform` distribution.
+        type Sampler: Clone + Copy + fmt::Debug + PartialEq;
+
+        fn new(version: RandVersion, low: Self, high: Self) -> Self::Sampler;
+        fn new_inclusive(version: RandVersion, low: Self, high: Self) -> Self::Sampler;
+        fn sample<R: WrappedRng + ?Sized>(sampler: &Self::Sampler, rng: &mut R) -> Self;
+        fn sample_single<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: Self,
+            high: Self,
+        ) -> Self;
+        fn sample_single_inclusive<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: Self,
+            high: Self,
+        ) -> Self;
+    }
+
+    /// Primitive integer type.
+    ///
+    /// Values are handled as their bits, i.e. as the corresponding unsigned
+    /// type, zero-extended to `u128`.
+    pub trait Int: Copy + PartialOrd + fmt::Debug {
+        /// Number of bits of the type.
+        const BITS: u32;
+
+        /// Return the bits of `self`.
+        fn to_bits(self) -> u128;
+
+        /// Return the value with the lowest `BITS` bits of `bits`.
+        fn from_bits(bits: u128) -> Self;
+
+        /// Return the number of bits of the unsigned integers the given
+        /// version samples for a range with the upper bound `high`.
+        fn sample_bits(version: RandVersion, high: Self) -> u32;
+    }
+
+    /// State of a `Uniform` distribution over integers.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct UniformInt<T> {
+        version: RandVersion,
+        low: T,
+        /// Size of the range, 0 for the full range of the sampled integers.
+        range: u128,
+        /// Number of sampled integers rejected to avoid a bias.
+        reject: u128,
+        /// Number of bits of the sampled integers.
+        bits: u32,
+    }
+
+    impl<T: Int> Sealed for T {
+        type Sampler = UniformInt<T>;
+
+        fn new(version: RandVersion, low: T, high: T) -> UniformInt<T> {
+            assert!(low < high, "Uniform::new called with `low >= high`");
+            T::new_inclusive(version, low, offset(high, u128::MAX))
+        }
+
+        fn new_inclusive(version: RandVersion, low: T, high: T) -> UniformInt<T> {
+            assert!(
+                low <= high,
+                "Uniform::new_inclusive called with `low > high`"
+            );
+            let bits = T::sample_bits(version, high);
+            let range = range(low, high, bits);
+            let reject = if range > 0 {
+                (mask(bits) - range + 1) % range
+            } else {
+                0
+            };
+            UniformInt {
+                version,
+                low,
+                range,
+                reject,
+                bits,
+            }
+        }
+
+        fn sample<R: WrappedRng + ?Sized>(sampler: &UniformInt<T>, rng: &mut R) -> T {
+            let bits = sampler.bits;
+            if sampler.range == 0 {
+                return T::from_bits(next(rng, bits));
+            }
+            loop {
+                let (hi, lo) = wmul(next(rng, bits), sampler.range, bits);
+                let accept = match sampler.version {
+                    RandVersion::V0_7 | RandVersion::V0_8 => lo <= mask(bits) - sampler.reject,
+                    RandVersion::V0_9 | RandVersion::V0_10 => lo >= sampler.reject,
+                };
+                if accept {
+                    return offset(sampler.low, hi);
+                }
+            }
+        }
+
+        fn sample_single<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: T,
+            high: T,
+        ) -> T {
+            assert!(low < high, "cannot sample empty range");
+            // `rand 0.9` chooses the sampled integers for `usize` by the
+            // exclusive upper bound.
+            let bits = T::sample_bits(version, high);
+            sample_single_inclusive(version, rng, low, offset(high, u128::MAX), bits)
+        }
+
+        fn sample_single_inclusive<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: T,
+            high: T,
+        ) -> T {
+            assert!(low <= high, "cannot sample empty range");
+            if version == RandVersion::V0_7 {
+                return T::sample(&T::new_inclusive(version, low, high), rng);
+            }
+            let bits = T::sample_bits(version, high);
+            sample_single_inclusive(version, rng, low, high, bits)
+        }
+    }
+
+    /// Sample a value from `low..=high` using `bits`-bit integers, like
+    /// `sample_single_inclusive` of `rand 0.8` or later.
+    ///
+    /// The exclusive `sample_single` of `rand 0.7` is the same as that of
+    /// `rand 0.8`.
+    fn sample_single_inclusive<T: Int, R: WrappedRng + ?Sized>(
+        version: RandVersion,
+        rng: &mut R,
+        low: T,
+        high: T,
+        bits: u32,
+    ) -> T {
+        let range = range(low, high, bits);
+        if range == 0 {
+            return T::from_bits(next(rng, bits));
+        }
+        match version {
+            RandVersion::V0_7 | RandVersion::V0_8 => {
+                // Reject the exact number of biased integers only for small
+                // types, and otherwise up to the size of the range.
+                let zone = if T::BITS <= 16 {
+                    mask(bits) - (mask(bits) - range + 1) % range
+                } else {
+                    (range << (range.leading_zeros() - (128 - bits))) - 1
+                };
+                loop {
+                    let (hi, lo) = wmul(next(rng, bits), range, bits);
+                    if lo <= zone {
+                        return offset(low, hi);
+                    }
+                }
+            }
+            RandVersion::V0_9 | RandVersion::V0_10 => {
+                // Canon's method: correct the result using a second sample if
+                // the first one is close to a boundary.
+                let (mut result, lo) = wmul(next(rng, bits), range, bits);
+                if lo > mask(bits) - range + 1 {
+                    let (new_hi, _) = wmul(next(rng, bits), range, bits);
+                    if lo.checked_add(new_hi).map_or(true, |sum| sum > mask(bits)) {
+                        result += 1;
+                    }
+                }
+                offset(low, result)
+            }
+        }
+    }
+
+    /// Return the size of the range `low..=high` of `T` as a `bits`-bit
+    /// integer, i.e. 0 for the full range.
+    fn range<T: Int>(low: T, high: T, bits: u32) -> u128 {
+        let range = high.to_bits().wrapping_sub(low.to_bits()).wrapping_add(1);
+        range & mask(T::BITS.min(bits))
+    }
+
+    /// Add `offset` to `value`, wrapping around.
+    fn offset<T: Int>(value: T, offset: u128) -> T {
+        T::from_bits(value.to_bits().wrapping_add(offset))
+    }
+
+    /// Return the largest `bits`-bit integer.
+    fn mask(bits: u32) -> u128 {
+        u128::MAX >> (128 - bits)
+    }
+
+    /// Return a random `bits`-bit integer, like `Standard` of the respective
+    /// unsigned type.
+    fn next<R: WrappedRng + ?Sized>(rng: &mut R, bits: u32) -> u128 {
+        match bits {
+            128 => {
+                let lo = u128::from(rng.next_u64());
+                let hi = u128::from(rng.next_u64());
+                (hi << 64) | lo
+            }
+            64 => rng.next_u64().into(),
+            _ => u128::from(rng.next_u32()) & mask(bits),
+        }
+    }
+
+    /// Multiply two `bits`-bit integers, returning the high and the low half
+    /// of the product.
+    fn wmul(x: u128, y: u128, bits: u32) -> (u128, u128) {
+        if bits < 128 {
+            let product = x * y;
+            return (product >> bits, product & mask(bits));
+        }
+        let lower = u128::from(u64::MAX);
+        let (x_hi, x_lo) = (x >> 64, x & lower);
+        let (y_hi, y_lo) = (y >> 64, y & lower);
+        let lo_lo = x_lo * y_lo;
+        let lo_hi = x_lo * y_hi;
+        let hi_lo = x_hi * y_lo;
+        let middle = (lo_lo >> 64) + (lo_hi & lower) + (hi_lo & lower);
+        let hi = x_hi * y_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64);
+        (hi, (middle << 64) | (lo_lo & lower))
+    }
+
+    /// State of a `Uniform` distribution over floats.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct UniformFloat<F> {
+        version: RandVersion,
+        low: F,
+        scale: F,
+    }
+
+    impl Sealed for f32 {
+        type Sampler = UniformFloat<f32>;
+
+        fn new(version: RandVersion, low: f32, high: f32) -> UniformFloat<f32> {
+            float_new(version, low, high)
+        }
+
+        fn new_inclusive(version: RandVersion, low: f32, high: f32) -> UniformFloat<f32> {
+            float_new_inclusive(version, low, high)
+        }
+
+        fn sample<R: WrappedRng + ?Sized>(sampler: &UniformFloat<f32>, rng: &mut R) -> f32 {
+            float_sample(sampler, rng)
+        }
+
+        fn sample_single<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: f32,
+            high: f32,
+        ) -> f32 {
+            float_sample_single(version, rng, low, high)
+        }
+
+        fn sample_single_inclusive<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: f32,
+            high: f32,
+        ) -> f32 {
+            float_sample_single_inclusive(version, rng, low, high)
+        }
+    }
+
+    impl Sealed for f64 {
+        type Sampler = UniformFloat<f64>;
+
+        fn new(version: RandVersion, low: f64, high: f64) -> UniformFloat<f64> {
+            float_new(version, low, high)
+        }
+
+        fn new_inclusive(version: RandVersion, low: f64, high: f64) -> UniformFloat<f64> {
+            float_new_inclusive(version, low, high)
+        }
+
+        fn sample<R: WrappedRng + ?Sized>(sampler: &UniformFloat<f64>, rng: &mut R) -> f64 {
+            float_sample(sampler, rng)
+        }
+
+        fn sample_single<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: f64,
+            high: f64,
+        ) -> f64 {
+            float_sample_single(version, rng, low, high)
+        }
+
+        fn sample_single_inclusive<R: WrappedRng + ?Sized>(
+            version: RandVersion,
+            rng: &mut R,
+            low: f64,
+            high: f64,
+        ) -> f64 {
+            float_sample_single_inclusive(version, rng, low, high)
+        }
+    }
+
+    fn float_new<F: Float>(version: RandVersion, low: F, high: F) -> UniformFloat<F> {
+        assert!(low < high, "Uniform::new called with `low >= high`");
+        let scale = high - low;
+        let exclusive = match version {
+            RandVersion::V0_7 => {
+                assert!(
+                    low.finite() && high.finite(),
+                    "Uniform::new called with non-finite boundaries"
+                );
+                true
+            }
+            RandVersion::V0_8 => {
+                assert!(scale.finite(), "Uniform::new: range overflow");
+                true
+            }
+            RandVersion::V0_9 | RandVersion::V0_10 => {
+                assert!(scale.finite(), "Uniform::new: range overflow");
+                false
+            }
+        };
+        UniformFloat {
+            version,
+            low,
+            scale: bound_scale(low, high, scale, exclusive),
+        }
+    }
+
+    fn float_new_inclusive<F: Float>(version: RandVersion, low: F, high: F) -> UniformFloat<F> {
+        assert!(
+            low <= high,
+            "Uniform::new_inclusive called with `low > high`"
+        );
+        let scale = (high - low) / F::MAX_RAND;
+        match version {
+            RandVersion::V0_7 => assert!(
+                low.finite() && high.finite(),
+                "Uniform::new_inclusive called with non-finite boundaries"
+            ),
+            RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => {
+                assert!(scale.finite(), "Uniform::new_inclusive: range overflow")
+            }
+        }
+        UniformFloat {
+            version,
+            low,
+            scale: bound_scale(low, high, scale, false),
+        }
+    }
+
+    /// Decrease `scale` until the largest sample is below `high`, or at most
+    /// `high` if not `exclusive`.
+    fn bound_scale<F: Float>(low: F, high: F, mut scale: F, exclusive: bool) -> F {
+        loop {
+            let max = scale * F::MAX_RAND + low;
+            if !(max > high || exclusive && max == high) {
+                return scale;
+            }
+            scale = scale.decrease();
+        }
+    }
+
+    fn float_sample<F: Float, R: WrappedRng + ?Sized>(sampler: &UniformFloat<F>, rng: &mut R) -> F {
+        match sampler.version {
+            RandVersion::V0_7 | RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => {
+                F::sample0_1(rng) * sampler.scale + sampler.low
+            }
+        }
+    }
+
+    fn float_sample_single<F: Float, R: WrappedRng + ?Sized>(
+        version: RandVersion,
+        rng: &mut R,
+        low: F,
+        high: F,
+    ) -> F {
+        assert!(low < high, "cannot sample empty range");
+        let mut scale = high - low;
+        match version {
+            RandVersion::V0_7 => loop {
+                // Reduce an overflowing scale until samples are finite.
+                let value = F::sample0_1(rng) * scale + low;
+                if value < high {
+                    return value;
+                }
+                if !scale.finite() {
+                    assert!(
+                        low.finite() && high.finite(),
+                        "Uniform::sample_single: low and high must be finite"
+                    );
+                    scale = scale.decrease();
+                }
+            },
+            RandVersion::V0_8 => {
+                assert!(
+                    scale.finite(),
+                    "UniformSampler::sample_single: range overflow"
+                );
+                loop {
+                    let value = F::sample0_1(rng) * scale + low;
+                    if value < high {
+                        return value;
+                    }
+                }
+            }
+            RandVersion::V0_9 | RandVersion::V0_10 => {
+                float_sample_single_inclusive(version, rng, low, high)
+            }
+        }
+    }
+
+    fn float_sample_single_inclusive<F: Float, R: WrappedRng + ?Sized>(
+        version: RandVersion,
+        rng: &mut R,
+        low: F,
+        high: F,
+    ) -> F {
+        assert!(low <= high, "cannot sample empty range");
+        match version {
+            RandVersion::V0_7 | RandVersion::V0_8 => {
+                float_sample(&float_new_inclusive(version, low, high), rng)
+            }
+            RandVersion::V0_9 | RandVersion::V0_10 => {
+                let scale = high - low;
+                assert!(
+                    scale.finite(),
+                    "UniformSampler::sample_single: range overflow"
+                );
+                F::sample0_1(rng) * scale + low
+            }
+        }
+    }
+
+    impl Int for i8 {
+        const BITS: u32 = 8;
+
+        fn to_bits(self) -> u128 {
+            self as u8 as u128
+        }
+
+        fn from_bits(bits: u128) -> i8 {
+            bits as u8 as i8
+        }
+
+        fn sample_bits(_version: RandVersion, _high: i8) -> u32 {
+            32
+        }
+    }
+
+    impl Int for i16 {
+        const BITS: u32 = 16;
+
+        fn to_bits(self) -> u128 {
+            self as u16 as u128
+        }
+
+        fn from_bits(bits: u128) -> i16 {
+            bits as u16 as i16
+        }
+
+        fn sample_bits(_version: RandVersion, _high: i16) -> u32 {
+            32
+        }
+    }
+
+    impl Int for i32 {
+        const BITS: u32 = 32;
+
+        fn to_bits(self) -> u128 {
+            self as u32 as u128
+        }
+
+        fn from_bits(bits: u128) -> i32 {
+            bits as u32 as i32
+        }
+
+        fn sample_bits(_version: RandVersion, _high: i32) -> u32 {
+            32
+        }
+    }
+
+    impl Int for i64 {
+        const BITS: u32 = 64;
+
+        fn to_bits(self) -> u128 {
+            self as u64 as u128
+        }
+
+        fn from_bits(bits: u128) -> i64 {
+            bits as u64 as i64
+        }
+
+        fn sample_bits(_version: RandVersion, _high: i64) -> u32 {
+            64
+        }
+    }
+
+    impl Int for i128 {
+        const BITS: u32 = 128;
+
+        fn to_bits(self) -> u128 {
+            self as u128
+        }
+
+        fn from_bits(bits: u128) -> i128 {
+            bits as i128
+        }
+
+        fn sample_bits(_version: RandVersion, _high: i128) -> u32 {
+            128
+        }
+    }
+
+    impl Int for isize {
+        const BITS: u32 = usize::BITS;
+
+        fn to_bits(self) -> u128 {
+            self as usize as u128
+        }
+
+        fn from_bits(bits: u128) -> isize {
+            bits as usize as isize
+        }
+
+        fn sample_bits(version: RandVersion, _high: isize) -> u32 {
+            match version {
+                RandVersion::V0_7 | RandVersion::V0_8 => usize::BITS,
+                RandVersion::V0_9 | RandVersion::V0_10 => {
+                    panic!("rand 0.9 and later don't sample isize uniformly")
+                }
+            }
+        }
+    }
+
+    impl Int for u8 {
+        const BITS: u32 = 8;
+
+        fn to_bits(self) -> u128 {
+            self.into()
+        }
+
+        fn from_bits(bits: u128) -> u8 {
+            bits as u8
+        }
+
+        fn sample_bits(_version: RandVersion, _high: u8) -> u32 {
+            32
+        }
+    }
+
+    impl Int for u16 {
+        const BITS: u32 = 16;
+
+        fn to_bits(self) -> u128 {
+            self.into()
+        }
+
+        fn from_bits(bits: u128) -> u16 {
+            bits as u16
+        }
+
+        fn sample_bits(_version: RandVersion, _high: u16) -> u32 {
+            32
+        }
+    }
+
+    impl Int for u32 {
+        const BITS: u32 = 32;
+
+        fn to_bits(self) -> u128 {
+            self.into()
+        }
+
+        fn from_bits(bits: u128) -> u32 {
+            bits as u32
+        }
+
+        fn sample_bits(_version: RandVersion, _high: u32) -> u32 {
+            32
+        }
+    }
+
+    impl Int for u64 {
+        const BITS: u32 = 64;
+
+        fn to_bits(self) -> u128 {
+            self.into()
+        }
+
+        fn from_bits(bits: u128) -> u64 {
+            bits as u64
+        }
+
+        fn sample_bits(_version: RandVersion, _high: u64) -> u32 {
+            64
+        }
+    }
+
+    impl Int for u128 {
+        const BITS: u32 = 128;
+
+        fn to_bits(self) -> u128 {
+            self
+        }
+
+        fn from_bits(bits: u128) -> u128 {
+            bits
+        }
+
+        fn sample_bits(_version: RandVersion, _high: u128) -> u32 {
+            128
+        }
+    }
+
+    impl Int for usize {
+        const BITS: u32 = usize::BITS;
+
+        fn to_bits(self) -> u128 {
+            self as u128
+        }
+
+        fn from_bits(bits: u128) -> usize {
+            bits as usize
+        }
+
+        fn sample_bits(version: RandVersion, high: usize) -> u32 {
+            match version {
+                RandVersion::V0_7 | RandVersion::V0_8 => usize::BITS,
+                RandVersion::V0_9 | RandVersion::V0_10 => {
+                    if usize::BITS > 32 && high > u32::MAX as usize {
+                        64
+                    } else {
+                        32
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,616 @@
+//! Sampling of indices with given weights, as in `rand 0.7` to `rand 0.10`.
+//!
+//! [`WeightedIndex`] samples a value below the total weight with [`Uniform`]
+//! and looks it up in the cumulative weights in all versions, so its samples
+//! change with the uniform sampling of the respective version. The versions
+//! differ in the weights they accept: `rand 0.8` rejects NaN, `rand 0.9`
+//! reports an overflow of the total of integer weights and `rand 0.10` also an
+//! infinite total of float weights.
+//!
+//! [`WeightedAliasIndex`] reproduces the alias method of `rand 0.7`, which
+//! moved to `rand_distr` afterwards. It builds the same table in all versions
+//! and differs through the uniform sampling only.
+
+use self::private::Sealed;
+use super::RandVersion;
+use super::WrappedRng;
+use super::uniform::SampleUniform;
+use super::uniform::Uniform;
+use core::cmp::Ordering;
+use core::fmt;
+
+/// Type usable as a weight.
+///
+/// It is implemented by the primitive integer and floating-point types. It is
+/// sealed, i.e. it cannot be implemented outside of this crate.
+pub trait Weight: SampleUniform + Sealed {}
+
+impl Weight for i8 {}
+impl Weight for i16 {}
+impl Weight for i32 {}
+impl Weight for i64 {}
+impl Weight for i128 {}
+impl Weight for isize {}
+impl Weight for u8 {}
+impl Weight for u16 {}
+impl Weight for u32 {}
+impl Weight for u64 {}
+impl Weight for u128 {}
+impl Weight for usize {}
+impl Weight for f32 {}
+impl Weight for f64 {}
+
+/// Error of the weighted sampling of this module.
+///
+/// The variants are those of `rand 0.9`. Older versions call them
+/// `NoItem`/`TooMany`, `InvalidWeight` and `AllWeightsZero`, and didn't
+/// detect overflows.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WeightError {
+    /// There were no weights, or too many for [`WeightedAliasIndex`].
+    InvalidInput,
+    /// A weight was negative, NaN or too large for [`WeightedAliasIndex`].
+    InvalidWeight,
+    /// All weights were zero.
+    InsufficientNonZero,
+    /// The sum of the weights overflowed.
+    Overflow,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            WeightError::InvalidInput => "weights sequence is empty or too long",
+            WeightError::InvalidWeight => "a weight is negative, too large or not a valid number",
+            WeightError::InsufficientNonZero => "not enough weights > zero",
+            WeightError::Overflow => "overflow when summing weights",
+        })
+    }
+}
+
+impl std::error::Error for WeightError {}
+
+/// Distribution of indices with probabilities proportional to given weights,
+/// like `WeightedIndex` of a given `rand` version.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedIndex<X: Weight> {
+    cumulative_weights: Vec<X>,
+    weight_distribution: Uniform<X>,
+}
+
+impl<X: Weight> WeightedIndex<X> {
+    /// Create a distribution of the indices of `weights`, like
+    /// `WeightedIndex::new` of the given `rand` version.
+    ///
+    /// Panics if the total weight is too large to sample uniformly, for
+    /// `rand 0.9` and earlier.
+    pub fn new<I: IntoIterator<Item = X>>(
+        version: RandVersion,
+        weights: I,
+    ) -> Result<WeightedIndex<X>, WeightError> {
+        let mut iter = weights.into_iter();
+        let mut total_weight = iter.next().ok_or(WeightError::InvalidInput)?;
+        if !valid(version, total_weight) {
+            return Err(WeightError::InvalidWeight);
+        }
+        let mut cumulative_weights = Vec::with_capacity(iter.size_hint().0);
+        for w in iter {
+            if !valid(version, w) {
+                return Err(WeightError::InvalidWeight);
+            }
+            cumulative_weights.push(total_weight);
+            total_weight = match version {
+                RandVersion::V0_7 | RandVersion::V0_8 => total_weight + w,
+                RandVersion::V0_9 | RandVersion::V0_10 => {
+                    total_weight.checked_add(w).ok_or(WeightError::Overflow)?
+                }
+            };
+        }
+        if total_weight == X::ZERO {
+            return Err(WeightError::InsufficientNonZero);
+        }
+        if version == RandVersion::V0_10 && !total_weight.finite() {
+            return Err(WeightError::Overflow);
+        }
+        Ok(WeightedIndex {
+            cumulative_weights,
+            weight_distribution: Uniform::new(version, X::ZERO, total_weight),
+        })
+    }
+
+    /// Sample an index, like `Distribution::sample` of `WeightedIndex`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> usize {
+        let chosen_weight = self.weight_distribution.sample(rng);
+        self.cumulative_weights
+            .partition_point(|&w| w <= chosen_weight)
+    }
+}
+
+/// Return whether `weight` is accepted by `WeightedIndex`.
+///
+/// `rand 0.7` only rejects negative weights, accepting NaN.
+fn valid<X: Weight>(version: RandVersion, weight: X) -> bool {
+    match version {
+        RandVersion::V0_7 => weight.partial_cmp(&X::ZERO) != Some(Ordering::Less),
+        RandVersion::V0_8 | RandVersion::V0_9 | RandVersion::V0_10 => weight >= X::ZERO,
+    }
+}
+
+/// Distribution of indices with probabilities proportional to given weights,
+/// using Walker's alias method, like `WeightedAliasIndex` of a given `rand`
+/// version.
+///
+/// This reproduces `distributions::weighted::alias_method::WeightedIndex` of
+/// `rand 0.7` and `WeightedAliasIndex` of `rand_distr 0.4`, `rand_distr 0.5`
+/// and `rand_distr 0.6`, for `rand 0.8`, `rand 0.9` and `rand 0.10`. Each
+/// sample takes two uniform samples, but creating it is more expensive than
+/// [`WeightedIndex`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedAliasIndex<W: Weight> {
+    aliases: Vec<u32>,
+    no_alias_odds: Vec<W>,
+    uniform_index: Uniform<u32>,
+    uniform_within_weight_sum: Uniform<W>,
+}
+
+impl<W: Weight> WeightedAliasIndex<W> {
+    /// Create a distribution of the indices of `weights`, like
+    /// `WeightedAliasIndex::new` of the given `rand` version.
+    ///
+    /// Each weight must be at most the maximum value of `W` divided by the
+    /// number of weights.
+    pub fn new(
+        version: RandVersion,
+        weights: Vec<W>,
+    ) -> Result<WeightedAliasIndex<W>, WeightError> {
+        let n = weights.len();
+        if n == 0 || n > u32::MAX as usize {
+            return Err(WeightError::InvalidInput);
+        }
+        let n = n as u32;
+        let max_weight_size = W::from_u32_lossy(n).map_or(W::ZERO, |n| W::MAX / n);
+        if !weights
+            .iter()
+            .all(|&w| W::ZERO <= w && w <= max_weight_size)
+        {
+            return Err(WeightError::InvalidWeight);
+        }
+        let weight_sum = W::sum(&weights);
+        let weight_sum = if weight_sum > W::MAX {
+            W::MAX
+        } else {
+            weight_sum
+        };
+        if weight_sum == W::ZERO {
+            return Err(WeightError::InsufficientNonZero);
+        }
+
+        // Scale the weights so that they average to `weight_sum`, then pair
+        // each index with a weight below the average with an index above.
+        let n_converted = W::from_u32_lossy(n).unwrap();
+        let mut no_alias_odds = weights;
+        for odds in no_alias_odds.iter_mut() {
+            *odds = *odds * n_converted;
+            if *odds > W::MAX {
+                *odds = W::MAX;
+            }
+        }
+        let mut aliases = Aliases::new(n);
+        for (index, &odds) in no_alias_odds.iter().enumerate() {
+            if odds < weight_sum {
+                aliases.push_small(index as u32);
+            } else {
+                aliases.push_big(index as u32);
+            }
+        }
+        while !aliases.smalls_is_empty() && !aliases.bigs_is_empty() {
+            let s = aliases.pop_small();
+            let b = aliases.pop_big();
+            aliases.aliases[s as usize] = b;
+            no_alias_odds[b as usize] =
+                no_alias_odds[b as usize] - weight_sum + no_alias_odds[s as usize];
+            if no_alias_odds[b as usize] < weight_sum {
+                aliases.push_small(b);
+            } else {
+                aliases.push_big(b);
+            }
+        }
+        while !aliases.smalls_is_empty() {
+            no_alias_odds[aliases.pop_small() as usize] = weight_sum;
+        }
+        while !aliases.bigs_is_empty() {
+            no_alias_odds[aliases.pop_big() as usize] = weight_sum;
+        }
+
+        Ok(WeightedAliasIndex {
+            aliases: aliases.aliases,
+            no_alias_odds,
+            uniform_index: Uniform::new(version, 0, n),
+            uniform_within_weight_sum: Uniform::new(version, W::ZERO, weight_sum),
+        })
+    }
+
+    /// Sample an index, like `Distribution::sample` of `WeightedAliasIndex`.
+    pub fn sample<R: WrappedRng + ?Sized>(&self, rng: &mut R) -> usize {
+        let candidate = self.uniform_index.sample(rng) as usize;
+        if self.uniform_within_weight_sum.sample(rng) < self.no_alias_odds[candidate] {
+            candidate
+        } else {
+            self.aliases[candidate] as usize
+        }
+    }
+}
+
+/// Aliases of the indices, also storing two linked lists of the indices below
+/// and above the average weight while the table is built.
+struct Aliases {
+    aliases: Vec<u32>,
+    smalls_head: u32,
+    bigs_head: u32,
+}
+
+impl Aliases {
+    fn new(size: u32) -> Aliases {
+        Aliases {
+            aliases: vec![0; size as usize],
+            smalls_head: u32::MAX,
+            bigs_head: u32::MAX,
+        }
+    }
+
+    fn push_small(&mut self, idx: u32) {
+        self.aliases[idx as usize] = self.smalls_head;
+        self.smalls_head = idx;
+    }
+
+    fn push_big(&mut self, idx: u32) {
+        self.aliases[idx as usize] = self.bigs_head;
+        self.bigs_head = idx;
+    }
+
+    fn pop_small(&mut self) -> u32 {
+        let popped = self.smalls_head;
+        self.smalls_head = self.aliases[popped as usize];
+        popped
+    }
+
+    fn pop_big(&mut self) -> u32 {
+        let popped = self.bigs_head;
+        self.bigs_head = self.aliases[popped as usize];
+        popped
+    }
+
+    fn smalls_is_empty(&self) -> bool {
+        self.smalls_head == u32::MAX
+    }
+
+    fn bigs_is_empty(&self) -> bool {
+        self.bigs_head == u32::MAX
+    }
+}
+
+mod private {
+    use core::ops::Add;
+    use core::ops::Div;
+    use core::ops::Mul;
+    use core::ops::Sub;
+
+    /// Arithmetic of a weight type.
+    pub trait Sealed:
+        Copy
+        + PartialOrd
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+    {
+        const ZERO: Self;
+        const MAX: Self;
+
+        /// Add `other`, or return `None` on integer overflow.
+        fn checked_add(self, other: Self) -> Option<Self>;
+        /// Return whether the value is finite, i.e. `true` for integers.
+        fn finite(self) -> bool;
+        /// Convert `n`, or return `None` if it doesn't fit.
+        fn from_u32_lossy(n: u32) -> Option<Self>;
+        /// Sum the weights like the alias method, pairwise for floats.
+        fn sum(values: &[Self]) -> Self;
+    }
+
+    impl Sealed for i8 {
+        const ZERO: i8 = 0;
+        const MAX: i8 = i8::MAX;
+
+        fn checked_add(self, other: i8) -> Option<i8> {
+            i8::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<i8> {
+            let n_converted = n as i8;
+            if n_converted >= 0 && n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[i8]) -> i8 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for i16 {
+        const ZERO: i16 = 0;
+        const MAX: i16 = i16::MAX;
+
+        fn checked_add(self, other: i16) -> Option<i16> {
+            i16::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<i16> {
+            let n_converted = n as i16;
+            if n_converted >= 0 && n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[i16]) -> i16 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for i32 {
+        const ZERO: i32 = 0;
+        const MAX: i32 = i32::MAX;
+
+        fn checked_add(self, other: i32) -> Option<i32> {
+            i32::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<i32> {
+            let n_converted = n as i32;
+            if n_converted >= 0 && n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[i32]) -> i32 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for i64 {
+        const ZERO: i64 = 0;
+        const MAX: i64 = i64::MAX;
+
+        fn checked_add(self, other: i64) -> Option<i64> {
+            i64::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<i64> {
+            Some(n.into())
+        }
+        fn sum(values: &[i64]) -> i64 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for i128 {
+        const ZERO: i128 = 0;
+        const MAX: i128 = i128::MAX;
+
+        fn checked_add(self, other: i128) -> Option<i128> {
+            i128::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<i128> {
+            Some(n.into())
+        }
+        fn sum(values: &[i128]) -> i128 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for isize {
+        const ZERO: isize = 0;
+        const MAX: isize = isize::MAX;
+
+        fn checked_add(self, other: isize) -> Option<isize> {
+            isize::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<isize> {
+            let n_converted = n as isize;
+            if n_converted >= 0 && n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[isize]) -> isize {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for u8 {
+        const ZERO: u8 = 0;
+        const MAX: u8 = u8::MAX;
+
+        fn checked_add(self, other: u8) -> Option<u8> {
+            u8::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<u8> {
+            let n_converted = n as u8;
+            if n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[u8]) -> u8 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for u16 {
+        const ZERO: u16 = 0;
+        const MAX: u16 = u16::MAX;
+
+        fn checked_add(self, other: u16) -> Option<u16> {
+            u16::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<u16> {
+            let n_converted = n as u16;
+            if n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[u16]) -> u16 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for u32 {
+        const ZERO: u32 = 0;
+        const MAX: u32 = u32::MAX;
+
+        fn checked_add(self, other: u32) -> Option<u32> {
+            u32::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<u32> {
+            Some(n)
+        }
+        fn sum(values: &[u32]) -> u32 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for u64 {
+        const ZERO: u64 = 0;
+        const MAX: u64 = u64::MAX;
+
+        fn checked_add(self, other: u64) -> Option<u64> {
+            u64::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<u64> {
+            Some(n.into())
+        }
+        fn sum(values: &[u64]) -> u64 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for u128 {
+        const ZERO: u128 = 0;
+        const MAX: u128 = u128::MAX;
+
+        fn checked_add(self, other: u128) -> Option<u128> {
+            u128::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<u128> {
+            Some(n.into())
+        }
+        fn sum(values: &[u128]) -> u128 {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for usize {
+        const ZERO: usize = 0;
+        const MAX: usize = usize::MAX;
+
+        fn checked_add(self, other: usize) -> Option<usize> {
+            usize::checked_add(self, other)
+        }
+        fn finite(self) -> bool {
+            true
+        }
+        fn from_u32_lossy(n: u32) -> Option<usize> {
+            let n_converted = n as usize;
+            if n_converted as u32 == n {
+                Some(n_converted)
+            } else {
+                None
+            }
+        }
+        fn sum(values: &[usize]) -> usize {
+            values.iter().copied().sum()
+        }
+    }
+
+    impl Sealed for f32 {
+        const ZERO: f32 = 0.0;
+        const MAX: f32 = f32::MAX;
+
+        fn checked_add(self, other: f32) -> Option<f32> {
+            Some(self + other)
+        }
+        fn finite(self) -> bool {
+            self.is_finite()
+        }
+        fn from_u32_lossy(n: u32) -> Option<f32> {
+            Some(n as f32)
+        }
+        fn sum(values: &[f32]) -> f32 {
+            if values.len() <= 32 {
+                values.iter().copied().sum()
+            } else {
+                let (a, b) = values.split_at(values.len() / 2);
+                f32::sum(a) + f32::sum(b)
+            }
+        }
+    }
+
+    impl Sealed for f64 {
+        const ZERO: f64 = 0.0;
+        const MAX: f64 = f64::MAX;
+
+        fn checked_add(self, other: f64) -> Option<f64> {
+            Some(self + other)
+        }
+        fn finite(self) -> bool {
+            self.is_finite()
+        }
+        fn from_u32_lossy(n: u32) -> Option<f64> {
+            Some(n as f64)
+        }
+        fn sum(values: &[f64]) -> f64 {
+            if values.len() <= 32 {
+                values.iter().copied().sum()
+            } else {
+                let (a, b) = values.split_at(values.len() / 2);
+                f64::sum(a) + f64::sum(b)
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use core::fmt;
+
+/// `XorShiftRng` of `rand 0.5` and `rand_xorshift 0.1`: Marsaglia's 128-bit
+/// Xorshift RNG.
+///
+/// It reproduces the output of the historical implementation bit for bit,
+/// given the same seed and the same sequence of calls. It implements the RNG
+/// and seedable RNG traits of every selected `rand_core`/`rand` version.
+///
+/// Its seed is the state, as 4 little-endian words. Like the historical
+/// implementation, it replaces an all-zero seed by a fixed one, as Xorshift
+/// would only output zeros otherwise.
+#[derive(Clone, Eq, PartialEq)]
+pub struct XorShiftRng {
+    x: u32,
+    y: u32,
+    z: u32,
+    w: u32,
+}
+
+impl XorShiftRng {
+    fn from_seed(seed: [u8; 16]) -> XorShiftRng {
+        let mut state = [0; 4];
+        for (s, chunk) in state.iter_mut().zip(seed.chunks_exact(4)) {
+            *s = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        if state == [0; 4] {
+            state = [0xBAD_5EED; 4];
+        }
+        XorShiftRng {
+            x: state[0],
+            y: state[1],
+            z: state[2],
+            w: state[3],
+        }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let t = self.x ^ (self.x << 11);
+        self.x = self.y;
+        self.y = self.z;
+        self.z = self.w;
+        self.w = self.w ^ (self.w >> 19) ^ (t ^ (t >> 8));
+        self.w
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32();
+        let hi = self.next_u32();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    /// Fill `dst` like `rand_core::impls::fill_bytes_via_next` of
+    /// `rand_core 0.4`.
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let rest = chunks.into_remainder();
+        if rest.len() > 4 {
+            rest.copy_from_slice(&self.next_u64().to_le_bytes()[..rest.len()]);
+        } else if !rest.is_empty() {
+            rest.copy_from_slice(&self.next_u32().to_le_bytes()[..rest.len()]);
+        }
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for XorShiftRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XorShiftRng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::XorShiftRng;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for XorShiftRng {
+        fn next_u32(&mut self) -> u32 {
+            XorShiftRng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            XorShiftRng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            XorShiftRng::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            XorShiftRng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for XorShiftRng {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> XorShiftRng {
+            XorShiftRng::from_seed(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::XorShiftRng;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for XorShiftRng {
+        fn next_u32(&mut self) -> u32 {
+            XorShiftRng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            XorShiftRng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            XorShiftRng::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            XorShiftRng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for XorShiftRng {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> XorShiftRng {
+            XorShiftRng::from_seed(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::XorShiftRng;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for XorShiftRng {
+        fn next_u32(&mut self) -> u32 {
+            XorShiftRng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            XorShiftRng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            XorShiftRng::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for XorShiftRng {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> XorShiftRng {
+            XorShiftRng::from_seed(seed)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::XorShiftRng;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for XorShiftRng {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(XorShiftRng::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(XorShiftRng::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            XorShiftRng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for XorShiftRng {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> XorShiftRng {
+            XorShiftRng::from_seed(seed)
+        }
+    }
+}
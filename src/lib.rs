@@ -19,24 +19,99 @@ pub extern crate rand_core_0_9;
 #[cfg(feature = "rand_core_0_10")]
 pub extern crate rand_core_0_10;
 
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicSplitMix64;
+#[cfg(any(
+    feature = "rand_chacha_0_2",
+    feature = "rand_chacha_0_3",
+    feature = "rand_chacha_0_9",
+    feature = "rand_chacha_0_10",
+))]
+pub use chacha::ChaChaState;
+pub use combine::Combine;
 pub use compat::*;
+#[cfg(feature = "conditioning")]
+pub use conditioned::Conditioned;
+#[cfg(feature = "derivation")]
+pub use derivation::DerivationKey;
+#[cfg(feature = "rand_0_7")]
+pub use distribution::Dist07;
+#[cfg(feature = "rand_0_8")]
+pub use distribution::Dist08;
+#[cfg(feature = "rand_0_9")]
+pub use distribution::Dist09;
+#[cfg(feature = "rand_0_10")]
+pub use distribution::Dist010;
+#[cfg(all(feature = "std", target_os = "linux"))]
+pub use fork_safe::ForkSafe;
+#[cfg(feature = "fortuna")]
+pub use fortuna::Fortuna;
+pub use health::HealthTested;
+#[cfg(any(
+    feature = "rand_pcg_0_2",
+    feature = "rand_pcg_0_3",
+    feature = "rand_pcg_0_9",
+    feature = "rand_pcg_0_10",
+))]
+pub use pcg::Pcg32State;
+#[cfg(any(
+    feature = "rand_pcg_0_2",
+    feature = "rand_pcg_0_3",
+    feature = "rand_pcg_0_9",
+    feature = "rand_pcg_0_10",
+))]
+pub use pcg::Pcg64McgState;
+#[cfg(any(
+    feature = "rand_pcg_0_2",
+    feature = "rand_pcg_0_3",
+    feature = "rand_pcg_0_9",
+    feature = "rand_pcg_0_10",
+))]
+pub use pcg::Pcg64State;
+pub use redacted::Redacted;
+pub use reseeding::Reseeding;
+#[cfg(feature = "std")]
+pub use shared::Shared;
+#[cfg(feature = "critical-section")]
+pub use shared::SharedStatic;
+pub use small_rng::SmallRng07;
+pub use small_rng::SmallRng08;
+pub use small_rng::SmallRng09;
+pub use small_rng::SmallRng010;
+pub use std_rng::StdRng07;
+pub use std_rng::StdRng08;
+pub use std_rng::StdRng09;
+pub use std_rng::StdRng010;
+#[cfg(feature = "thread_rng")]
+pub use thread_rng::ThreadRng;
+#[cfg(feature = "thread_rng")]
+pub use thread_rng::thread_rng;
+#[cfg(feature = "serde")]
+pub use versioned::Version;
+#[cfg(feature = "serde")]
+pub use versioned::Versioned;
+#[cfg(any(
+    feature = "rand_xoshiro_0_4",
+    feature = "rand_xoshiro_0_6",
+    feature = "rand_xoshiro_0_7",
+    feature = "rand_xoshiro_0_8",
+))]
+pub use xoshiro::Xoshiro128State;
+#[cfg(any(
+    feature = "rand_xoshiro_0_4",
+    feature = "rand_xoshiro_0_6",
+    feature = "rand_xoshiro_0_7",
+    feature = "rand_xoshiro_0_8",
+))]
+pub use xoshiro::Xoshiro256State;
 
-/// Custom error codes used when compiled without `std` support.
-#[cfg(any(feature = "rand_core_0_5", feature = "rand_core_0_6"))]
-pub mod error {
-    use core::num::NonZeroU32;
-
-    /// Error code when we couldn't extract an error code from the original error.
-    pub const UNKNOWN: NonZeroU32 = match NonZeroU32::new(3222222222) {
-        Some(n) => n,
-        None => unreachable!(),
-    };
-    /// Error code when the original error code was 0.
-    pub const OS_ERROR_0: NonZeroU32 = match NonZeroU32::new(3222222223) {
-        Some(n) => n,
-        None => unreachable!(),
-    };
-}
+#[cfg(feature = "drbg")]
+pub mod drbg;
+pub mod error;
+#[cfg(feature = "critical-section")]
+pub mod global;
+#[cfg(feature = "legacy_algorithms")]
+pub mod legacy;
 
 #[cfg(not(any(
     feature = "rand_core_0_5",
@@ -49,4 +124,64 @@ compile_error!(concat!(
     "`rand_core_0_5`, `rand_core_0_6`, `rand_core_0_9`, `rand_core_0_10`",
 ));
 
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+#[cfg(any(
+    feature = "rand_pcg_0_2",
+    feature = "rand_pcg_0_3",
+    feature = "rand_pcg_0_9",
+    feature = "rand_xoshiro_0_4",
+    feature = "rand_xoshiro_0_6",
+    feature = "rand_xoshiro_0_7",
+    feature = "rand_xoshiro_0_8",
+))]
+mod capture;
+#[cfg(any(
+    feature = "rand_chacha_0_2",
+    feature = "rand_chacha_0_3",
+    feature = "rand_chacha_0_9",
+    feature = "rand_chacha_0_10",
+))]
+mod chacha;
+mod combine;
 mod compat;
+#[cfg(feature = "conditioning")]
+mod conditioned;
+#[cfg(feature = "derivation")]
+mod derivation;
+#[cfg(any(
+    feature = "rand_0_7",
+    feature = "rand_0_8",
+    feature = "rand_0_9",
+    feature = "rand_0_10",
+))]
+mod distribution;
+#[cfg(all(feature = "std", target_os = "linux"))]
+mod fork_safe;
+#[cfg(feature = "fortuna")]
+mod fortuna;
+mod health;
+#[cfg(any(
+    feature = "rand_pcg_0_2",
+    feature = "rand_pcg_0_3",
+    feature = "rand_pcg_0_9",
+    feature = "rand_pcg_0_10",
+))]
+mod pcg;
+mod redacted;
+mod reseeding;
+#[cfg(any(feature = "std", feature = "critical-section"))]
+mod shared;
+mod small_rng;
+mod std_rng;
+#[cfg(feature = "thread_rng")]
+mod thread_rng;
+#[cfg(feature = "serde")]
+mod versioned;
+#[cfg(any(
+    feature = "rand_xoshiro_0_4",
+    feature = "rand_xoshiro_0_6",
+    feature = "rand_xoshiro_0_7",
+    feature = "rand_xoshiro_0_8",
+))]
+mod xoshiro;
@@ -0,0 +1,478 @@
+/// Multiplier of the LCG of `Lcg64Xsh32`.
+const MULTIPLIER_64: u64 = 6364136223846793005;
+
+/// Multiplier of the LCGs of `Lcg128Xsl64` and `Mcg128Xsl64`.
+const MULTIPLIER_128: u128 = 0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645;
+
+/// Advance an LCG by `delta` steps in O(log `delta`) time, using Brown's
+/// "Random Number Generation with Arbitrary Strides" like `rand_pcg`.
+fn advance_64(state: u64, multiplier: u64, increment: u64, mut delta: u64) -> u64 {
+    let mut acc_mult: u64 = 1;
+    let mut acc_plus: u64 = 0;
+    let mut cur_mult = multiplier;
+    let mut cur_plus = increment;
+    while delta > 0 {
+        if delta & 1 != 0 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    acc_mult.wrapping_mul(state).wrapping_add(acc_plus)
+}
+
+/// Advance an LCG by `delta` steps in O(log `delta`) time, like
+/// [`advance_64`].
+fn advance_128(state: u128, multiplier: u128, increment: u128, mut delta: u128) -> u128 {
+    let mut acc_mult: u128 = 1;
+    let mut acc_plus: u128 = 0;
+    let mut cur_mult = multiplier;
+    let mut cur_plus = increment;
+    while delta > 0 {
+        if delta & 1 != 0 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    acc_mult.wrapping_mul(state).wrapping_add(acc_plus)
+}
+
+/// State of a `Pcg32` (`Lcg64Xsh32`) RNG of `rand_pcg`, independent of its
+/// version.
+///
+/// It consists of the LCG state and the stream, which determine all further
+/// output. It can be extracted from the `Lcg64Xsh32` of every selected
+/// `rand_pcg` version and converted back into it, so that a checkpoint taken
+/// with one version can be resumed with another one at the same position, e.g.
+/// from the RNG wrapped by a [`Rng05`](crate::Rng05) into a `rand_pcg 0.9`
+/// RNG. Before 0.10, `rand_pcg` only exposes the state through `serde`, which
+/// the features of these versions enable.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pcg32State {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32State {
+    /// Create the state from the LCG state and the stream.
+    ///
+    /// Like `from_state` of `rand_pcg 0.10` and unlike `new` of `rand_pcg`, it
+    /// uses the LCG state as is. The highest bit of the stream is discarded.
+    pub fn new(state: u64, stream: u64) -> Pcg32State {
+        Pcg32State {
+            state,
+            increment: (stream << 1) | 1,
+        }
+    }
+
+    /// Return the LCG state.
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// Return the stream.
+    pub fn stream(&self) -> u64 {
+        self.increment >> 1
+    }
+
+    /// Skip `delta` outputs of `next_u32`, independently of the `rand_pcg`
+    /// version. `rand_pcg 0.2` has no `advance`.
+    pub fn advance(&mut self, delta: u64) {
+        self.state = advance_64(self.state, MULTIPLIER_64, self.increment, delta);
+    }
+
+    /// Return the arguments of `rand_pcg`'s `new` resulting in this state.
+    #[cfg(any(
+        feature = "rand_pcg_0_2",
+        feature = "rand_pcg_0_3",
+        feature = "rand_pcg_0_9",
+    ))]
+    fn new_args(&self) -> (u64, u64) {
+        // `new` adds the increment and steps the LCG once, so undo both. The
+        // LCG has a period of 2^64.
+        let previous = advance_64(self.state, MULTIPLIER_64, self.increment, u64::MAX);
+        (previous.wrapping_sub(self.increment), self.stream())
+    }
+}
+
+/// Serialize the state as the tuple of the LCG state and the stream.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pcg32State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.state, self.stream()), serializer)
+    }
+}
+
+/// Deserialize the state from the tuple of the LCG state and the stream.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pcg32State {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Pcg32State, D::Error> {
+        let (state, stream): (u64, u64) = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Pcg32State::new(state, stream))
+    }
+}
+
+/// State of a `Pcg64` (`Lcg128Xsl64`) RNG of `rand_pcg`, independent of its
+/// version.
+///
+/// It consists of the LCG state and the stream, which determine all further
+/// output. It can be extracted from the `Lcg128Xsl64` of every selected
+/// `rand_pcg` version and converted back into it, like [`Pcg32State`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pcg64State {
+    state: u128,
+    increment: u128,
+}
+
+impl Pcg64State {
+    /// Create the state from the LCG state and the stream.
+    ///
+    /// Like `from_state` of `rand_pcg 0.10` and unlike `new` of `rand_pcg`, it
+    /// uses the LCG state as is. The highest bit of the stream is discarded.
+    pub fn new(state: u128, stream: u128) -> Pcg64State {
+        Pcg64State {
+            state,
+            increment: (stream << 1) | 1,
+        }
+    }
+
+    /// Return the LCG state.
+    pub fn state(&self) -> u128 {
+        self.state
+    }
+
+    /// Return the stream.
+    pub fn stream(&self) -> u128 {
+        self.increment >> 1
+    }
+
+    /// Skip `delta` outputs of `next_u64`, independently of the `rand_pcg`
+    /// version. `rand_pcg 0.2` has no `advance`.
+    pub fn advance(&mut self, delta: u128) {
+        self.state = advance_128(self.state, MULTIPLIER_128, self.increment, delta);
+    }
+
+    /// Return the arguments of `rand_pcg`'s `new` resulting in this state.
+    #[cfg(any(
+        feature = "rand_pcg_0_2",
+        feature = "rand_pcg_0_3",
+        feature = "rand_pcg_0_9",
+    ))]
+    fn new_args(&self) -> (u128, u128) {
+        // `new` adds the increment and steps the LCG once, so undo both. The
+        // LCG has a period of 2^128.
+        let previous = advance_128(self.state, MULTIPLIER_128, self.increment, u128::MAX);
+        (previous.wrapping_sub(self.increment), self.stream())
+    }
+}
+
+/// Serialize the state as the tuple of the LCG state and the stream.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pcg64State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.state, self.stream()), serializer)
+    }
+}
+
+/// Deserialize the state from the tuple of the LCG state and the stream.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pcg64State {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Pcg64State, D::Error> {
+        let (state, stream): (u128, u128) = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Pcg64State::new(state, stream))
+    }
+}
+
+/// State of a `Pcg64Mcg` (`Mcg128Xsl64`) RNG of `rand_pcg`, independent of its
+/// version.
+///
+/// It consists of the MCG state, which determines all further output. It can
+/// be extracted from the `Mcg128Xsl64` of every selected `rand_pcg` version
+/// and converted back into it, like [`Pcg32State`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pcg64McgState {
+    state: u128,
+}
+
+impl Pcg64McgState {
+    /// Create the state from the MCG state.
+    ///
+    /// Like `new` of `rand_pcg`, it sets the lowest bit, as the state of the
+    /// MCG is always odd.
+    pub fn new(state: u128) -> Pcg64McgState {
+        Pcg64McgState { state: state | 1 }
+    }
+
+    /// Return the MCG state.
+    pub fn state(&self) -> u128 {
+        self.state
+    }
+
+    /// Skip `delta` outputs of `next_u64`, independently of the `rand_pcg`
+    /// version. `rand_pcg 0.2` has no `advance`.
+    pub fn advance(&mut self, delta: u128) {
+        self.state = advance_128(self.state, MULTIPLIER_128, 0, delta);
+    }
+}
+
+/// Serialize the state as the tuple of the MCG state.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pcg64McgState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.state,), serializer)
+    }
+}
+
+/// Deserialize the state from the tuple of the MCG state.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pcg64McgState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Pcg64McgState, D::Error> {
+        let (state,): (u128,) = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Pcg64McgState::new(state))
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_2")]
+mod v0_2 {
+    use super::Pcg32State;
+    use super::Pcg64McgState;
+    use super::Pcg64State;
+    use crate::capture;
+    use rand_pcg_0_2::Lcg64Xsh32;
+    use rand_pcg_0_2::Lcg128Xsl64;
+    use rand_pcg_0_2::Mcg128Xsl64;
+
+    /// Extract the state of a `rand_pcg 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<&Lcg64Xsh32> for Pcg32State {
+        fn from(rng: &Lcg64Xsh32) -> Pcg32State {
+            let [state, increment] = capture::words(rng);
+            Pcg32State {
+                state: state as u64,
+                increment: increment as u64,
+            }
+        }
+    }
+
+    /// Restore a `rand_pcg 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Pcg32State> for Lcg64Xsh32 {
+        fn from(state: Pcg32State) -> Lcg64Xsh32 {
+            let (state, stream) = state.new_args();
+            Lcg64Xsh32::new(state, stream)
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<&Lcg128Xsl64> for Pcg64State {
+        fn from(rng: &Lcg128Xsl64) -> Pcg64State {
+            let [state, increment] = capture::words(rng);
+            Pcg64State { state, increment }
+        }
+    }
+
+    /// Restore a `rand_pcg 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Pcg64State> for Lcg128Xsl64 {
+        fn from(state: Pcg64State) -> Lcg128Xsl64 {
+            let (state, stream) = state.new_args();
+            Lcg128Xsl64::new(state, stream)
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<&Mcg128Xsl64> for Pcg64McgState {
+        fn from(rng: &Mcg128Xsl64) -> Pcg64McgState {
+            let [state] = capture::words(rng);
+            Pcg64McgState::new(state)
+        }
+    }
+
+    /// Restore a `rand_pcg 0.2` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Pcg64McgState> for Mcg128Xsl64 {
+        fn from(state: Pcg64McgState) -> Mcg128Xsl64 {
+            Mcg128Xsl64::new(state.state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_3")]
+mod v0_3 {
+    use super::Pcg32State;
+    use super::Pcg64McgState;
+    use super::Pcg64State;
+    use crate::capture;
+    use rand_pcg_0_3::Lcg64Xsh32;
+    use rand_pcg_0_3::Lcg128Xsl64;
+    use rand_pcg_0_3::Mcg128Xsl64;
+
+    /// Extract the state of a `rand_pcg 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<&Lcg64Xsh32> for Pcg32State {
+        fn from(rng: &Lcg64Xsh32) -> Pcg32State {
+            let [state, increment] = capture::words(rng);
+            Pcg32State {
+                state: state as u64,
+                increment: increment as u64,
+            }
+        }
+    }
+
+    /// Restore a `rand_pcg 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Pcg32State> for Lcg64Xsh32 {
+        fn from(state: Pcg32State) -> Lcg64Xsh32 {
+            let (state, stream) = state.new_args();
+            Lcg64Xsh32::new(state, stream)
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<&Lcg128Xsl64> for Pcg64State {
+        fn from(rng: &Lcg128Xsl64) -> Pcg64State {
+            let [state, increment] = capture::words(rng);
+            Pcg64State { state, increment }
+        }
+    }
+
+    /// Restore a `rand_pcg 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Pcg64State> for Lcg128Xsl64 {
+        fn from(state: Pcg64State) -> Lcg128Xsl64 {
+            let (state, stream) = state.new_args();
+            Lcg128Xsl64::new(state, stream)
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<&Mcg128Xsl64> for Pcg64McgState {
+        fn from(rng: &Mcg128Xsl64) -> Pcg64McgState {
+            let [state] = capture::words(rng);
+            Pcg64McgState::new(state)
+        }
+    }
+
+    /// Restore a `rand_pcg 0.3` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Pcg64McgState> for Mcg128Xsl64 {
+        fn from(state: Pcg64McgState) -> Mcg128Xsl64 {
+            Mcg128Xsl64::new(state.state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_9")]
+mod v0_9 {
+    use super::Pcg32State;
+    use super::Pcg64McgState;
+    use super::Pcg64State;
+    use crate::capture;
+    use rand_pcg_0_9::Lcg64Xsh32;
+    use rand_pcg_0_9::Lcg128Xsl64;
+    use rand_pcg_0_9::Mcg128Xsl64;
+
+    /// Extract the state of a `rand_pcg 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<&Lcg64Xsh32> for Pcg32State {
+        fn from(rng: &Lcg64Xsh32) -> Pcg32State {
+            let [state, increment] = capture::words(rng);
+            Pcg32State {
+                state: state as u64,
+                increment: increment as u64,
+            }
+        }
+    }
+
+    /// Restore a `rand_pcg 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Pcg32State> for Lcg64Xsh32 {
+        fn from(state: Pcg32State) -> Lcg64Xsh32 {
+            let (state, stream) = state.new_args();
+            Lcg64Xsh32::new(state, stream)
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<&Lcg128Xsl64> for Pcg64State {
+        fn from(rng: &Lcg128Xsl64) -> Pcg64State {
+            let [state, increment] = capture::words(rng);
+            Pcg64State { state, increment }
+        }
+    }
+
+    /// Restore a `rand_pcg 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Pcg64State> for Lcg128Xsl64 {
+        fn from(state: Pcg64State) -> Lcg128Xsl64 {
+            let (state, stream) = state.new_args();
+            Lcg128Xsl64::new(state, stream)
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<&Mcg128Xsl64> for Pcg64McgState {
+        fn from(rng: &Mcg128Xsl64) -> Pcg64McgState {
+            let [state] = capture::words(rng);
+            Pcg64McgState::new(state)
+        }
+    }
+
+    /// Restore a `rand_pcg 0.9` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Pcg64McgState> for Mcg128Xsl64 {
+        fn from(state: Pcg64McgState) -> Mcg128Xsl64 {
+            Mcg128Xsl64::new(state.state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_10")]
+mod v0_10 {
+    use super::Pcg32State;
+    use super::Pcg64McgState;
+    use super::Pcg64State;
+    use rand_pcg_0_10::Lcg64Xsh32;
+    use rand_pcg_0_10::Lcg128Xsl64;
+    use rand_pcg_0_10::Mcg128Xsl64;
+
+    /// Extract the state of a `rand_pcg 0.10` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Lcg64Xsh32> for Pcg32State {
+        fn from(rng: &Lcg64Xsh32) -> Pcg32State {
+            Pcg32State::new(rng.state(), rng.stream())
+        }
+    }
+
+    /// Restore a `rand_pcg 0.10` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Pcg32State> for Lcg64Xsh32 {
+        fn from(state: Pcg32State) -> Lcg64Xsh32 {
+            Lcg64Xsh32::from_state(state.state, state.stream())
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.10` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Lcg128Xsl64> for Pcg64State {
+        fn from(rng: &Lcg128Xsl64) -> Pcg64State {
+            Pcg64State::new(rng.state(), rng.stream())
+        }
+    }
+
+    /// Restore a `rand_pcg 0.10` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Pcg64State> for Lcg128Xsl64 {
+        fn from(state: Pcg64State) -> Lcg128Xsl64 {
+            Lcg128Xsl64::from_state(state.state, state.stream())
+        }
+    }
+
+    /// Extract the state of a `rand_pcg 0.10` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Mcg128Xsl64> for Pcg64McgState {
+        fn from(rng: &Mcg128Xsl64) -> Pcg64McgState {
+            Pcg64McgState::new(rng.state())
+        }
+    }
+
+    /// Restore a `rand_pcg 0.10` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Pcg64McgState> for Mcg128Xsl64 {
+        fn from(state: Pcg64McgState) -> Mcg128Xsl64 {
+            Mcg128Xsl64::new(state.state)
+        }
+    }
+}
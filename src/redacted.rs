@@ -0,0 +1,132 @@
+use core::fmt;
+
+/// Adapter hiding the state of an RNG in `Debug` output.
+///
+/// The wrapper structs derive `Debug`, which prints the internal state of the
+/// wrapped RNG, e.g. the key of a ChaCha RNG, into logs. Wrap crypto RNGs in
+/// this adapter, e.g. `Redacted(Rng06(rng))`, to print `Redacted { .. }`
+/// instead.
+///
+/// It implements the RNG and crypto RNG traits of every selected
+/// `rand_core`/`rand` version that `T` implements. With the `zeroize` feature,
+/// it implements `Zeroize` and `ZeroizeOnDrop` if `T` does.
+#[derive(Clone)]
+pub struct Redacted<T>(pub T);
+
+/// Doesn't print the internal state.
+impl<T> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redacted").finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the wrapped RNG.
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for Redacted<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The wrapped RNG is zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Redacted<T> {}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Redacted;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for Redacted<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.try_fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng
+        for Redacted<T>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Redacted;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for Redacted<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.try_fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng
+        for Redacted<T>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Redacted;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    ///
+    /// This requires the wrapped RNG to be infallible.
+    impl<T: rand_core_0_9::RngCore> rand_core_0_9::RngCore for Redacted<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl<T: rand_core_0_9::CryptoRng> rand_core_0_9::CryptoRng for Redacted<T> {}
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Redacted;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for Redacted<T> {
+        type Error = T::Error;
+        fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+            self.0.try_next_u32()
+        }
+        fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+            self.0.try_next_u64()
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+            self.0.try_fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    impl<T: rand_core_0_10::TryCryptoRng> rand_core_0_10::TryCryptoRng for Redacted<T> {}
+}
@@ -0,0 +1,273 @@
+/// Adapter reseeding an RNG using another RNG.
+///
+/// Once `threshold` bytes have been generated since the last reseed, or after
+/// calling [`Reseeding::schedule_reseed`], the RNG `G` is replaced by a new
+/// one, seeded from the reseeder `R`, before generating further output.
+///
+/// It implements the RNG and crypto RNG traits of every selected
+/// `rand_core`/`rand` version for which `G` implements the RNG and seedable
+/// RNG traits and `R` implements the RNG trait. To combine RNGs of different
+/// versions, wrap them in the wrapper structs of this crate, e.g.
+/// `Reseeding::new(Rng06(ChaCha20Rng::from_entropy()), 1 << 20, TryRng09(OsRng))`.
+/// Errors of the reseeder are returned, not ignored.
+#[derive(Clone, Debug)]
+pub struct Reseeding<G, R> {
+    rng: G,
+    reseeder: R,
+    threshold: u64,
+    bytes_until_reseed: u64,
+}
+
+impl<G, R> Reseeding<G, R> {
+    /// Reseed `rng` from `reseeder` every `threshold` bytes.
+    ///
+    /// If `threshold` is 0, `rng` is only reseeded on demand.
+    pub fn new(rng: G, threshold: u64, reseeder: R) -> Reseeding<G, R> {
+        Reseeding {
+            rng,
+            reseeder,
+            threshold,
+            bytes_until_reseed: Reseeding::<G, R>::initial_bytes_until_reseed(threshold),
+        }
+    }
+
+    fn initial_bytes_until_reseed(threshold: u64) -> u64 {
+        if threshold == 0 { u64::MAX } else { threshold }
+    }
+
+    /// Reseed the RNG before generating further output.
+    pub fn schedule_reseed(&mut self) {
+        self.bytes_until_reseed = 0;
+    }
+
+    /// Reference the RNG and the reseeder.
+    pub fn inner(&self) -> (&G, &R) {
+        (&self.rng, &self.reseeder)
+    }
+
+    /// Unwrap the RNG and the reseeder.
+    pub fn into_inner(self) -> (G, R) {
+        (self.rng, self.reseeder)
+    }
+
+    /// Reseed using `reseed` if due, then generate `len` bytes of output using
+    /// `generate`.
+    fn try_generate<V, E>(
+        &mut self,
+        len: usize,
+        reseed: impl FnOnce(&mut R) -> Result<G, E>,
+        generate: impl FnOnce(&mut G) -> Result<V, E>,
+    ) -> Result<V, E> {
+        if self.bytes_until_reseed == 0 {
+            self.rng = reseed(&mut self.reseeder)?;
+            self.bytes_until_reseed = Reseeding::<G, R>::initial_bytes_until_reseed(self.threshold);
+        }
+        self.bytes_until_reseed = self.bytes_until_reseed.saturating_sub(len as u64);
+        generate(&mut self.rng)
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Reseeding;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on reseed errors if `next_u32`, `next_u64` or `fill_bytes` is
+    /// called.
+    impl<G, R> rand_core_0_5::RngCore for Reseeding<G, R>
+    where
+        G: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng,
+        R: rand_core_0_5::RngCore,
+    {
+        fn next_u32(&mut self) -> u32 {
+            self.try_generate(
+                4,
+                |reseeder| G::from_rng(reseeder),
+                |rng| Ok(rng.next_u32()),
+            )
+            .unwrap()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.try_generate(
+                8,
+                |reseeder| G::from_rng(reseeder),
+                |rng| Ok(rng.next_u64()),
+            )
+            .unwrap()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.try_generate(
+                dst.len(),
+                |reseeder| G::from_rng(reseeder),
+                |rng| rng.try_fill_bytes(dst),
+            )
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl<G, R> rand_core_0_5::CryptoRng for Reseeding<G, R>
+    where
+        G: rand_core_0_5::RngCore + rand_core_0_5::SeedableRng + rand_core_0_5::CryptoRng,
+        R: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Reseeding;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    ///
+    /// Since the older trait cannot express fallible RNGs perfectly, it'll
+    /// panic on reseed errors if `next_u32`, `next_u64` or `fill_bytes` is
+    /// called.
+    impl<G, R> rand_core_0_6::RngCore for Reseeding<G, R>
+    where
+        G: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng,
+        R: rand_core_0_6::RngCore,
+    {
+        fn next_u32(&mut self) -> u32 {
+            self.try_generate(
+                4,
+                |reseeder| G::from_rng(reseeder),
+                |rng| Ok(rng.next_u32()),
+            )
+            .unwrap()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.try_generate(
+                8,
+                |reseeder| G::from_rng(reseeder),
+                |rng| Ok(rng.next_u64()),
+            )
+            .unwrap()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.try_fill_bytes(dst).unwrap();
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.try_generate(
+                dst.len(),
+                |reseeder| G::from_rng(reseeder),
+                |rng| rng.try_fill_bytes(dst),
+            )
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl<G, R> rand_core_0_6::CryptoRng for Reseeding<G, R>
+    where
+        G: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng + rand_core_0_6::CryptoRng,
+        R: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Reseeding;
+    use crate::Error09;
+    use crate::error::Error;
+    use core::fmt;
+
+    fn reseed<G, R>(reseeder: &mut R) -> Result<G, Error>
+    where
+        G: rand_core_0_9::SeedableRng,
+        R: rand_core_0_9::TryRngCore,
+        R::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        Ok(G::try_from_rng(reseeder).map_err(Error09)?)
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible RNG trait.
+    ///
+    /// Errors of the RNG and the reseeder are converted to
+    /// [`crate::error::Error`] via [`Error09`].
+    impl<G, R> rand_core_0_9::TryRngCore for Reseeding<G, R>
+    where
+        G: rand_core_0_9::TryRngCore + rand_core_0_9::SeedableRng,
+        G::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+        R: rand_core_0_9::TryRngCore,
+        R::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            self.try_generate(4, reseed, |rng| Ok(rng.try_next_u32().map_err(Error09)?))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            self.try_generate(8, reseed, |rng| Ok(rng.try_next_u64().map_err(Error09)?))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_generate(dst.len(), reseed, |rng| {
+                Ok(rng.try_fill_bytes(dst).map_err(Error09)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` fallible crypto RNG trait.
+    impl<G, R> rand_core_0_9::TryCryptoRng for Reseeding<G, R>
+    where
+        G: rand_core_0_9::TryCryptoRng + rand_core_0_9::SeedableRng,
+        G::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+        R: rand_core_0_9::TryCryptoRng,
+        R::Error: fmt::Debug + fmt::Display + Send + Sync + 'static,
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Reseeding;
+    use crate::Error010;
+    use crate::error::Error;
+
+    fn reseed<G, R>(reseeder: &mut R) -> Result<G, Error>
+    where
+        G: rand_core_0_10::SeedableRng,
+        R: rand_core_0_10::TryRng,
+        R::Error: Send + Sync + 'static,
+    {
+        Ok(G::try_from_rng(reseeder).map_err(Error010)?)
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    ///
+    /// Errors of the RNG and the reseeder are converted to
+    /// [`crate::error::Error`] via [`Error010`].
+    impl<G, R> rand_core_0_10::TryRng for Reseeding<G, R>
+    where
+        G: rand_core_0_10::TryRng + rand_core_0_10::SeedableRng,
+        G::Error: Send + Sync + 'static,
+        R: rand_core_0_10::TryRng,
+        R::Error: Send + Sync + 'static,
+    {
+        type Error = Error;
+        fn try_next_u32(&mut self) -> Result<u32, Error> {
+            self.try_generate(4, reseed, |rng| Ok(rng.try_next_u32().map_err(Error010)?))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Error> {
+            self.try_generate(8, reseed, |rng| Ok(rng.try_next_u64().map_err(Error010)?))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+            self.try_generate(dst.len(), reseed, |rng| {
+                Ok(rng.try_fill_bytes(dst).map_err(Error010)?)
+            })
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    impl<G, R> rand_core_0_10::TryCryptoRng for Reseeding<G, R>
+    where
+        G: rand_core_0_10::TryCryptoRng + rand_core_0_10::SeedableRng,
+        G::Error: Send + Sync + 'static,
+        R: rand_core_0_10::TryCryptoRng,
+        R::Error: Send + Sync + 'static,
+    {
+    }
+}
@@ -0,0 +1,397 @@
+#[cfg(feature = "critical-section")]
+use core::cell::RefCell;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
+use std::sync::PoisonError;
+
+/// Cloneable handle to an RNG shared between threads.
+///
+/// [`Shared::new`] puts the RNG in an `Arc<Mutex<_>>`. For `no_std` targets,
+/// use [`SharedStatic`] with the `critical-section` feature instead.
+///
+/// Both `Shared<T>` and `&Shared<T>` implement the RNG and crypto RNG traits
+/// of every selected `rand_core`/`rand` version that `T` implements, locking
+/// the RNG for every call. As with the wrapper structs, wrap `T` in them to
+/// get the traits of the other versions, e.g. `Shared::new(Rng06(rng))`.
+///
+/// The `rand_core 0.9`/`rand 0.9` RNG trait is only implemented for
+/// infallible RNGs, as the fallible RNG trait of that version is implemented
+/// for all of them. Fallible `rand_core 0.9` RNGs wrapped in [`crate::TryRng09`]
+/// can be used through the traits of the other selected versions.
+#[cfg(feature = "std")]
+pub struct Shared<T> {
+    rng: Arc<Mutex<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> Shared<T> {
+    /// Share `rng` between threads.
+    pub fn new(rng: T) -> Shared<T> {
+        Shared {
+            rng: Arc::new(Mutex::new(rng)),
+        }
+    }
+
+    /// Run `f` with the locked RNG.
+    ///
+    /// If another thread panicked while holding the lock, the RNG is used
+    /// regardless.
+    fn with<V>(&self, f: impl FnOnce(&mut T) -> V) -> V {
+        f(&mut self.rng.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Shared<T> {
+        Shared {
+            rng: self.rng.clone(),
+        }
+    }
+}
+
+/// Doesn't print the RNG, to avoid locking it.
+#[cfg(feature = "std")]
+impl<T> fmt::Debug for Shared<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shared").finish_non_exhaustive()
+    }
+}
+
+/// Static storage for an RNG shared between threads, guarded by a critical
+/// section.
+///
+/// The RNG is used inside a critical section, which may e.g. disable
+/// interrupts while it generates output. Declare it as a `static` and call
+/// [`SharedStatic::init`] to store the RNG.
+///
+/// `&SharedStatic<T>` implements the same traits as [`Shared`], and is a
+/// handle that can be copied freely. Using it before `init` has been called
+/// panics.
+#[cfg(feature = "critical-section")]
+pub struct SharedStatic<T>(critical_section::Mutex<RefCell<Option<T>>>);
+
+#[cfg(feature = "critical-section")]
+impl<T> SharedStatic<T> {
+    /// Create an empty storage.
+    pub const fn new() -> SharedStatic<T> {
+        SharedStatic(critical_section::Mutex::new(RefCell::new(None)))
+    }
+
+    /// Store `rng`, replacing any previously stored RNG, and return a handle
+    /// to it.
+    pub fn init(&'static self, rng: T) -> &'static SharedStatic<T> {
+        critical_section::with(|cs| *self.0.borrow_ref_mut(cs) = Some(rng));
+        self
+    }
+
+    /// Run `f` with the RNG inside a critical section.
+    fn with<V>(&self, f: impl FnOnce(&mut T) -> V) -> V {
+        critical_section::with(|cs| {
+            let mut rng = self.0.borrow_ref_mut(cs);
+            f(rng
+                .as_mut()
+                .expect("`SharedStatic` must be initialized before use"))
+        })
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> Default for SharedStatic<T> {
+    fn default() -> SharedStatic<T> {
+        SharedStatic::new()
+    }
+}
+
+/// Doesn't print the RNG, to avoid locking it.
+#[cfg(feature = "critical-section")]
+impl<T> fmt::Debug for SharedStatic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedStatic").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    #[cfg(feature = "std")]
+    use super::Shared;
+    #[cfg(feature = "critical-section")]
+    use super::SharedStatic;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for &Shared<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng for &Shared<T> {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for Shared<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng for Shared<T> {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_5::RngCore> rand_core_0_5::RngCore for &SharedStatic<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_5::RngCore + rand_core_0_5::CryptoRng> rand_core_0_5::CryptoRng
+        for &SharedStatic<T>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    #[cfg(feature = "std")]
+    use super::Shared;
+    #[cfg(feature = "critical-section")]
+    use super::SharedStatic;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for &Shared<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng for &Shared<T> {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for Shared<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng for Shared<T> {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_6::RngCore> rand_core_0_6::RngCore for &SharedStatic<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_6::RngCore + rand_core_0_6::CryptoRng> rand_core_0_6::CryptoRng
+        for &SharedStatic<T>
+    {
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    #[cfg(feature = "std")]
+    use super::Shared;
+    #[cfg(feature = "critical-section")]
+    use super::SharedStatic;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    ///
+    /// This requires the shared RNG to be infallible.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_9::RngCore> rand_core_0_9::RngCore for &Shared<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_9::CryptoRng> rand_core_0_9::CryptoRng for &Shared<T> {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    ///
+    /// This requires the shared RNG to be infallible.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_9::RngCore> rand_core_0_9::RngCore for Shared<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_9::CryptoRng> rand_core_0_9::CryptoRng for Shared<T> {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    ///
+    /// This requires the shared RNG to be infallible.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_9::RngCore> rand_core_0_9::RngCore for &SharedStatic<T> {
+        fn next_u32(&mut self) -> u32 {
+            self.with(|rng| rng.next_u32())
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.with(|rng| rng.next_u64())
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.with(|rng| rng.fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_9::CryptoRng> rand_core_0_9::CryptoRng for &SharedStatic<T> {}
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    #[cfg(feature = "std")]
+    use super::Shared;
+    #[cfg(feature = "critical-section")]
+    use super::SharedStatic;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for &Shared<T> {
+        type Error = T::Error;
+        fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+            self.with(|rng| rng.try_next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+            self.with(|rng| rng.try_next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_10::TryCryptoRng> rand_core_0_10::TryCryptoRng for &Shared<T> {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for Shared<T> {
+        type Error = T::Error;
+        fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+            self.with(|rng| rng.try_next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+            self.with(|rng| rng.try_next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    #[cfg(feature = "std")]
+    impl<T: rand_core_0_10::TryCryptoRng> rand_core_0_10::TryCryptoRng for Shared<T> {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_10::TryRng> rand_core_0_10::TryRng for &SharedStatic<T> {
+        type Error = T::Error;
+        fn try_next_u32(&mut self) -> Result<u32, T::Error> {
+            self.with(|rng| rng.try_next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, T::Error> {
+            self.with(|rng| rng.try_next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), T::Error> {
+            self.with(|rng| rng.try_fill_bytes(dst))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` fallible crypto RNG trait.
+    #[cfg(feature = "critical-section")]
+    impl<T: rand_core_0_10::TryCryptoRng> rand_core_0_10::TryCryptoRng for &SharedStatic<T> {}
+}
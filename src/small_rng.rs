@@ -0,0 +1,909 @@
+use core::fmt;
+
+/// Increment of the SplitMix64 state.
+const PHI: u64 = 0x9e3779b97f4a7c15;
+
+/// Algorithm behind a `SmallRng` type.
+trait Algorithm {
+    fn next_u32(&mut self) -> u32;
+    fn next_u64(&mut self) -> u64;
+}
+
+/// Fill `dst` like `rand_core::impls::fill_bytes_via_next` of
+/// `rand_core 0.5` to `0.9`.
+fn fill_bytes_via_next(rng: &mut impl Algorithm, dst: &mut [u8]) {
+    let mut chunks = dst.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        chunk.copy_from_slice(&rng.next_u64().to_le_bytes());
+    }
+    let rest = chunks.into_remainder();
+    if rest.len() > 4 {
+        rest.copy_from_slice(&rng.next_u64().to_le_bytes()[..rest.len()]);
+    } else if !rest.is_empty() {
+        rest.copy_from_slice(&rng.next_u32().to_le_bytes()[..rest.len()]);
+    }
+}
+
+/// Fill `dst` using `next_u64` only, discarding the unused bytes of the last
+/// output, like `rand_pcg`'s `Pcg64Mcg` and `rand 0.10`'s
+/// `Xoshiro256PlusPlus`.
+#[cfg(target_pointer_width = "64")]
+fn fill_bytes_via_u64(rng: &mut impl Algorithm, dst: &mut [u8]) {
+    for chunk in dst.chunks_mut(8) {
+        chunk.copy_from_slice(&rng.next_u64().to_le_bytes()[..chunk.len()]);
+    }
+}
+
+/// Fill a seed like the default `SeedableRng::seed_from_u64` of every
+/// `rand_core` version, using PCG32.
+fn pcg32_seed<const N: usize>(mut state: u64) -> [u8; N] {
+    let mut seed = [0; N];
+    for chunk in seed.chunks_mut(4) {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(11634580027462260723);
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rot = (state >> 59) as u32;
+        chunk.copy_from_slice(&xorshifted.rotate_right(rot).to_le_bytes()[..chunk.len()]);
+    }
+    seed
+}
+
+/// Output the SplitMix64 sequence starting at `state`.
+fn splitmix64(mut state: u64) -> impl FnMut() -> u64 {
+    move || {
+        state = state.wrapping_add(PHI);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// `Pcg64Mcg` of `rand_pcg 0.2`.
+#[cfg(all(not(target_os = "emscripten"), target_pointer_width = "64"))]
+#[derive(Clone, Eq, PartialEq)]
+struct Pcg64Mcg {
+    state: u128,
+}
+
+#[cfg(all(not(target_os = "emscripten"), target_pointer_width = "64"))]
+impl Pcg64Mcg {
+    fn from_seed(seed: [u8; 16]) -> Pcg64Mcg {
+        Pcg64Mcg {
+            state: u128::from_le_bytes(seed) | 1,
+        }
+    }
+}
+
+#[cfg(all(not(target_os = "emscripten"), target_pointer_width = "64"))]
+impl Algorithm for Pcg64Mcg {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645);
+        let rot = (self.state >> 122) as u32;
+        (((self.state >> 64) as u64) ^ (self.state as u64)).rotate_right(rot)
+    }
+}
+
+/// `Pcg32` of `rand_pcg 0.2`.
+#[cfg(not(all(not(target_os = "emscripten"), target_pointer_width = "64")))]
+#[derive(Clone, Eq, PartialEq)]
+struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+#[cfg(not(all(not(target_os = "emscripten"), target_pointer_width = "64")))]
+impl Pcg32 {
+    fn from_seed(seed: [u8; 16]) -> Pcg32 {
+        let (state, increment) = seed.split_at(8);
+        let mut pcg = Pcg32 {
+            state: u64::from_le_bytes(state.try_into().unwrap()),
+            increment: u64::from_le_bytes(increment.try_into().unwrap()) | 1,
+        };
+        pcg.state = pcg.state.wrapping_add(pcg.increment);
+        pcg.step();
+        pcg
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(self.increment);
+    }
+}
+
+#[cfg(not(all(not(target_os = "emscripten"), target_pointer_width = "64")))]
+impl Algorithm for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+        self.step();
+        let rot = (state >> 59) as u32;
+        ((((state >> 18) ^ state) >> 27) as u32).rotate_right(rot)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32();
+        let hi = self.next_u32();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+}
+
+/// `Xoshiro256PlusPlus` of `rand 0.8` to `rand 0.10`.
+#[cfg(target_pointer_width = "64")]
+#[derive(Clone, Eq, PartialEq)]
+struct Xoshiro256PlusPlus {
+    s: [u64; 4],
+}
+
+#[cfg(target_pointer_width = "64")]
+impl Xoshiro256PlusPlus {
+    /// Create the RNG from `seed`, replacing an all-zero state using
+    /// [`Xoshiro256PlusPlus::seed_from_u64`].
+    fn from_seed(seed: [u8; 32]) -> Xoshiro256PlusPlus {
+        let mut s = [0; 4];
+        for (s, chunk) in s.iter_mut().zip(seed.chunks_exact(8)) {
+            *s = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        if s == [0; 4] {
+            return Xoshiro256PlusPlus::seed_from_u64(0);
+        }
+        Xoshiro256PlusPlus { s }
+    }
+
+    fn seed_from_u64(state: u64) -> Xoshiro256PlusPlus {
+        let mut next = splitmix64(state);
+        Xoshiro256PlusPlus {
+            s: core::array::from_fn(|_| next()),
+        }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl Algorithm for Xoshiro256PlusPlus {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+/// `Xoshiro128PlusPlus` of `rand 0.8` to `rand 0.10`.
+#[cfg(not(target_pointer_width = "64"))]
+#[derive(Clone, Eq, PartialEq)]
+struct Xoshiro128PlusPlus {
+    s: [u32; 4],
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+impl Xoshiro128PlusPlus {
+    /// Create the RNG from `seed`, replacing an all-zero state using
+    /// [`Xoshiro128PlusPlus::seed_from_u64`].
+    fn from_seed(seed: [u8; 16]) -> Xoshiro128PlusPlus {
+        let mut s = [0; 4];
+        for (s, chunk) in s.iter_mut().zip(seed.chunks_exact(4)) {
+            *s = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        if s == [0; 4] {
+            return Xoshiro128PlusPlus::seed_from_u64(0);
+        }
+        Xoshiro128PlusPlus { s }
+    }
+
+    fn seed_from_u64(state: u64) -> Xoshiro128PlusPlus {
+        let mut next = splitmix64(state);
+        let (a, b) = (next(), next());
+        Xoshiro128PlusPlus {
+            s: [a as u32, (a >> 32) as u32, b as u32, (b >> 32) as u32],
+        }
+    }
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+impl Algorithm for Xoshiro128PlusPlus {
+    fn next_u32(&mut self) -> u32 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(7).wrapping_add(s[0]);
+        let t = s[1] << 9;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(11);
+        result
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32();
+        let hi = self.next_u32();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+}
+
+/// Algorithm of [`SmallRng07`].
+#[cfg(all(not(target_os = "emscripten"), target_pointer_width = "64"))]
+type Algorithm07 = Pcg64Mcg;
+/// Algorithm of [`SmallRng07`].
+#[cfg(not(all(not(target_os = "emscripten"), target_pointer_width = "64")))]
+type Algorithm07 = Pcg32;
+
+/// Algorithm of [`SmallRng08`], [`SmallRng09`] and [`SmallRng010`].
+#[cfg(target_pointer_width = "64")]
+type Xoshiro = Xoshiro256PlusPlus;
+/// Algorithm of [`SmallRng08`], [`SmallRng09`] and [`SmallRng010`].
+#[cfg(not(target_pointer_width = "64"))]
+type Xoshiro = Xoshiro128PlusPlus;
+
+/// Seed of [`SmallRng08`], depending on the pointer width.
+#[cfg(target_pointer_width = "64")]
+type Seed08 = [u8; 32];
+/// Seed of [`SmallRng08`], depending on the pointer width.
+#[cfg(not(target_pointer_width = "64"))]
+type Seed08 = [u8; 16];
+
+/// `SmallRng` of `rand 0.7`.
+///
+/// Like `rand 0.7`'s `SmallRng`, it uses `Pcg64Mcg` on 64-bit targets other
+/// than Emscripten, and `Pcg32` otherwise, both as implemented by
+/// `rand_pcg 0.2`. The seed is 16 bytes long.
+///
+/// It reproduces the output of `rand 0.7`'s `SmallRng` on the same target
+/// bit for bit, given the same seed and the same sequence of calls. It
+/// implements the RNG and seedable RNG traits of every selected
+/// `rand_core`/`rand` version. It is not a crypto RNG.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SmallRng07(Algorithm07);
+
+/// `SmallRng` of `rand 0.8`.
+///
+/// Like `rand 0.8`'s `SmallRng`, it uses `Xoshiro256PlusPlus` with a 32-byte
+/// seed on 64-bit targets, and `Xoshiro128PlusPlus` with a 16-byte seed
+/// otherwise.
+///
+/// It reproduces the output of `rand 0.8`'s `SmallRng` on the same target
+/// bit for bit, given the same seed and the same sequence of calls. It
+/// implements the RNG and seedable RNG traits of every selected
+/// `rand_core`/`rand` version. It is not a crypto RNG.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SmallRng08(Xoshiro);
+
+/// `SmallRng` of `rand 0.9`.
+///
+/// Like `rand 0.9`'s `SmallRng`, it uses `Xoshiro256PlusPlus` on 64-bit
+/// targets, and `Xoshiro128PlusPlus` otherwise, which only uses the first 16
+/// bytes of the 32-byte seed.
+///
+/// It reproduces the output of `rand 0.9`'s `SmallRng` on the same target
+/// bit for bit, given the same seed and the same sequence of calls. It
+/// implements the RNG and seedable RNG traits of every selected
+/// `rand_core`/`rand` version. It is not a crypto RNG.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SmallRng09(Xoshiro);
+
+/// `SmallRng` of `rand 0.10`.
+///
+/// Like `rand 0.10`'s `SmallRng`, it uses `Xoshiro256PlusPlus` on 64-bit
+/// targets, and `Xoshiro128PlusPlus` otherwise, which only uses the first 16
+/// bytes of the 32-byte seed. It differs from [`SmallRng09`] only in how
+/// `fill_bytes` uses the last output on 64-bit targets.
+///
+/// It reproduces the output of `rand 0.10`'s `SmallRng` on the same target
+/// bit for bit, given the same seed and the same sequence of calls. It
+/// implements the RNG and seedable RNG traits of every selected
+/// `rand_core`/`rand` version. It is not a crypto RNG.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SmallRng010(Xoshiro);
+
+impl SmallRng07 {
+    fn from_seed(seed: [u8; 16]) -> SmallRng07 {
+        SmallRng07(Algorithm07::from_seed(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> SmallRng07 {
+        SmallRng07::from_seed(pcg32_seed(state))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        #[cfg(all(not(target_os = "emscripten"), target_pointer_width = "64"))]
+        fill_bytes_via_u64(&mut self.0, dst);
+        #[cfg(not(all(not(target_os = "emscripten"), target_pointer_width = "64")))]
+        fill_bytes_via_next(&mut self.0, dst);
+    }
+}
+
+impl SmallRng08 {
+    fn from_seed(seed: Seed08) -> SmallRng08 {
+        SmallRng08(Xoshiro::from_seed(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> SmallRng08 {
+        SmallRng08::from_seed(pcg32_seed(state))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        fill_bytes_via_next(&mut self.0, dst);
+    }
+}
+
+/// Create the algorithm of [`SmallRng09`] and [`SmallRng010`] from the
+/// beginning of `seed`.
+fn xoshiro_from_seed(seed: [u8; 32]) -> Xoshiro {
+    let mut truncated = Default::default();
+    let len = core::mem::size_of_val(&truncated);
+    AsMut::<[u8]>::as_mut(&mut truncated).copy_from_slice(&seed[..len]);
+    Xoshiro::from_seed(truncated)
+}
+
+impl SmallRng09 {
+    fn from_seed(seed: [u8; 32]) -> SmallRng09 {
+        SmallRng09(xoshiro_from_seed(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> SmallRng09 {
+        SmallRng09(Xoshiro::seed_from_u64(state))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        fill_bytes_via_next(&mut self.0, dst);
+    }
+}
+
+impl SmallRng010 {
+    fn from_seed(seed: [u8; 32]) -> SmallRng010 {
+        SmallRng010(xoshiro_from_seed(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> SmallRng010 {
+        SmallRng010(Xoshiro::seed_from_u64(state))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    /// Fill `dst` using `next_u64` on 64-bit targets and `next_u32`
+    /// otherwise. The latter matches `rand_core 0.9`'s `fill_bytes_via_next`
+    /// for `Xoshiro128PlusPlus`.
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        #[cfg(target_pointer_width = "64")]
+        fill_bytes_via_u64(&mut self.0, dst);
+        #[cfg(not(target_pointer_width = "64"))]
+        fill_bytes_via_next(&mut self.0, dst);
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for SmallRng07 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRng07").finish_non_exhaustive()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for SmallRng08 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRng08").finish_non_exhaustive()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for SmallRng09 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRng09").finish_non_exhaustive()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for SmallRng010 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRng010").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::Seed08;
+    use super::SmallRng07;
+    use super::SmallRng08;
+    use super::SmallRng09;
+    use super::SmallRng010;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for SmallRng07 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng07::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng07::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng07::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            SmallRng07::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for SmallRng07 {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> SmallRng07 {
+            SmallRng07::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng07 {
+            SmallRng07::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for SmallRng08 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng08::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng08::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng08::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            SmallRng08::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for SmallRng08 {
+        type Seed = Seed08;
+        fn from_seed(seed: Seed08) -> SmallRng08 {
+            SmallRng08::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng08 {
+            SmallRng08::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for SmallRng09 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng09::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng09::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng09::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            SmallRng09::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for SmallRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng09 {
+            SmallRng09::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng09 {
+            SmallRng09::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for SmallRng010 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng010::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng010::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng010::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            SmallRng010::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for SmallRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng010 {
+            SmallRng010::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng010 {
+            SmallRng010::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::Seed08;
+    use super::SmallRng07;
+    use super::SmallRng08;
+    use super::SmallRng09;
+    use super::SmallRng010;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for SmallRng07 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng07::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng07::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng07::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            SmallRng07::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for SmallRng07 {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> SmallRng07 {
+            SmallRng07::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng07 {
+            SmallRng07::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for SmallRng08 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng08::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng08::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng08::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            SmallRng08::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for SmallRng08 {
+        type Seed = Seed08;
+        fn from_seed(seed: Seed08) -> SmallRng08 {
+            SmallRng08::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng08 {
+            SmallRng08::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for SmallRng09 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng09::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng09::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng09::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            SmallRng09::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for SmallRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng09 {
+            SmallRng09::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng09 {
+            SmallRng09::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for SmallRng010 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng010::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng010::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng010::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            SmallRng010::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for SmallRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng010 {
+            SmallRng010::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng010 {
+            SmallRng010::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::Seed08;
+    use super::SmallRng07;
+    use super::SmallRng08;
+    use super::SmallRng09;
+    use super::SmallRng010;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for SmallRng07 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng07::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng07::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng07::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for SmallRng07 {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> SmallRng07 {
+            SmallRng07::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng07 {
+            SmallRng07::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for SmallRng08 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng08::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng08::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng08::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for SmallRng08 {
+        type Seed = Seed08;
+        fn from_seed(seed: Seed08) -> SmallRng08 {
+            SmallRng08::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng08 {
+            SmallRng08::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for SmallRng09 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng09::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng09::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng09::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for SmallRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng09 {
+            SmallRng09::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng09 {
+            SmallRng09::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for SmallRng010 {
+        fn next_u32(&mut self) -> u32 {
+            SmallRng010::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            SmallRng010::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            SmallRng010::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for SmallRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng010 {
+            SmallRng010::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng010 {
+            SmallRng010::seed_from_u64(state)
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::Seed08;
+    use super::SmallRng07;
+    use super::SmallRng08;
+    use super::SmallRng09;
+    use super::SmallRng010;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for SmallRng07 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(SmallRng07::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(SmallRng07::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            SmallRng07::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for SmallRng07 {
+        type Seed = [u8; 16];
+        fn from_seed(seed: [u8; 16]) -> SmallRng07 {
+            SmallRng07::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng07 {
+            SmallRng07::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for SmallRng08 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(SmallRng08::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(SmallRng08::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            SmallRng08::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for SmallRng08 {
+        type Seed = Seed08;
+        fn from_seed(seed: Seed08) -> SmallRng08 {
+            SmallRng08::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng08 {
+            SmallRng08::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for SmallRng09 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(SmallRng09::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(SmallRng09::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            SmallRng09::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for SmallRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng09 {
+            SmallRng09::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng09 {
+            SmallRng09::seed_from_u64(state)
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for SmallRng010 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(SmallRng010::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(SmallRng010::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            SmallRng010::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for SmallRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> SmallRng010 {
+            SmallRng010::from_seed(seed)
+        }
+        fn seed_from_u64(state: u64) -> SmallRng010 {
+            SmallRng010::seed_from_u64(state)
+        }
+    }
+}
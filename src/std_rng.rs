@@ -0,0 +1,682 @@
+use core::fmt;
+
+/// Number of 32-bit words in a ChaCha block.
+const BLOCK_WORDS: usize = 16;
+/// Number of blocks generated at once, as in `rand_chacha` and `chacha20`.
+const BUF_BLOCKS: usize = 4;
+
+/// ChaCha RNG with `DOUBLE_ROUNDS` double rounds, generating the stream of
+/// `rand_chacha`'s `ChaChaXRng` types.
+///
+/// The buffering matches `rand_core`'s `BlockRng`, which behaves the same in
+/// every version: `next_u64` combines two consecutive words, even across
+/// blocks, and `fill_bytes` discards the unused bytes of its last word.
+#[derive(Clone, Eq, PartialEq)]
+struct ChaCha<const DOUBLE_ROUNDS: usize> {
+    key: [u32; 8],
+    counter: u64,
+    results: [u32; BLOCK_WORDS * BUF_BLOCKS],
+    index: usize,
+}
+
+fn quarter_round(x: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+impl<const DOUBLE_ROUNDS: usize> ChaCha<DOUBLE_ROUNDS> {
+    fn from_seed(seed: [u8; 32]) -> ChaCha<DOUBLE_ROUNDS> {
+        let mut key = [0; 8];
+        for (k, chunk) in key.iter_mut().zip(seed.chunks_exact(4)) {
+            *k = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        ChaCha {
+            key,
+            counter: 0,
+            results: [0; BLOCK_WORDS * BUF_BLOCKS],
+            index: BLOCK_WORDS * BUF_BLOCKS,
+        }
+    }
+
+    /// Refill the buffer with the next blocks, using a 64-bit block counter
+    /// and stream 0.
+    fn generate(&mut self) {
+        for block in self.results.chunks_exact_mut(BLOCK_WORDS) {
+            let mut input = [0; BLOCK_WORDS];
+            input[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+            input[4..12].copy_from_slice(&self.key);
+            input[12] = self.counter as u32;
+            input[13] = (self.counter >> 32) as u32;
+            let mut x = input;
+            for _ in 0..DOUBLE_ROUNDS {
+                quarter_round(&mut x, 0, 4, 8, 12);
+                quarter_round(&mut x, 1, 5, 9, 13);
+                quarter_round(&mut x, 2, 6, 10, 14);
+                quarter_round(&mut x, 3, 7, 11, 15);
+                quarter_round(&mut x, 0, 5, 10, 15);
+                quarter_round(&mut x, 1, 6, 11, 12);
+                quarter_round(&mut x, 2, 7, 8, 13);
+                quarter_round(&mut x, 3, 4, 9, 14);
+            }
+            for ((out, x), input) in block.iter_mut().zip(x.iter()).zip(input.iter()) {
+                *out = x.wrapping_add(*input);
+            }
+            self.counter = self.counter.wrapping_add(1);
+        }
+        self.index = 0;
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= self.results.len() {
+            self.generate();
+        }
+        let value = self.results[self.index];
+        self.index += 1;
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32();
+        let hi = self.next_u32();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(4) {
+            chunk.copy_from_slice(&self.next_u32().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+/// Zeroize the key and the buffered output.
+#[cfg(feature = "zeroize")]
+impl<const DOUBLE_ROUNDS: usize> zeroize::Zeroize for ChaCha<DOUBLE_ROUNDS> {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.counter.zeroize();
+        self.results.zeroize();
+        self.index = self.results.len();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const DOUBLE_ROUNDS: usize> Drop for ChaCha<DOUBLE_ROUNDS> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+/// `StdRng` of `rand 0.7`: ChaCha20, as implemented by `rand_chacha 0.2`.
+///
+/// It reproduces the output of `rand 0.7`'s `StdRng` bit for bit, given the
+/// same seed and the same sequence of calls. It implements the RNG, crypto
+/// RNG and seedable RNG traits of every selected `rand_core`/`rand` version.
+#[derive(Clone, Eq, PartialEq)]
+pub struct StdRng07(ChaCha<10>);
+
+/// `StdRng` of `rand 0.8`: ChaCha12, as implemented by `rand_chacha 0.3`.
+///
+/// It reproduces the output of `rand 0.8`'s `StdRng` bit for bit, given the
+/// same seed and the same sequence of calls. It implements the RNG, crypto
+/// RNG and seedable RNG traits of every selected `rand_core`/`rand` version.
+#[derive(Clone, Eq, PartialEq)]
+pub struct StdRng08(ChaCha<6>);
+
+/// `StdRng` of `rand 0.9`: ChaCha12, as implemented by `rand_chacha 0.9`.
+///
+/// It reproduces the output of `rand 0.9`'s `StdRng` bit for bit, given the
+/// same seed and the same sequence of calls. It implements the RNG, crypto
+/// RNG and seedable RNG traits of every selected `rand_core`/`rand` version.
+#[derive(Clone, Eq, PartialEq)]
+pub struct StdRng09(ChaCha<6>);
+
+/// `StdRng` of `rand 0.10`: ChaCha12, as implemented by `chacha20 0.10`.
+///
+/// It reproduces the output of `rand 0.10`'s `StdRng` bit for bit, given the
+/// same seed and the same sequence of calls. It implements the RNG, crypto
+/// RNG and seedable RNG traits of every selected `rand_core`/`rand` version.
+#[derive(Clone, Eq, PartialEq)]
+pub struct StdRng010(ChaCha<6>);
+
+/// Doesn't print the internal state.
+impl fmt::Debug for StdRng07 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdRng07").finish_non_exhaustive()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for StdRng08 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdRng08").finish_non_exhaustive()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for StdRng09 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdRng09").finish_non_exhaustive()
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for StdRng010 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdRng010").finish_non_exhaustive()
+    }
+}
+
+/// Zeroize the key and the buffered output.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for StdRng07 {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The key and the buffered output are zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for StdRng07 {}
+
+/// Zeroize the key and the buffered output.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for StdRng08 {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The key and the buffered output are zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for StdRng08 {}
+
+/// Zeroize the key and the buffered output.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for StdRng09 {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The key and the buffered output are zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for StdRng09 {}
+
+/// Zeroize the key and the buffered output.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for StdRng010 {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The key and the buffered output are zeroized on drop.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for StdRng010 {}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::ChaCha;
+    use super::StdRng07;
+    use super::StdRng08;
+    use super::StdRng09;
+    use super::StdRng010;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for StdRng07 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for StdRng07 {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for StdRng07 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng07 {
+            StdRng07(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for StdRng08 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for StdRng08 {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for StdRng08 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng08 {
+            StdRng08(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for StdRng09 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for StdRng09 {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for StdRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng09 {
+            StdRng09(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for StdRng010 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for StdRng010 {}
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` seedable RNG trait.
+    impl rand_core_0_5::SeedableRng for StdRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng010 {
+            StdRng010(ChaCha::from_seed(seed))
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::ChaCha;
+    use super::StdRng07;
+    use super::StdRng08;
+    use super::StdRng09;
+    use super::StdRng010;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for StdRng07 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for StdRng07 {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for StdRng07 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng07 {
+            StdRng07(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for StdRng08 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for StdRng08 {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for StdRng08 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng08 {
+            StdRng08(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for StdRng09 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for StdRng09 {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for StdRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng09 {
+            StdRng09(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for StdRng010 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for StdRng010 {}
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` seedable RNG trait.
+    impl rand_core_0_6::SeedableRng for StdRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng010 {
+            StdRng010(ChaCha::from_seed(seed))
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::ChaCha;
+    use super::StdRng07;
+    use super::StdRng08;
+    use super::StdRng09;
+    use super::StdRng010;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for StdRng07 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl rand_core_0_9::CryptoRng for StdRng07 {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for StdRng07 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng07 {
+            StdRng07(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for StdRng08 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl rand_core_0_9::CryptoRng for StdRng08 {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for StdRng08 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng08 {
+            StdRng08(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for StdRng09 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl rand_core_0_9::CryptoRng for StdRng09 {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for StdRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng09 {
+            StdRng09(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for StdRng010 {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl rand_core_0_9::CryptoRng for StdRng010 {}
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` seedable RNG trait.
+    impl rand_core_0_9::SeedableRng for StdRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng010 {
+            StdRng010(ChaCha::from_seed(seed))
+        }
+    }
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::ChaCha;
+    use super::StdRng07;
+    use super::StdRng08;
+    use super::StdRng09;
+    use super::StdRng010;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for StdRng07 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(self.0.next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(self.0.next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for StdRng07 {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for StdRng07 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng07 {
+            StdRng07(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for StdRng08 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(self.0.next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(self.0.next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for StdRng08 {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for StdRng08 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng08 {
+            StdRng08(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for StdRng09 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(self.0.next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(self.0.next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for StdRng09 {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for StdRng09 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng09 {
+            StdRng09(ChaCha::from_seed(seed))
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for StdRng010 {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(self.0.next_u32())
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(self.0.next_u64())
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            self.0.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for StdRng010 {}
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` seedable RNG trait.
+    impl rand_core_0_10::SeedableRng for StdRng010 {
+        type Seed = [u8; 32];
+        fn from_seed(seed: [u8; 32]) -> StdRng010 {
+            StdRng010(ChaCha::from_seed(seed))
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::drbg::CtrDrbg;
+use crate::error::Error;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Number of bytes generated before reseeding from the OS.
+const RESEED_THRESHOLD: u64 = 64 * 1024;
+
+/// Number of bytes generated by the DRBG at once and buffered.
+const BLOCK_LEN: usize = 64;
+
+/// Thread-local CSPRNG behind [`ThreadRng`].
+struct Core {
+    drbg: CtrDrbg,
+    /// Output of the DRBG, the bytes before `pos` are used and zeroed.
+    block: [u8; BLOCK_LEN],
+    pos: usize,
+    pid: u32,
+    bytes_until_reseed: u64,
+    #[cfg(test)]
+    reseeds: u64,
+}
+
+impl Core {
+    fn new() -> Result<Core, Error> {
+        let mut seed = [0; 48];
+        getrandom::getrandom(&mut seed).map_err(|e| Error::from(e.code()))?;
+        let (entropy_input, nonce) = seed.split_at(32);
+        let drbg = CtrDrbg::new(entropy_input, nonce, b"rand_core_compat::thread_rng");
+        seed.fill(0);
+        Ok(Core {
+            drbg,
+            block: [0; BLOCK_LEN],
+            pos: BLOCK_LEN,
+            pid: std::process::id(),
+            bytes_until_reseed: RESEED_THRESHOLD,
+            #[cfg(test)]
+            reseeds: 0,
+        })
+    }
+
+    fn reseed(&mut self) -> Result<(), Error> {
+        let mut entropy_input = [0; 32];
+        getrandom::getrandom(&mut entropy_input).map_err(|e| Error::from(e.code()))?;
+        self.drbg.reseed(&entropy_input, &[]);
+        entropy_input.fill(0);
+        self.bytes_until_reseed = RESEED_THRESHOLD;
+        #[cfg(test)]
+        {
+            self.reseeds += 1;
+        }
+        Ok(())
+    }
+
+    /// Generate the next block, reseeding first if the process ID changed or
+    /// the reseed threshold is reached.
+    fn refill(&mut self) -> Result<(), Error> {
+        let pid = std::process::id();
+        if pid != self.pid || self.bytes_until_reseed == 0 {
+            self.reseed()?;
+            self.pid = pid;
+        }
+        self.drbg.generate(&mut self.block, &[])?;
+        self.bytes_until_reseed = self.bytes_until_reseed.saturating_sub(BLOCK_LEN as u64);
+        self.pos = 0;
+        Ok(())
+    }
+
+    fn try_fill_bytes(&mut self, mut dst: &mut [u8]) -> Result<(), Error> {
+        while !dst.is_empty() {
+            if self.pos == BLOCK_LEN {
+                self.refill()?;
+            }
+            let len = dst.len().min(BLOCK_LEN - self.pos);
+            let src = &mut self.block[self.pos..self.pos + len];
+            dst[..len].copy_from_slice(src);
+            src.fill(0);
+            self.pos += len;
+            dst = &mut dst[len..];
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Core {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.block);
+    }
+}
+
+thread_local! {
+    static THREAD_RNG: Rc<RefCell<Core>> = Rc::new(RefCell::new(
+        Core::new().unwrap_or_else(|e| panic!("could not seed thread_rng: {}", e)),
+    ));
+}
+
+/// Return a handle to the thread-local CSPRNG.
+///
+/// The CSPRNG is a CTR_DRBG using AES-256 (see [`crate::drbg`]), seeded from
+/// the OS and reseeded every 64 KiB of output, as well as in child processes
+/// after `fork()`, detected by a change of the process ID. A single CSPRNG
+/// serves every selected `rand_core`/`rand` version, as [`ThreadRng`]
+/// implements the RNG and crypto RNG traits of all of them.
+///
+/// The output is generated in blocks of 64 bytes, and both checks only happen
+/// before generating a block. After `fork()`, the child may therefore repeat
+/// the rest of the block of its parent, up to 63 bytes.
+///
+/// # Panics
+///
+/// Panics if the OS RNG fails when first used on a thread.
+pub fn thread_rng() -> ThreadRng {
+    ThreadRng {
+        core: THREAD_RNG.with(Rc::clone),
+    }
+}
+
+/// Handle to the thread-local CSPRNG, returned by [`thread_rng`].
+///
+/// The RNG traits that cannot fail panic if reseeding from the OS fails.
+#[derive(Clone)]
+pub struct ThreadRng {
+    core: Rc<RefCell<Core>>,
+}
+
+impl ThreadRng {
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+        self.core.borrow_mut().try_fill_bytes(dst)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.try_fill_bytes(dst)
+            .unwrap_or_else(|e| panic!("thread_rng failed: {}", e))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+}
+
+/// Doesn't print the internal state.
+impl fmt::Debug for ThreadRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadRng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+mod v0_5 {
+    use super::ThreadRng;
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` RNG trait.
+    impl rand_core_0_5::RngCore for ThreadRng {
+        fn next_u32(&mut self) -> u32 {
+            ThreadRng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            ThreadRng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            ThreadRng::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_5::Error> {
+            Ok(ThreadRng::try_fill_bytes(self, dst)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.5`/`rand 0.7` crypto RNG trait.
+    impl rand_core_0_5::CryptoRng for ThreadRng {}
+}
+
+#[cfg(feature = "rand_core_0_6")]
+mod v0_6 {
+    use super::ThreadRng;
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` RNG trait.
+    impl rand_core_0_6::RngCore for ThreadRng {
+        fn next_u32(&mut self) -> u32 {
+            ThreadRng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            ThreadRng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            ThreadRng::fill_bytes(self, dst)
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core_0_6::Error> {
+            Ok(ThreadRng::try_fill_bytes(self, dst)?)
+        }
+    }
+
+    /// Implement the `rand_core 0.6`/`rand 0.8` crypto RNG trait.
+    impl rand_core_0_6::CryptoRng for ThreadRng {}
+}
+
+#[cfg(feature = "rand_core_0_9")]
+mod v0_9 {
+    use super::ThreadRng;
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` RNG trait.
+    impl rand_core_0_9::RngCore for ThreadRng {
+        fn next_u32(&mut self) -> u32 {
+            ThreadRng::next_u32(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            ThreadRng::next_u64(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            ThreadRng::fill_bytes(self, dst)
+        }
+    }
+
+    /// Implement the `rand_core 0.9`/`rand 0.9` crypto RNG trait.
+    impl rand_core_0_9::CryptoRng for ThreadRng {}
+}
+
+#[cfg(feature = "rand_core_0_10")]
+mod v0_10 {
+    use super::ThreadRng;
+    use core::convert::Infallible;
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` RNG trait.
+    impl rand_core_0_10::TryRng for ThreadRng {
+        type Error = Infallible;
+        fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+            Ok(ThreadRng::next_u32(self))
+        }
+        fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+            Ok(ThreadRng::next_u64(self))
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+            ThreadRng::fill_bytes(self, dst);
+            Ok(())
+        }
+    }
+
+    /// Implement the `rand_core 0.10`/`rand 0.10` crypto RNG trait.
+    impl rand_core_0_10::TryCryptoRng for ThreadRng {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BLOCK_LEN;
+    use super::RESEED_THRESHOLD;
+    use super::thread_rng;
+
+    #[test]
+    fn reseeds_at_threshold() {
+        let mut rng = thread_rng();
+        let mut dst = vec![0; RESEED_THRESHOLD as usize - 1];
+        rng.fill_bytes(&mut dst);
+        assert_eq!(rng.core.borrow().reseeds, 0);
+        // The last byte of the block before the threshold.
+        rng.fill_bytes(&mut [0]);
+        assert_eq!(rng.core.borrow().reseeds, 0);
+        rng.next_u32();
+        assert_eq!(rng.core.borrow().reseeds, 1);
+        // The rest of the block comes from the buffer.
+        for _ in 0..(BLOCK_LEN - 4) / 4 {
+            rng.next_u32();
+        }
+        assert_eq!(rng.core.borrow().pos, BLOCK_LEN);
+        let mut dst = vec![0; RESEED_THRESHOLD as usize - BLOCK_LEN];
+        rng.fill_bytes(&mut dst);
+        assert_eq!(rng.core.borrow().reseeds, 1);
+        rng.next_u64();
+        assert_eq!(rng.core.borrow().reseeds, 2);
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde::de;
+
+/// `rand_core`/`rand` version of the generator an RNG state was saved from.
+///
+/// All versions are available independently of the selected crate features,
+/// so that a build which dropped an old version can still recognise its
+/// checkpoints.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Version {
+    /// `rand_core 0.5`/`rand 0.7`.
+    V0_5,
+    /// `rand_core 0.6`/`rand 0.8`.
+    V0_6,
+    /// `rand_core 0.9`/`rand 0.9`.
+    V0_9,
+    /// `rand_core 0.10`/`rand 0.10`.
+    V0_10,
+}
+
+impl Version {
+    /// Return the major and minor version number of `rand_core`.
+    fn number(self) -> (u64, u64) {
+        match self {
+            Version::V0_5 => (0, 5),
+            Version::V0_6 => (0, 6),
+            Version::V0_9 => (0, 9),
+            Version::V0_10 => (0, 10),
+        }
+    }
+}
+
+/// Serialize the version as the tuple of the major and minor version number of
+/// `rand_core`.
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.number().serialize(serializer)
+    }
+}
+
+/// Deserialize the version from the tuple of the major and minor version
+/// number of `rand_core`.
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        match <(u64, u64)>::deserialize(deserializer)? {
+            (0, 5) => Ok(Version::V0_5),
+            (0, 6) => Ok(Version::V0_6),
+            (0, 9) => Ok(Version::V0_9),
+            (0, 10) => Ok(Version::V0_10),
+            _ => Err(de::Error::custom("unknown rand_core version")),
+        }
+    }
+}
+
+/// RNG state tagged with the [`Version`] of the generator it was saved from.
+///
+/// The state is typically a wrapped RNG like `Rng06<T>`, which serializes like
+/// the inner RNG with the `serde` feature, or one of the version-independent
+/// states like `ChaChaState`. When loading a checkpoint after an upgrade, the
+/// tag tells which type to deserialize the state as. The state of an older
+/// generator is then migrated to a newer one via the conversions of the
+/// version-independent states, e.g. from `rand_xoshiro 0.6` to
+/// `rand_xoshiro 0.7` via `Xoshiro256State`. With self-describing formats, the
+/// tag can be read on its own by deserializing a
+/// `Versioned<serde::de::IgnoredAny>` first.
+///
+/// It serializes as the tuple of the version and the state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Versioned<S> {
+    version: Version,
+    state: S,
+}
+
+impl<S> Versioned<S> {
+    /// Tag `state` with the version of the generator it was saved from.
+    pub fn new(version: Version, state: S) -> Versioned<S> {
+        Versioned { version, state }
+    }
+
+    /// Return the version of the generator the state was saved from.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Return the state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Return the state, dropping the version.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+/// Serialize the tuple of the version and the state.
+impl<S: Serialize> Serialize for Versioned<S> {
+    fn serialize<T: Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+        (self.version, &self.state).serialize(serializer)
+    }
+}
+
+/// Deserialize the tuple of the version and the state.
+impl<'de, S: Deserialize<'de>> Deserialize<'de> for Versioned<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Versioned<S>, D::Error> {
+        let (version, state) = Deserialize::deserialize(deserializer)?;
+        Ok(Versioned { version, state })
+    }
+}
@@ -0,0 +1,640 @@
+/// State of a `Xoshiro256Plus`, `Xoshiro256PlusPlus` or `Xoshiro256StarStar`
+/// RNG of `rand_xoshiro`, independent of its version.
+///
+/// It consists of the four 64-bit words of the xoshiro256 state, which
+/// together with the variant determine all further output. It can be extracted
+/// from these RNGs of every selected `rand_xoshiro` version and converted back
+/// into them, so that a checkpoint taken with one version can be resumed with
+/// another one at the same position, e.g. from the RNG wrapped by a
+/// [`Rng06`](crate::Rng06) into a `rand_xoshiro 0.7` RNG. `rand_xoshiro` only
+/// exposes the state through `serde`, which the features of these versions
+/// enable. The state doesn't record the variant, so it must be restored into
+/// the same variant.
+///
+/// The all-zero state never occurs. `rand_xoshiro` replaces it when
+/// restoring, like an all-zero seed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Xoshiro256State {
+    s: [u64; 4],
+}
+
+impl Xoshiro256State {
+    /// Create the state from its four words.
+    pub fn new(s: [u64; 4]) -> Xoshiro256State {
+        Xoshiro256State { s }
+    }
+
+    /// Return the four words of the state.
+    pub fn words(&self) -> [u64; 4] {
+        self.s
+    }
+
+    /// Jump ahead by 2<sup>128</sup> outputs, independently of the
+    /// `rand_xoshiro` version.
+    pub fn jump(&mut self) {
+        self.jump_by([
+            0x180ec6d33cfd0aba,
+            0xd5a61266f0c9392c,
+            0xa9582618e03fc9aa,
+            0x39abdc4529b1661c,
+        ]);
+    }
+
+    /// Jump ahead by 2<sup>192</sup> outputs, independently of the
+    /// `rand_xoshiro` version.
+    pub fn long_jump(&mut self) {
+        self.jump_by([
+            0x76e15d3efefdcbbf,
+            0xc5004e441c522fb3,
+            0x77710069854ee241,
+            0x39109bb02acbe635,
+        ]);
+    }
+
+    /// Apply the jump polynomial `poly` to the state, like the reference
+    /// implementation.
+    fn jump_by(&mut self, poly: [u64; 4]) {
+        let mut s = [0; 4];
+        for word in poly {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (s, t) in s.iter_mut().zip(self.s) {
+                        *s ^= t;
+                    }
+                }
+                self.step();
+            }
+        }
+        self.s = s;
+    }
+
+    /// Advance the state by one output.
+    fn step(&mut self) {
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+    }
+
+    /// Return the seed resulting in this state.
+    fn seed(&self) -> [u8; 32] {
+        let mut seed = [0; 32];
+        for (chunk, s) in seed.chunks_exact_mut(8).zip(self.s) {
+            chunk.copy_from_slice(&s.to_le_bytes());
+        }
+        seed
+    }
+}
+
+/// Serialize the state as its four words.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Xoshiro256State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.s, serializer)
+    }
+}
+
+/// Deserialize the state from its four words.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Xoshiro256State {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Xoshiro256State, D::Error> {
+        let s: [u64; 4] = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Xoshiro256State::new(s))
+    }
+}
+
+/// State of a `Xoshiro128Plus`, `Xoshiro128PlusPlus` or `Xoshiro128StarStar`
+/// RNG of `rand_xoshiro`, independent of its version.
+///
+/// It consists of the four 32-bit words of the xoshiro128 state and can be
+/// moved between `rand_xoshiro` versions like [`Xoshiro256State`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Xoshiro128State {
+    s: [u32; 4],
+}
+
+impl Xoshiro128State {
+    /// Create the state from its four words.
+    pub fn new(s: [u32; 4]) -> Xoshiro128State {
+        Xoshiro128State { s }
+    }
+
+    /// Return the four words of the state.
+    pub fn words(&self) -> [u32; 4] {
+        self.s
+    }
+
+    /// Jump ahead by 2<sup>64</sup> outputs, independently of the
+    /// `rand_xoshiro` version.
+    pub fn jump(&mut self) {
+        self.jump_by([0x8764000b, 0xf542d2d3, 0x6fa035c3, 0x77f2db5b]);
+    }
+
+    /// Jump ahead by 2<sup>96</sup> outputs, independently of the
+    /// `rand_xoshiro` version. `rand_xoshiro` before 0.7 has no `long_jump` for
+    /// `Xoshiro128Plus`.
+    pub fn long_jump(&mut self) {
+        self.jump_by([0xb523952e, 0x0b6f099f, 0xccf5a0ef, 0x1c580662]);
+    }
+
+    /// Apply the jump polynomial `poly` to the state, like the reference
+    /// implementation.
+    fn jump_by(&mut self, poly: [u32; 4]) {
+        let mut s = [0; 4];
+        for word in poly {
+            for bit in 0..32 {
+                if word & (1 << bit) != 0 {
+                    for (s, t) in s.iter_mut().zip(self.s) {
+                        *s ^= t;
+                    }
+                }
+                self.step();
+            }
+        }
+        self.s = s;
+    }
+
+    /// Advance the state by one output.
+    fn step(&mut self) {
+        let t = self.s[1] << 9;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(11);
+    }
+
+    /// Return the seed resulting in this state.
+    fn seed(&self) -> [u8; 16] {
+        let mut seed = [0; 16];
+        for (chunk, s) in seed.chunks_exact_mut(4).zip(self.s) {
+            chunk.copy_from_slice(&s.to_le_bytes());
+        }
+        seed
+    }
+}
+
+/// Serialize the state as its four words.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Xoshiro128State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.s, serializer)
+    }
+}
+
+/// Deserialize the state from its four words.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Xoshiro128State {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Xoshiro128State, D::Error> {
+        let s: [u32; 4] = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Xoshiro128State::new(s))
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_4")]
+mod v0_4 {
+    use super::Xoshiro128State;
+    use super::Xoshiro256State;
+    use crate::capture;
+    use rand_core_0_5::SeedableRng;
+    use rand_xoshiro_0_4::Xoshiro128Plus;
+    use rand_xoshiro_0_4::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_4::Xoshiro128StarStar;
+    use rand_xoshiro_0_4::Xoshiro256Plus;
+    use rand_xoshiro_0_4::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_4::Xoshiro256StarStar;
+
+    /// Extract the state of a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`)
+    /// RNG.
+    impl From<&Xoshiro128Plus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128Plus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128Plus {
+        fn from(state: Xoshiro128State) -> Xoshiro128Plus {
+            Xoshiro128Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`)
+    /// RNG.
+    impl From<&Xoshiro128PlusPlus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128PlusPlus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128PlusPlus {
+        fn from(state: Xoshiro128State) -> Xoshiro128PlusPlus {
+            Xoshiro128PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`)
+    /// RNG.
+    impl From<&Xoshiro128StarStar> for Xoshiro128State {
+        fn from(rng: &Xoshiro128StarStar) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128StarStar {
+        fn from(state: Xoshiro128State) -> Xoshiro128StarStar {
+            Xoshiro128StarStar::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`)
+    /// RNG.
+    impl From<&Xoshiro256Plus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256Plus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256Plus {
+        fn from(state: Xoshiro256State) -> Xoshiro256Plus {
+            Xoshiro256Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`)
+    /// RNG.
+    impl From<&Xoshiro256PlusPlus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256PlusPlus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256PlusPlus {
+        fn from(state: Xoshiro256State) -> Xoshiro256PlusPlus {
+            Xoshiro256PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`)
+    /// RNG.
+    impl From<&Xoshiro256StarStar> for Xoshiro256State {
+        fn from(rng: &Xoshiro256StarStar) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.4` (`rand_core 0.5`/`rand 0.7`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256StarStar {
+        fn from(state: Xoshiro256State) -> Xoshiro256StarStar {
+            Xoshiro256StarStar::from_seed(state.seed())
+        }
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_6")]
+mod v0_6 {
+    use super::Xoshiro128State;
+    use super::Xoshiro256State;
+    use crate::capture;
+    use rand_core_0_6::SeedableRng;
+    use rand_xoshiro_0_6::Xoshiro128Plus;
+    use rand_xoshiro_0_6::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_6::Xoshiro128StarStar;
+    use rand_xoshiro_0_6::Xoshiro256Plus;
+    use rand_xoshiro_0_6::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_6::Xoshiro256StarStar;
+
+    /// Extract the state of a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&Xoshiro128Plus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128Plus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128Plus {
+        fn from(state: Xoshiro128State) -> Xoshiro128Plus {
+            Xoshiro128Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&Xoshiro128PlusPlus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128PlusPlus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128PlusPlus {
+        fn from(state: Xoshiro128State) -> Xoshiro128PlusPlus {
+            Xoshiro128PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&Xoshiro128StarStar> for Xoshiro128State {
+        fn from(rng: &Xoshiro128StarStar) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128StarStar {
+        fn from(state: Xoshiro128State) -> Xoshiro128StarStar {
+            Xoshiro128StarStar::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&Xoshiro256Plus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256Plus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256Plus {
+        fn from(state: Xoshiro256State) -> Xoshiro256Plus {
+            Xoshiro256Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&Xoshiro256PlusPlus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256PlusPlus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256PlusPlus {
+        fn from(state: Xoshiro256State) -> Xoshiro256PlusPlus {
+            Xoshiro256PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`)
+    /// RNG.
+    impl From<&Xoshiro256StarStar> for Xoshiro256State {
+        fn from(rng: &Xoshiro256StarStar) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.6` (`rand_core 0.6`/`rand 0.8`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256StarStar {
+        fn from(state: Xoshiro256State) -> Xoshiro256StarStar {
+            Xoshiro256StarStar::from_seed(state.seed())
+        }
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_7")]
+mod v0_7 {
+    use super::Xoshiro128State;
+    use super::Xoshiro256State;
+    use crate::capture;
+    use rand_core_0_9::SeedableRng;
+    use rand_xoshiro_0_7::Xoshiro128Plus;
+    use rand_xoshiro_0_7::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_7::Xoshiro128StarStar;
+    use rand_xoshiro_0_7::Xoshiro256Plus;
+    use rand_xoshiro_0_7::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_7::Xoshiro256StarStar;
+
+    /// Extract the state of a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&Xoshiro128Plus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128Plus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128Plus {
+        fn from(state: Xoshiro128State) -> Xoshiro128Plus {
+            Xoshiro128Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&Xoshiro128PlusPlus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128PlusPlus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128PlusPlus {
+        fn from(state: Xoshiro128State) -> Xoshiro128PlusPlus {
+            Xoshiro128PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&Xoshiro128StarStar> for Xoshiro128State {
+        fn from(rng: &Xoshiro128StarStar) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128StarStar {
+        fn from(state: Xoshiro128State) -> Xoshiro128StarStar {
+            Xoshiro128StarStar::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&Xoshiro256Plus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256Plus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256Plus {
+        fn from(state: Xoshiro256State) -> Xoshiro256Plus {
+            Xoshiro256Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&Xoshiro256PlusPlus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256PlusPlus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256PlusPlus {
+        fn from(state: Xoshiro256State) -> Xoshiro256PlusPlus {
+            Xoshiro256PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`)
+    /// RNG.
+    impl From<&Xoshiro256StarStar> for Xoshiro256State {
+        fn from(rng: &Xoshiro256StarStar) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.7` (`rand_core 0.9`/`rand 0.9`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256StarStar {
+        fn from(state: Xoshiro256State) -> Xoshiro256StarStar {
+            Xoshiro256StarStar::from_seed(state.seed())
+        }
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_8")]
+mod v0_8 {
+    use super::Xoshiro128State;
+    use super::Xoshiro256State;
+    use crate::capture;
+    use rand_core_0_10::SeedableRng;
+    use rand_xoshiro_0_8::Xoshiro128Plus;
+    use rand_xoshiro_0_8::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_8::Xoshiro128StarStar;
+    use rand_xoshiro_0_8::Xoshiro256Plus;
+    use rand_xoshiro_0_8::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_8::Xoshiro256StarStar;
+
+    /// Extract the state of a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Xoshiro128Plus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128Plus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128Plus {
+        fn from(state: Xoshiro128State) -> Xoshiro128Plus {
+            Xoshiro128Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Xoshiro128PlusPlus> for Xoshiro128State {
+        fn from(rng: &Xoshiro128PlusPlus) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128PlusPlus {
+        fn from(state: Xoshiro128State) -> Xoshiro128PlusPlus {
+            Xoshiro128PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Xoshiro128StarStar> for Xoshiro128State {
+        fn from(rng: &Xoshiro128StarStar) -> Xoshiro128State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro128State::new(s.map(|s| s as u32))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Xoshiro128State> for Xoshiro128StarStar {
+        fn from(state: Xoshiro128State) -> Xoshiro128StarStar {
+            Xoshiro128StarStar::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Xoshiro256Plus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256Plus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256Plus {
+        fn from(state: Xoshiro256State) -> Xoshiro256Plus {
+            Xoshiro256Plus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Xoshiro256PlusPlus> for Xoshiro256State {
+        fn from(rng: &Xoshiro256PlusPlus) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256PlusPlus {
+        fn from(state: Xoshiro256State) -> Xoshiro256PlusPlus {
+            Xoshiro256PlusPlus::from_seed(state.seed())
+        }
+    }
+
+    /// Extract the state of a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`)
+    /// RNG.
+    impl From<&Xoshiro256StarStar> for Xoshiro256State {
+        fn from(rng: &Xoshiro256StarStar) -> Xoshiro256State {
+            let s: [u128; 4] = capture::words(rng);
+            Xoshiro256State::new(s.map(|s| s as u64))
+        }
+    }
+
+    /// Restore a `rand_xoshiro 0.8` (`rand_core 0.10`/`rand 0.10`) RNG.
+    impl From<Xoshiro256State> for Xoshiro256StarStar {
+        fn from(state: Xoshiro256State) -> Xoshiro256StarStar {
+            Xoshiro256StarStar::from_seed(state.seed())
+        }
+    }
+}
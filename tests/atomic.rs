@@ -0,0 +1,108 @@
+//! Tests of `AtomicSplitMix64`.
+//!
+//! The expected outputs are the first values of the reference tests of the
+//! `SplitMix64` RNG of `rand_xoshiro`, produced using the reference
+//! implementations of SplitMix64 by Sebastiano Vigna.
+#![cfg(feature = "rand_core_0_9")]
+
+use rand_core_compat::AtomicSplitMix64;
+
+/// Outputs of `next_u64` for the state 1477776061723855037.
+const NEXT_U64: [u64; 10] = [
+    1985237415132408290,
+    2979275885539914483,
+    13511426838097143398,
+    8488337342461049707,
+    15141737807933549159,
+    17093170987380407015,
+    16389528042912955399,
+    13177319091862933652,
+    10841969400225389492,
+    17094824097954834098,
+];
+
+/// Outputs of `next_u32` for the state 10.
+const NEXT_U32: [u32; 10] = [
+    3930361779, 4016923089, 4113052479, 925926767, 1755287528, 802865554, 954171070, 3724185978,
+    173676273, 1414488795,
+];
+
+#[test]
+fn reference() {
+    use rand_core_0_9::RngCore;
+    use rand_core_0_9::SeedableRng;
+
+    let rng = AtomicSplitMix64::new(1477776061723855037);
+    for expected in NEXT_U64 {
+        assert_eq!((&rng).next_u64(), expected);
+    }
+    let mut rng = AtomicSplitMix64::seed_from_u64(10);
+    for expected in NEXT_U32 {
+        assert_eq!(rng.next_u32(), expected);
+    }
+    let mut rng = AtomicSplitMix64::from_seed(1477776061723855037u64.to_le_bytes());
+    let mut dst = [0; 20];
+    rng.fill_bytes(&mut dst);
+    assert_eq!(dst[..8], NEXT_U64[0].to_le_bytes());
+    assert_eq!(dst[8..16], NEXT_U64[1].to_le_bytes());
+    // The last 4 bytes are taken from `next_u32`.
+    assert_eq!(rng.clone().next_u64(), NEXT_U64[3]);
+}
+
+#[test]
+fn threads() {
+    use rand_core_0_9::RngCore;
+
+    // Every output is generated exactly once, whichever thread draws it.
+    static RNG: AtomicSplitMix64 = AtomicSplitMix64::new(1477776061723855037);
+    let threads: Vec<_> = (0..5)
+        .map(|_| std::thread::spawn(|| vec![(&RNG).next_u64(), (&RNG).next_u64()]))
+        .collect();
+    let mut outputs: Vec<u64> = threads
+        .into_iter()
+        .flat_map(|thread| thread.join().unwrap())
+        .collect();
+    outputs.sort_unstable();
+    let mut expected = NEXT_U64;
+    expected.sort_unstable();
+    assert_eq!(outputs, expected);
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+    use rand_core_0_5::SeedableRng;
+
+    let mut rng = AtomicSplitMix64::seed_from_u64(1477776061723855037);
+    assert_eq!(rng.next_u64(), NEXT_U64[0]);
+    assert_eq!((&rng).next_u64(), NEXT_U64[1]);
+    assert_eq!(AtomicSplitMix64::seed_from_u64(10).next_u32(), NEXT_U32[0]);
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+    use rand_core_0_6::SeedableRng;
+
+    let mut rng = AtomicSplitMix64::seed_from_u64(1477776061723855037);
+    assert_eq!(rng.next_u64(), NEXT_U64[0]);
+    assert_eq!((&rng).next_u64(), NEXT_U64[1]);
+    assert_eq!(AtomicSplitMix64::seed_from_u64(10).next_u32(), NEXT_U32[0]);
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::SeedableRng;
+    use rand_core_0_10::TryRng;
+
+    let mut rng = AtomicSplitMix64::seed_from_u64(1477776061723855037);
+    assert_eq!(rng.try_next_u64().unwrap(), NEXT_U64[0]);
+    assert_eq!((&rng).try_next_u64().unwrap(), NEXT_U64[1]);
+    assert_eq!(
+        AtomicSplitMix64::seed_from_u64(10).try_next_u32().unwrap(),
+        NEXT_U32[0]
+    );
+}
@@ -0,0 +1,149 @@
+//! Tests of moving the state of a ChaCha RNG between `rand_chacha` versions.
+//!
+//! A `rand_chacha 0.3` RNG on a non-zero stream is advanced by a number of
+//! words that is not a multiple of the block size of 16 words, so that the
+//! state points into the middle of a block. The state is restored into the
+//! other versions, which must continue with the same output, and taken back
+//! to `rand_chacha 0.3`.
+#![cfg(all(feature = "rand_chacha_0_3", feature = "rand_chacha_0_9"))]
+
+use rand_core_compat::ChaChaState;
+
+const SEED: [u8; 32] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe,
+    0x2a, 0x3b, 0x4c, 0x5d, 0x6e, 0x7f, 0x80, 0x91, 0xa2, 0xb3, 0xc4, 0xd5, 0xe6, 0xf7, 0x08, 0x19,
+];
+
+const STREAM: u64 = 0x0123_4567_89ab_cdef;
+
+/// Number of words generated before taking the state, 2 blocks and 5 words.
+const WORDS: usize = 37;
+
+/// Number of words compared after restoring the state.
+const LEN: usize = 45;
+
+/// Create the `rand_chacha 0.3` RNG on `STREAM` advanced by `WORDS` words.
+fn advanced<R>(set_stream: fn(&mut R, u64)) -> R
+where
+    R: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng<Seed = [u8; 32]>,
+{
+    let mut rng = R::from_seed(SEED);
+    set_stream(&mut rng, STREAM);
+    for _ in 0..WORDS {
+        rng.next_u32();
+    }
+    rng
+}
+
+fn words06(rng: &mut impl rand_core_0_6::RngCore) -> Vec<u32> {
+    (0..LEN).map(|_| rng.next_u32()).collect()
+}
+
+fn words09(rng: &mut impl rand_core_0_9::RngCore) -> Vec<u32> {
+    (0..LEN).map(|_| rng.next_u32()).collect()
+}
+
+#[cfg(feature = "rand_chacha_0_10")]
+fn words010(rng: &mut impl rand_core_0_10::Rng) -> Vec<u32> {
+    (0..LEN).map(|_| rng.next_u32()).collect()
+}
+
+/// Check the state of `rng` at `word_pos`.
+fn check_state(state: &ChaChaState, word_pos: usize) {
+    assert_eq!(state.seed(), SEED);
+    assert_eq!(state.stream(), STREAM);
+    assert_eq!(state.word_pos(), word_pos as u128);
+}
+
+/// Move the advanced `rand_chacha 0.3` RNG `R03` to `rand_chacha 0.9` and
+/// back, checking the output of both, and return the state taken from `R03`.
+fn round_trip09<R03, R09>(set_stream: fn(&mut R03, u64)) -> ChaChaState
+where
+    R03: rand_core_0_6::RngCore + rand_core_0_6::SeedableRng<Seed = [u8; 32]>,
+    R03: From<ChaChaState>,
+    for<'a> ChaChaState: From<&'a R03>,
+    R09: rand_core_0_9::RngCore + From<ChaChaState>,
+    for<'a> ChaChaState: From<&'a R09>,
+{
+    let rng03 = advanced(set_stream);
+    let state = ChaChaState::from(&rng03);
+    check_state(&state, WORDS);
+
+    let mut expected = rng03;
+    let first = words06(&mut expected);
+    let second = words06(&mut expected);
+
+    let mut rng09 = R09::from(state.clone());
+    assert_eq!(words09(&mut rng09), first);
+    let back = ChaChaState::from(&rng09);
+    check_state(&back, WORDS + LEN);
+    assert_eq!(words06(&mut R03::from(back)), second);
+    state
+}
+
+/// Move `state` to the `rand_chacha 0.10` RNG `R010` and back to the
+/// `rand_chacha 0.3` RNG `R03`, checking the output of both.
+#[cfg(feature = "rand_chacha_0_10")]
+fn round_trip010<R03, R010>(state: ChaChaState)
+where
+    R03: rand_core_0_6::RngCore + From<ChaChaState>,
+    R010: rand_core_0_10::Rng + From<ChaChaState>,
+    for<'a> ChaChaState: From<&'a R010>,
+{
+    let mut expected = R03::from(state.clone());
+    let first = words06(&mut expected);
+    let second = words06(&mut expected);
+
+    let mut rng010 = R010::from(state);
+    assert_eq!(words010(&mut rng010), first);
+    let back = ChaChaState::from(&rng010);
+    check_state(&back, WORDS + LEN);
+    assert_eq!(words06(&mut R03::from(back)), second);
+}
+
+#[test]
+fn chacha8() {
+    let state = round_trip09::<rand_chacha_0_3::ChaCha8Rng, rand_chacha_0_9::ChaCha8Rng>(
+        rand_chacha_0_3::ChaCha8Rng::set_stream,
+    );
+    #[cfg(feature = "rand_chacha_0_10")]
+    round_trip010::<rand_chacha_0_3::ChaCha8Rng, rand_chacha_0_10::ChaCha8Rng>(state);
+    #[cfg(not(feature = "rand_chacha_0_10"))]
+    let _ = state;
+}
+
+#[test]
+fn chacha12() {
+    let state = round_trip09::<rand_chacha_0_3::ChaCha12Rng, rand_chacha_0_9::ChaCha12Rng>(
+        rand_chacha_0_3::ChaCha12Rng::set_stream,
+    );
+    #[cfg(feature = "rand_chacha_0_10")]
+    round_trip010::<rand_chacha_0_3::ChaCha12Rng, rand_chacha_0_10::ChaCha12Rng>(state);
+    #[cfg(not(feature = "rand_chacha_0_10"))]
+    let _ = state;
+}
+
+#[test]
+fn chacha20() {
+    let state = round_trip09::<rand_chacha_0_3::ChaCha20Rng, rand_chacha_0_9::ChaCha20Rng>(
+        rand_chacha_0_3::ChaCha20Rng::set_stream,
+    );
+    #[cfg(feature = "rand_chacha_0_10")]
+    round_trip010::<rand_chacha_0_3::ChaCha20Rng, rand_chacha_0_10::ChaCha20Rng>(state);
+    #[cfg(not(feature = "rand_chacha_0_10"))]
+    let _ = state;
+}
+
+#[cfg(feature = "rand_chacha_0_2")]
+#[test]
+fn rand_chacha_0_2() {
+    use rand_core_0_5::RngCore;
+
+    let state = ChaChaState::from(&advanced::<rand_chacha_0_3::ChaCha20Rng>(
+        rand_chacha_0_3::ChaCha20Rng::set_stream,
+    ));
+    let expected = words06(&mut rand_chacha_0_3::ChaCha20Rng::from(state.clone()));
+    let mut rng = rand_chacha_0_2::ChaCha20Rng::from(state);
+    let output: Vec<u32> = (0..LEN).map(|_| rng.next_u32()).collect();
+    assert_eq!(output, expected);
+}
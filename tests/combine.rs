@@ -0,0 +1,130 @@
+//! Tests of `Combine` XOR-ing two RNGs.
+#![cfg(feature = "rand_core_0_9")]
+
+mod common;
+
+use common::Counter;
+use rand_core_0_9::RngCore;
+use rand_core_0_9::TryCryptoRng;
+use rand_core_0_9::TryRngCore;
+use rand_core_compat::Combine;
+use rand_core_compat::Rng09;
+use rand_core_compat::TryRng09;
+use rand_core_compat::error::Error;
+
+/// Non-random RNG pretending to be a crypto RNG.
+#[derive(Clone, Debug)]
+struct Crypto(Counter);
+
+impl RngCore for Crypto {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+}
+
+impl rand_core_0_9::CryptoRng for Crypto {}
+
+/// RNG failing every request.
+#[derive(Debug)]
+struct Failing(&'static str);
+
+impl TryRngCore for Failing {
+    type Error = &'static str;
+    fn try_next_u32(&mut self) -> Result<u32, &'static str> {
+        Err(self.0)
+    }
+    fn try_next_u64(&mut self) -> Result<u64, &'static str> {
+        Err(self.0)
+    }
+    fn try_fill_bytes(&mut self, _dst: &mut [u8]) -> Result<(), &'static str> {
+        Err(self.0)
+    }
+}
+
+fn assert_crypto<R: TryCryptoRng>(_rng: &R) {}
+
+/// Check that `error` is the error of the `Failing` RNG named `name`.
+///
+/// Without `std`, only the error code `UNKNOWN` is kept.
+fn assert_failed(error: Error, name: &str) {
+    #[cfg(feature = "std")]
+    assert_eq!(error.to_string(), name);
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = name;
+        assert_eq!(error.code(), rand_core_compat::error::UNKNOWN);
+    }
+}
+
+#[test]
+fn xor() {
+    let mut rng = Combine(Rng09(Counter(1)), Rng09(Counter(0xff01)));
+    assert_eq!(rng.try_next_u32().unwrap(), 1 ^ 0xff01);
+    assert_eq!(rng.try_next_u64().unwrap(), 2 ^ 0xff02);
+
+    let mut a = Counter(10);
+    let mut b = Counter(1000);
+    let mut rng = Combine(Rng09(a.clone()), Rng09(b.clone()));
+    for len in [0, 1, 7, 8, 63, 64, 65, 200] {
+        let mut dst = vec![0; len];
+        rng.try_fill_bytes(&mut dst).unwrap();
+        let mut expected_a = vec![0; len];
+        let mut expected_b = vec![0; len];
+        a.fill_bytes(&mut expected_a);
+        // The second RNG is asked for output in chunks of 64 bytes.
+        for chunk in expected_b.chunks_mut(64) {
+            b.fill_bytes(chunk);
+        }
+        let expected: Vec<u8> = expected_a
+            .iter()
+            .zip(&expected_b)
+            .map(|(a, b)| a ^ b)
+            .collect();
+        assert_eq!(dst, expected);
+    }
+}
+
+#[test]
+fn swap() {
+    let mut rng = Combine(Rng09(Counter(0)), Rng09(Crypto(Counter(7)))).swap();
+    assert_crypto(&rng);
+    let mut expected = Combine(Rng09(Counter(0)), Rng09(Counter(7)));
+    let mut dst = [0; 100];
+    let mut expected_dst = [0; 100];
+    rng.try_fill_bytes(&mut dst).unwrap();
+    expected.try_fill_bytes(&mut expected_dst).unwrap();
+    assert_eq!(dst, expected_dst);
+    assert_eq!(
+        rng.try_next_u64().unwrap(),
+        expected.try_next_u64().unwrap()
+    );
+}
+
+#[test]
+fn errors() {
+    let mut rng = Combine(TryRng09(Failing("first")), Rng09(Counter(0)));
+    assert_failed(rng.try_next_u32().unwrap_err(), "first");
+    assert_failed(rng.try_next_u64().unwrap_err(), "first");
+    assert_failed(rng.try_fill_bytes(&mut [0; 16]).unwrap_err(), "first");
+
+    let mut rng = Combine(Rng09(Counter(0)), TryRng09(Failing("second")));
+    assert_failed(rng.try_next_u32().unwrap_err(), "second");
+    assert_failed(rng.try_next_u64().unwrap_err(), "second");
+    assert_failed(rng.try_fill_bytes(&mut [0; 16]).unwrap_err(), "second");
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::TryRng;
+
+    let mut rng = Combine(Rng09(Counter(1)), Rng09(Counter(0xff01)));
+    assert_eq!(TryRng::try_next_u32(&mut rng).unwrap(), 1 ^ 0xff01);
+    assert_eq!(TryRng::try_next_u64(&mut rng).unwrap(), 2 ^ 0xff02);
+}
@@ -0,0 +1,115 @@
+//! Test vectors shared by the tests of the sampling reproductions of the
+//! `legacy` module.
+//!
+//! Each test records the output of its calls for every `rand` version with
+//! `XorShiftRng` seeded with `SEED` as the RNG, and checks them with the RNG
+//! wrapped for every selected `rand_core` version.
+
+use core::fmt::Debug;
+use rand_core_compat::legacy::RandVersion;
+use rand_core_compat::legacy::WrappedRng;
+
+pub const SEED: [u8; 16] = [
+    0x5e, 0x3d, 0x1c, 0x0b, 0xfa, 0xe9, 0xd8, 0xc7, 0xb6, 0xa5, 0x94, 0x83, 0x72, 0x61, 0x50, 0x4f,
+];
+
+/// The reproduced `rand` versions, in the order of the vectors.
+pub const VERSIONS: [RandVersion; 4] = [
+    RandVersion::V0_7,
+    RandVersion::V0_8,
+    RandVersion::V0_9,
+    RandVersion::V0_10,
+];
+
+/// Create `XorShiftRng` seeded with `SEED`, wrapped for one `rand_core`
+/// version.
+type NewRng = fn() -> Box<dyn WrappedRng>;
+
+/// The wrapped RNGs of the selected `rand_core` versions.
+const RNGS: &[(&str, NewRng)] = &[
+    #[cfg(feature = "rand_core_0_5")]
+    ("rand_core 0.5", || {
+        use rand_core_0_5::SeedableRng;
+        use rand_core_compat::Rng05;
+        use rand_core_compat::legacy::XorShiftRng;
+
+        Box::new(Rng05(XorShiftRng::from_seed(SEED)))
+    }),
+    #[cfg(feature = "rand_core_0_6")]
+    ("rand_core 0.6", || {
+        use rand_core_0_6::SeedableRng;
+        use rand_core_compat::Rng06;
+        use rand_core_compat::legacy::XorShiftRng;
+
+        Box::new(Rng06(XorShiftRng::from_seed(SEED)))
+    }),
+    #[cfg(feature = "rand_core_0_9")]
+    ("rand_core 0.9", || {
+        use rand_core_0_9::SeedableRng;
+        use rand_core_compat::Rng09;
+        use rand_core_compat::legacy::XorShiftRng;
+
+        Box::new(Rng09(XorShiftRng::from_seed(SEED)))
+    }),
+    #[cfg(feature = "rand_core_0_10")]
+    ("rand_core 0.10", || {
+        use rand_core_0_10::SeedableRng;
+        use rand_core_compat::Rng010;
+        use rand_core_compat::legacy::XorShiftRng;
+
+        Box::new(Rng010(XorShiftRng::from_seed(SEED)))
+    }),
+];
+
+/// Output of a call, compared bit for bit, so that floats must match exactly.
+pub trait Output: Copy + Debug {
+    fn bits(self) -> u128;
+}
+
+impl Output for usize {
+    fn bits(self) -> u128 {
+        self as u128
+    }
+}
+
+impl Output for i128 {
+    fn bits(self) -> u128 {
+        self as u128
+    }
+}
+
+impl Output for f64 {
+    fn bits(self) -> u128 {
+        self.to_bits().into()
+    }
+}
+
+/// Output of the calls of a test for one `rand` version.
+pub struct Vector<T: 'static> {
+    pub version: RandVersion,
+    pub output: &'static [T],
+}
+
+/// Check that `outputs` reproduces the vector of every `rand` version with
+/// the RNG wrapped for every selected `rand_core` version.
+pub fn check<T: Output>(
+    vectors: &[Vector<T>],
+    outputs: impl Fn(RandVersion, &mut dyn WrappedRng) -> Vec<T>,
+) {
+    let versions: Vec<RandVersion> = vectors.iter().map(|v| v.version).collect();
+    assert_eq!(versions, VERSIONS);
+    for (name, new_rng) in RNGS {
+        for v in vectors {
+            let output = outputs(v.version, &mut *new_rng());
+            assert!(
+                output
+                    .iter()
+                    .map(|x| x.bits())
+                    .eq(v.output.iter().map(|x| x.bits())),
+                "{:?} with {name}: {output:?} != {:?}",
+                v.version,
+                v.output
+            );
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Helpers shared by the tests.
+//!
+//! Every test compiles this module, but uses only some of it.
+#![allow(dead_code)]
+
+#[cfg(feature = "legacy_algorithms")]
+pub mod legacy;
+
+/// Non-random RNG returning consecutive numbers starting at its seed.
+#[cfg(feature = "rand_core_0_9")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counter(pub u64);
+
+#[cfg(feature = "rand_core_0_9")]
+impl rand_core_0_9::RngCore for Counter {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 += 1;
+        self.0 - 1
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core_0_9::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[cfg(feature = "rand_core_0_9")]
+impl rand_core_0_9::SeedableRng for Counter {
+    type Seed = [u8; 8];
+    fn from_seed(seed: [u8; 8]) -> Counter {
+        Counter(u64::from_le_bytes(seed))
+    }
+}
@@ -0,0 +1,149 @@
+//! Known-answer tests of `Conditioned`.
+//!
+//! The raw input is the sequence of bytes 0, 1, 2, ..., so each output block
+//! is the SHA-256 hash of consecutive bytes. The expected hashes were computed
+//! using Python's `hashlib`.
+#![cfg(all(feature = "conditioning", feature = "rand_core_0_9"))]
+
+use rand_core_0_9::RngCore;
+use rand_core_0_9::TryRngCore;
+use rand_core_compat::Conditioned;
+use rand_core_compat::Rng09;
+
+/// Non-random source returning consecutive bytes.
+#[derive(Clone, Debug)]
+struct Bytes(u8);
+
+impl RngCore for Bytes {
+    fn next_u32(&mut self) -> u32 {
+        rand_core_0_9::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core_0_9::impls::next_u64_via_fill(self)
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for byte in dst {
+            *byte = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+}
+
+/// SHA-256 of the bytes 0 to 39.
+const SHA256_0_40: [u8; 32] = [
+    0x5f, 0xaa, 0x4e, 0xec, 0x36, 0x11, 0x55, 0x68, 0x12, 0xc2, 0xd7, 0x4b, 0x43, 0x7c, 0x8c, 0x49,
+    0xad, 0xd3, 0xf9, 0x10, 0xf1, 0x00, 0x63, 0xd8, 0x01, 0x44, 0x1f, 0x7d, 0x75, 0xcd, 0x5e, 0x3b,
+];
+
+/// SHA-256 of the bytes 40 to 79.
+const SHA256_40_80: [u8; 32] = [
+    0x35, 0xba, 0x2f, 0x4f, 0xd7, 0xe6, 0x3a, 0x39, 0x1c, 0xd9, 0x0f, 0xc4, 0x7b, 0x12, 0xce, 0x09,
+    0xa8, 0x68, 0x2a, 0xaa, 0x86, 0x43, 0x84, 0x3d, 0x2b, 0x5f, 0xd1, 0x30, 0x2c, 0x9e, 0x86, 0x5f,
+];
+
+/// SHA-256 of the bytes 0 to 99.
+const SHA256_0_100: [u8; 32] = [
+    0xbc, 0xe0, 0xaf, 0xf1, 0x9c, 0xf5, 0xaa, 0x6a, 0x74, 0x69, 0xa3, 0x0d, 0x61, 0xd0, 0x4e, 0x43,
+    0x76, 0xe4, 0xbb, 0xf6, 0x38, 0x10, 0x52, 0xee, 0x9e, 0x7f, 0x33, 0x92, 0x5c, 0x95, 0x4d, 0x52,
+];
+
+#[test]
+fn blocks() {
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 40);
+    let mut output = [0; 64];
+    rng.try_fill_bytes(&mut output).unwrap();
+    assert_eq!(output[..32], SHA256_0_40);
+    assert_eq!(output[32..], SHA256_40_80);
+}
+
+#[test]
+fn partial_blocks() {
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 40);
+    assert_eq!(
+        rng.try_next_u32().unwrap(),
+        u32::from_le_bytes(SHA256_0_40[..4].try_into().unwrap())
+    );
+    let mut output = [0; 30];
+    rng.try_fill_bytes(&mut output).unwrap();
+    assert_eq!(output[..28], SHA256_0_40[4..]);
+    assert_eq!(output[28..], SHA256_40_80[..2]);
+}
+
+#[test]
+fn long_input() {
+    // The input is read in chunks of 64 bytes.
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 100);
+    let mut output = [0; 32];
+    rng.try_fill_bytes(&mut output).unwrap();
+    assert_eq!(output, SHA256_0_100);
+    assert_eq!(rng.inner().0.0, 100);
+}
+
+#[test]
+fn debug() {
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 40);
+    rng.try_next_u32().unwrap();
+    assert_eq!(
+        format!("{rng:?}"),
+        "Conditioned { inner: Rng09(Bytes(40)), input_len: 40, .. }"
+    );
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+
+    fn assert_crypto<R: rand_core_0_5::CryptoRng>(_rng: &R) {}
+
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 40);
+    assert_crypto(&rng);
+    assert_eq!(
+        rng.next_u32(),
+        u32::from_le_bytes(SHA256_0_40[..4].try_into().unwrap())
+    );
+    let mut output = [0; 30];
+    RngCore::try_fill_bytes(&mut rng, &mut output).unwrap();
+    assert_eq!(output[..28], SHA256_0_40[4..]);
+    assert_eq!(output[28..], SHA256_40_80[..2]);
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+
+    fn assert_crypto<R: rand_core_0_6::CryptoRng>(_rng: &R) {}
+
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 40);
+    assert_crypto(&rng);
+    let mut output = [0; 64];
+    rng.fill_bytes(&mut output);
+    assert_eq!(output[..32], SHA256_0_40);
+    assert_eq!(output[32..], SHA256_40_80);
+
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 100);
+    assert_eq!(
+        rng.next_u64(),
+        u64::from_le_bytes(SHA256_0_100[..8].try_into().unwrap())
+    );
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::TryRng;
+
+    fn assert_crypto<R: rand_core_0_10::TryCryptoRng>(_rng: &R) {}
+
+    let mut rng = Conditioned::new(Rng09(Bytes(0)), 40);
+    assert_crypto(&rng);
+    assert_eq!(
+        TryRng::try_next_u64(&mut rng).unwrap(),
+        u64::from_le_bytes(SHA256_0_40[..8].try_into().unwrap())
+    );
+    let mut output = [0; 30];
+    TryRng::try_fill_bytes(&mut rng, &mut output).unwrap();
+    assert_eq!(output[..24], SHA256_0_40[8..]);
+    assert_eq!(output[24..], SHA256_40_80[..6]);
+}
@@ -0,0 +1,119 @@
+//! Tests for the derivation of child RNGs.
+//!
+//! The HKDF-Expand vectors are test cases 1 and 3 of RFC 5869, using the
+//! pseudorandom key of the test case as the key and the info as the label.
+#![cfg(feature = "derivation")]
+
+use rand_core_compat::DerivationKey;
+
+struct Vector {
+    key: [u8; 32],
+    label: &'static [u8],
+    seed: [u8; 42],
+}
+
+const VECTORS: &[Vector] = &[
+    Vector {
+        key: [
+            0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf, 0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b,
+            0xba, 0x63, 0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31, 0x22, 0xec, 0x84, 0x4a,
+            0xd7, 0xc2, 0xb3, 0xe5,
+        ],
+        label: &[0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9],
+        seed: [
+            0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36,
+            0x2f, 0x2a, 0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56,
+            0xec, 0xc4, 0xc5, 0xbf, 0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+        ],
+    },
+    Vector {
+        key: [
+            0x19, 0xef, 0x24, 0xa3, 0x2c, 0x71, 0x7b, 0x16, 0x7f, 0x33, 0xa9, 0x1d, 0x6f, 0x64,
+            0x8b, 0xdf, 0x96, 0x59, 0x67, 0x76, 0xaf, 0xdb, 0x63, 0x77, 0xac, 0x43, 0x4c, 0x1c,
+            0x29, 0x3c, 0xcb, 0x04,
+        ],
+        label: &[],
+        seed: [
+            0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f, 0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c,
+            0x5a, 0x31, 0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e, 0xc3, 0x45, 0x4e, 0x5f,
+            0x3c, 0x73, 0x8d, 0x2d, 0x9d, 0x20, 0x13, 0x95, 0xfa, 0xa4, 0xb6, 0x1a, 0x96, 0xc8,
+        ],
+    },
+];
+
+const KEY: [u8; 32] = [0x42; 32];
+
+fn seed(label: &str) -> [u8; 32] {
+    let mut seed = [0; 32];
+    DerivationKey::new(KEY).fill_seed(label, &mut seed);
+    seed
+}
+
+#[test]
+fn vectors() {
+    for vector in VECTORS {
+        let mut seed = [0; 42];
+        DerivationKey::new(vector.key).fill_seed(vector.label, &mut seed);
+        assert_eq!(seed, vector.seed);
+        // Shorter seeds are prefixes of longer ones.
+        let mut seed = [0; 20];
+        DerivationKey::new(vector.key).fill_seed(vector.label, &mut seed);
+        assert_eq!(seed, vector.seed[..20]);
+    }
+}
+
+#[test]
+fn labels() {
+    assert_ne!(seed("agent/42"), seed("agent/43"));
+    assert_ne!(seed("agent/42"), seed("agent/4"));
+    assert_ne!(seed("agent/42"), seed(""));
+    assert_eq!(seed("agent/42"), seed("agent/42"));
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::SeedableRng;
+    use rand_core_compat::StdRng08;
+
+    let key = DerivationKey::from_seed(KEY);
+    assert_eq!(key, DerivationKey::new(KEY));
+    let child = key.child05::<StdRng08>("agent/42");
+    assert_eq!(child.0, StdRng08::from_seed(seed("agent/42")));
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::SeedableRng;
+    use rand_core_compat::StdRng08;
+
+    let key = DerivationKey::from_seed(KEY);
+    assert_eq!(key, DerivationKey::new(KEY));
+    let child = key.child06::<StdRng08>("agent/42");
+    assert_eq!(child.0, StdRng08::from_seed(seed("agent/42")));
+}
+
+#[cfg(feature = "rand_core_0_9")]
+#[test]
+fn rand_core_0_9() {
+    use rand_core_0_9::SeedableRng;
+    use rand_core_compat::StdRng08;
+
+    let key = DerivationKey::from_seed(KEY);
+    assert_eq!(key, DerivationKey::new(KEY));
+    let child = key.child09::<StdRng08>("agent/42");
+    assert_eq!(child.0, StdRng08::from_seed(seed("agent/42")));
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::SeedableRng;
+    use rand_core_compat::StdRng08;
+
+    let key = DerivationKey::from_seed(KEY);
+    assert_eq!(key, DerivationKey::new(KEY));
+    let child = key.child010::<StdRng08>("agent/42");
+    assert_eq!(child.0, StdRng08::from_seed(seed("agent/42")));
+}
@@ -0,0 +1,225 @@
+//! Tests for the distribution wrappers.
+//!
+//! Each test samples a distribution of one `rand` version through its wrapper
+//! with the RNGs of every selected `rand` version and checks that the samples
+//! match sampling the distribution directly. `AtomicSplitMix64` implements the
+//! RNG traits of every version, so each sampler gets the same output. The
+//! distributions cover the `next_u32`, `next_u64` and `fill_bytes` calls of the
+//! RNG.
+#![cfg(any(
+    feature = "rand_0_7",
+    feature = "rand_0_8",
+    feature = "rand_0_9",
+    feature = "rand_0_10",
+))]
+
+use core::fmt::Debug;
+use rand_core_compat::AtomicSplitMix64;
+
+const STATE: u64 = 0x0123_4567_89ab_cdef;
+const COUNT: usize = 32;
+
+#[cfg(feature = "rand_0_7")]
+fn sample07<T>(dist: &impl rand_0_7::distributions::Distribution<T>) -> Vec<T> {
+    let mut rng = &AtomicSplitMix64::new(STATE);
+    (0..COUNT).map(|_| dist.sample(&mut rng)).collect()
+}
+
+#[cfg(feature = "rand_0_8")]
+fn sample08<T>(dist: &impl rand_0_8::distributions::Distribution<T>) -> Vec<T> {
+    let mut rng = &AtomicSplitMix64::new(STATE);
+    (0..COUNT).map(|_| dist.sample(&mut rng)).collect()
+}
+
+#[cfg(feature = "rand_0_9")]
+fn sample09<T>(dist: &impl rand_0_9::distr::Distribution<T>) -> Vec<T> {
+    let mut rng = &AtomicSplitMix64::new(STATE);
+    (0..COUNT).map(|_| dist.sample(&mut rng)).collect()
+}
+
+#[cfg(feature = "rand_0_10")]
+fn sample010<T>(dist: &impl rand_0_10::distr::Distribution<T>) -> Vec<T> {
+    let mut rng = &AtomicSplitMix64::new(STATE);
+    (0..COUNT).map(|_| dist.sample(&mut rng)).collect()
+}
+
+/// Distribution filling a byte array using `fill_bytes`, which none of the
+/// portable distributions of `rand` call.
+#[derive(Clone, Copy)]
+struct Bytes;
+
+#[cfg(feature = "rand_0_7")]
+impl rand_0_7::distributions::Distribution<[u8; 13]> for Bytes {
+    fn sample<R: rand_0_7::Rng + ?Sized>(&self, rng: &mut R) -> [u8; 13] {
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+}
+
+#[cfg(feature = "rand_0_8")]
+impl rand_0_8::distributions::Distribution<[u8; 13]> for Bytes {
+    fn sample<R: rand_0_8::Rng + ?Sized>(&self, rng: &mut R) -> [u8; 13] {
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+}
+
+#[cfg(feature = "rand_0_9")]
+impl rand_0_9::distr::Distribution<[u8; 13]> for Bytes {
+    fn sample<R: rand_0_9::Rng + ?Sized>(&self, rng: &mut R) -> [u8; 13] {
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+}
+
+#[cfg(feature = "rand_0_10")]
+impl rand_0_10::distr::Distribution<[u8; 13]> for Bytes {
+    fn sample<R: rand_0_10::Rng + ?Sized>(&self, rng: &mut R) -> [u8; 13] {
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+}
+
+/// Check that sampling `dist` wrapped in `Dist07` with the RNG of every
+/// selected version matches sampling it directly.
+#[cfg(feature = "rand_0_7")]
+fn check07<T, D>(dist: D)
+where
+    T: Debug + PartialEq,
+    D: rand_0_7::distributions::Distribution<T> + Copy,
+{
+    use rand_core_compat::Dist07;
+
+    let expected = sample07(&dist);
+    assert_eq!(sample07(&Dist07(dist)), expected);
+    #[cfg(feature = "rand_0_8")]
+    assert_eq!(sample08(&Dist07(dist)), expected);
+    #[cfg(feature = "rand_0_9")]
+    assert_eq!(sample09(&Dist07(dist)), expected);
+    #[cfg(feature = "rand_0_10")]
+    assert_eq!(sample010(&Dist07(dist)), expected);
+}
+
+/// Check that sampling `dist` wrapped in `Dist08` with the RNG of every
+/// selected version matches sampling it directly.
+#[cfg(feature = "rand_0_8")]
+fn check08<T, D>(dist: D)
+where
+    T: Debug + PartialEq,
+    D: rand_0_8::distributions::Distribution<T> + Copy,
+{
+    use rand_core_compat::Dist08;
+
+    let expected = sample08(&dist);
+    #[cfg(feature = "rand_0_7")]
+    assert_eq!(sample07(&Dist08(dist)), expected);
+    assert_eq!(sample08(&Dist08(dist)), expected);
+    #[cfg(feature = "rand_0_9")]
+    assert_eq!(sample09(&Dist08(dist)), expected);
+    #[cfg(feature = "rand_0_10")]
+    assert_eq!(sample010(&Dist08(dist)), expected);
+}
+
+/// Check that sampling `dist` wrapped in `Dist09` with the RNG of every
+/// selected version matches sampling it directly.
+#[cfg(feature = "rand_0_9")]
+fn check09<T, D>(dist: D)
+where
+    T: Debug + PartialEq,
+    D: rand_0_9::distr::Distribution<T> + Copy,
+{
+    use rand_core_compat::Dist09;
+
+    let expected = sample09(&dist);
+    #[cfg(feature = "rand_0_7")]
+    assert_eq!(sample07(&Dist09(dist)), expected);
+    #[cfg(feature = "rand_0_8")]
+    assert_eq!(sample08(&Dist09(dist)), expected);
+    assert_eq!(sample09(&Dist09(dist)), expected);
+    #[cfg(feature = "rand_0_10")]
+    assert_eq!(sample010(&Dist09(dist)), expected);
+}
+
+/// Check that sampling `dist` wrapped in `Dist010` with the RNG of every
+/// selected version matches sampling it directly.
+#[cfg(feature = "rand_0_10")]
+fn check010<T, D>(dist: D)
+where
+    T: Debug + PartialEq,
+    D: rand_0_10::distr::Distribution<T> + Copy,
+{
+    use rand_core_compat::Dist010;
+
+    let expected = sample010(&dist);
+    #[cfg(feature = "rand_0_7")]
+    assert_eq!(sample07(&Dist010(dist)), expected);
+    #[cfg(feature = "rand_0_8")]
+    assert_eq!(sample08(&Dist010(dist)), expected);
+    #[cfg(feature = "rand_0_9")]
+    assert_eq!(sample09(&Dist010(dist)), expected);
+    assert_eq!(sample010(&Dist010(dist)), expected);
+}
+
+#[cfg(feature = "rand_0_7")]
+#[test]
+fn dist07() {
+    use rand_0_7::distributions::Bernoulli;
+    use rand_0_7::distributions::Standard;
+    use rand_0_7::distributions::Uniform;
+
+    check07::<u64, _>(Uniform::new(0u64, 1000));
+    check07::<u32, _>(Standard);
+    check07::<f32, _>(Standard);
+    check07::<[u8; 16], _>(Standard);
+    check07::<bool, _>(Bernoulli::new(0.3).unwrap());
+    check07::<[u8; 13], _>(Bytes);
+}
+
+#[cfg(feature = "rand_0_8")]
+#[test]
+fn dist08() {
+    use rand_0_8::distributions::Bernoulli;
+    use rand_0_8::distributions::Standard;
+    use rand_0_8::distributions::Uniform;
+
+    check08::<u64, _>(Uniform::new(0u64, 1000));
+    check08::<u32, _>(Standard);
+    check08::<f32, _>(Standard);
+    check08::<[u8; 16], _>(Standard);
+    check08::<bool, _>(Bernoulli::new(0.3).unwrap());
+    check08::<[u8; 13], _>(Bytes);
+}
+
+#[cfg(feature = "rand_0_9")]
+#[test]
+fn dist09() {
+    use rand_0_9::distr::Bernoulli;
+    use rand_0_9::distr::StandardUniform;
+    use rand_0_9::distr::Uniform;
+
+    check09::<u64, _>(Uniform::new(0u64, 1000).unwrap());
+    check09::<u32, _>(StandardUniform);
+    check09::<f32, _>(StandardUniform);
+    check09::<[u8; 16], _>(StandardUniform);
+    check09::<bool, _>(Bernoulli::new(0.3).unwrap());
+    check09::<[u8; 13], _>(Bytes);
+}
+
+#[cfg(feature = "rand_0_10")]
+#[test]
+fn dist010() {
+    use rand_0_10::distr::Bernoulli;
+    use rand_0_10::distr::StandardUniform;
+    use rand_0_10::distr::Uniform;
+
+    check010::<u64, _>(Uniform::new(0u64, 1000).unwrap());
+    check010::<u32, _>(StandardUniform);
+    check010::<f32, _>(StandardUniform);
+    check010::<[u8; 16], _>(StandardUniform);
+    check010::<bool, _>(Bernoulli::new(0.3).unwrap());
+    check010::<[u8; 13], _>(Bytes);
+}
@@ -0,0 +1,477 @@
+//! Test vectors for the DRBGs of NIST SP 800-90A.
+//!
+//! The HMAC_DRBG vectors are taken from the CAVP test vectors (`HMAC_DRBG.rsp`,
+//! SHA-256), the CTR_DRBG vectors from `CTR_DRBG.rsp` (AES-256 use df,
+//! prediction resistance, the first two cases of each combination of
+//! personalization string and additional input lengths) and the Hash_DRBG
+//! vectors from `Hash_DRBG.rsp` (SHA-256, without prediction resistance and
+//! reseed, and with prediction resistance, personalization string and
+//! additional input).
+//!
+//! Each vector instantiates the DRBG, optionally reseeds it, and then generates
+//! output twice, checking the output of the second request. With prediction
+//! resistance, every request is preceded by a reseed using `entropy_input_pr`.
+#![cfg(feature = "drbg")]
+
+use rand_core_compat::drbg;
+use rand_core_compat::drbg::CtrAes256;
+use rand_core_compat::drbg::Drbg;
+use rand_core_compat::drbg::HashSha256;
+use rand_core_compat::drbg::HmacSha256;
+use rand_core_compat::drbg::Mechanism;
+use rand_core_compat::error;
+
+struct Vector {
+    entropy_input: &'static str,
+    nonce: &'static str,
+    personalization_string: &'static str,
+    entropy_input_reseed: &'static str,
+    additional_input_reseed: &'static str,
+    additional_input: [&'static str; 2],
+    entropy_input_pr: [&'static str; 2],
+    returned_bits: &'static str,
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn check<M: Mechanism>(vectors: &[Vector]) {
+    for v in vectors {
+        let mut drbg = Drbg::<M>::new(
+            &hex(v.entropy_input),
+            &hex(v.nonce),
+            &hex(v.personalization_string),
+        );
+        if !v.entropy_input_reseed.is_empty() {
+            drbg.reseed(
+                &hex(v.entropy_input_reseed),
+                &hex(v.additional_input_reseed),
+            );
+        }
+        let mut returned_bits = vec![0; v.returned_bits.len() / 2];
+        for i in 0..2 {
+            let additional_input = hex(v.additional_input[i]);
+            if v.entropy_input_pr[i].is_empty() {
+                drbg.generate(&mut returned_bits, &additional_input)
+                    .unwrap();
+            } else {
+                drbg.reseed(&hex(v.entropy_input_pr[i]), &additional_input);
+                drbg.generate(&mut returned_bits, &[]).unwrap();
+            }
+        }
+        assert_eq!(returned_bits, hex(v.returned_bits));
+    }
+}
+
+const HMAC_SHA256: &[Vector] = &[
+    Vector {
+        entropy_input: "ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488",
+        nonce: "659ba96c601dc69fc902940805ec0ca8",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89",
+            "d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1",
+            "07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668",
+            "961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8",
+        ),
+    },
+    Vector {
+        entropy_input: "79737479ba4e7642a221fcfd1b820b134e9e3540a35bb48ffae29c20f5418ea3",
+        nonce: "3593259c092bef4129bc2c6c9e19f343",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "cf5ad5984f9e43917aa9087380dac46e410ddc8a7731859c84e9d0f31bd43655",
+            "b924159413e2293b17610f211e09f770f172b8fb693a35b85d3b9e5e63b1dc25",
+            "2ac0e115002e9bedfb4b5b6fd43f33b8e0eafb2d072e1a6fee1f159df9b51e6c",
+            "8da737e60d5032dd30544ec51558c6f080bdbdab1de8a939e961e06b5f1aca37",
+        ),
+    },
+    Vector {
+        entropy_input: "b340907445b97a8b589264de4a17c0bea11bb53ad72f9f33297f05d2879d898d",
+        nonce: "65cb27735d83c0708f72684ea58f7ee5",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "75183aaaf3574bc68003352ad655d0e9ce9dd17552723b47fab0e84ef903694a",
+            "32987eeddbdc48efd24195dbdac8a46ba2d972f5808f23a869e71343140361f5",
+            "8b243e62722088fe10a98e43372d252b144e00c89c215a76a121734bdc485486",
+            "f65c0b16b8963524a3a70e6f38f169c12f6cbdd169dd48fe4421a235847a23ff",
+        ),
+    },
+    Vector {
+        entropy_input: "d3cc4d1acf3dde0c4bd2290d262337042dc632948223d3a2eaab87da44295fbd",
+        nonce: "0109b0e729f457328aa18569a9224921",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "3c311848183c9a212a26f27f8c6647e40375e466a0857cc39c4e47575d53f1f6",
+            "fcb9abd19ccfbccef88c9c39bfb3dd7b1c12266c9808992e305bc3cff566e4e4",
+        ],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "9c7b758b212cd0fcecd5daa489821712e3cdea4467b560ef5ddc24ab47749a1f",
+            "1ffdbbb118f4e62fcfca3371b8fbfc5b0646b83e06bfbbab5fac30ea09ea2bc7",
+            "6f1ea568c9be0444b2cc90517b20ca825f2d0eccd88e7175538b85d90ab39018",
+            "3ca6395535d34473af6b5a5b88f5a59ee7561573337ea819da0dcc3573a22974",
+        ),
+    },
+    Vector {
+        entropy_input: "f97a3cfd91faa046b9e61b9493d436c4931f604b22f1081521b3419151e8ff06",
+        nonce: "11f3a7d43595357d58120bd1e2dd8aed",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "517289afe444a0fe5ed1a41dbbb5eb17150079bdd31e29cf2ff30034d8268e3b",
+            "88028d29ef80b4e6f0fe12f91d7449fe75062682e89c571440c0c9b52c42a6e0",
+        ],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "c6871cff0824fe55ea7689a52229886730450e5d362da5bf590dcf9acd67fed4",
+            "cb32107df5d03969a66b1f6494fdf5d63d5b4d0d34ea7399a07d0116126d0d51",
+            "8c7c55ba46e12f62efc8fe28a51c9d428e6d371d7397ab319fc73ded4722e5b4",
+            "f30004032a6128df5e7497ecf82ca7b0a50e867ef6728a4f509a8c859087039c",
+        ),
+    },
+    Vector {
+        entropy_input: "0f2f23d64f481cabec7abb01db3aabf125c3173a044b9bf26844300b69dcac8b",
+        nonce: "9a5ae13232b43aa19cfe8d7958b4b590",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "ec4c7a62acab73385f567da10e892ff395a0929f959231a5628188ce0c26e818",
+            "6b97b8c6b6bb8935e676c410c17caa8042aa3145f856d0a32b641e4ae5298648",
+        ],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "7480a361058bd9afa3db82c9d7586e42269102013f6ec5c269b6d05f17987847",
+            "748684766b44918fd4b65e1648622fc0e0954178b0279dfc9fa99b66c6f53e51",
+            "c4860131e9e0644287a4afe4ca8e480417e070db68008a97c3397e4b320b5d1a",
+            "1d7e1d18a95cfedd7d1e74997052bf649d132deb9ec53aae7dafdab55e6dae93",
+        ),
+    },
+    Vector {
+        entropy_input: "13fc8799ab641b4a0a2ef0e0d0a62f2dcc0cf9b37466b261b7445e72f7e1b526",
+        nonce: "e7a76f37eb7ec46972dd3de461ef50a0",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "5461982d603116c0b5ba82b744fff01c725c1eab3aa955c453bf7ec005906d96",
+            "eaf4d198e599bd358fd0aa84738afd6d798a2875b7767cc945b59c4a4c992277",
+            "8c7a9bdec1ef3dc3a6f2453689f404050791cb1d74c9be5fc20b0c9465f9281e",
+            "23896e71d7e7fce1779236fee285f55e86b727c37720eaf21f3a08885e4f2a58",
+        ),
+    },
+    Vector {
+        entropy_input: "1e604c15f0127939e9b50eb9ae586852fcab11451b0b82ea21788b71e429afc5",
+        nonce: "277d93ad464ce4657f95964283b9b8f9",
+        personalization_string: "6651eb617da89fe89678c86de718c8fcf884e2f5f001775f21fa9c1242edd73d",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "86e5c40de958a18aab4e4940711fc2e16608f6a54f8621ac633668851ff334d4",
+            "21322b1e17d6b5ad0407f4d199c78684a8160edf0efeafdad644b5376c8fc683",
+        ],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "a929b75c7331dbd53bc90c2da1ab1ae72b18bfab8337e93c3e590ad46dd4e20d",
+            "6581c9b5fc37432e27b31bfc9cae415dd4fa0fb89e22dc5d0bee8494bc258df3",
+            "7326548d4ec55e1f00400e7d08157e5ce5cfddb619c12f5707d165e87f9e5e81",
+            "afc8eb7941b06278cd5156478a91916a9cb6dac1d6ec65005cdf9952f0a586b2",
+        ),
+    },
+    Vector {
+        entropy_input: "40f589c8304e25070ab8b6bc22ba7c16d07f4c80a5b539a004f92075302a7fe0",
+        nonce: "44b4903a614f9a388d66b6c11bdd0656",
+        personalization_string: "ae0c66614fafd31fbdd6b8ae8271fe007800925ec309e316cb1cf1844e301855",
+        entropy_input_reseed: "d8576c6ae884119e2974017da3a4220e03c8a393bf178b26750ce70eac3bb41e",
+        additional_input_reseed: "647fd193217a914b0556cddba884d869836fe3af82098dedd64bdd48842856e7",
+        additional_input: [
+            "45a6b77370ce95b155f2b776464278534af124d25d862bc4b6b2fcbb68874cd4",
+            "5fd2e1afed8bd13d8d3cf6fa46008b926493e77d92eba255830aaf0c66f702a2",
+        ],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "53bb8f20320b3d03d48e54474daee227279c21d047c0a9ce1ec9f4f002000a0e",
+            "6278a7d5da0194c0cd57bf7a46f4613c70ec00a43f73d542417df2038b22f9d7",
+            "6968034dddb857bbe0b5370faf32d4719d80e0462a9653a24a06a3926eb37301",
+            "f09421e3b1fd0a052e3791f6b50a58ca2f862e2f97de2526f59343cb7cca7859",
+        ),
+    },
+    Vector {
+        entropy_input: "21ae39d2da815a617e9097e48a02540093e52a969399f8ee21e3d33cd25b1e01",
+        nonce: "3594ff9d0db76358da084c627aa66e2a",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: [
+            "39a00b13afa7c517e24fbd1ff1bddda66b3f9a13c1eeb98b9135e13094c705ed",
+            "d167f26aca74d6a3efc556c6f736ddd88ccbbd4b59d38651d7bce4fe822abd57",
+        ],
+        returned_bits: concat!(
+            "199955d16e08a9bfda374aa14002c886def1924948824b686eddaa80d4572ee1",
+            "8d8f6db6d7b290cdb54346145e3c379086308fd8aca1b5404ebeedcc97a81031",
+            "a40aacfe86f5ba13c88e0d2f798e76c1d5f81e7617721b0c97f43ec00bd038e4",
+            "ce9fa9e72e750ea392f386164cd94e6a889b283856925445c00e87624a062dc4",
+        ),
+    },
+];
+
+const HASH_SHA256: &[Vector] = &[
+    // PredictionResistance = False, no reseed, COUNT = 0.
+    Vector {
+        entropy_input: "a65ad0f345db4e0effe875c3a2e71f42c7129d620ff5c119a9ef55f05185e0fb",
+        nonce: "8581f9317517276e06e9607ddbcbcc2e",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: ["", ""],
+        returned_bits: concat!(
+            "d3e160c35b99f340b2628264d1751060e0045da383ff57a57d73a673d2b8d80d",
+            "aaf6a6c35a91bb4579d73fd0c8fed111b0391306828adfed528f018121b3febd",
+            "c343e797b87dbb63db1333ded9d1ece177cfa6b71fe8ab1da46624ed6415e51c",
+            "cde2c7ca86e283990eeaeb91120415528b2295910281b02dd431f4c9f70427df",
+        ),
+    },
+    // PredictionResistance = True, personalization string and additional
+    // input of 256 bits, COUNT = 14.
+    Vector {
+        entropy_input: "066dc8ce75b28966a685163fe2a4d427fbdb616650616ba282fc332b4e6f1220",
+        nonce: "559f7c64897083ec2d7370d9f0e5071f",
+        personalization_string: "886f549aad1ac63d18cbcc6685daa2c2f79eb0894cb4aef1ac544fce57f15e11",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "b7215f14ac7bafd0a91772ba22f719afbd20b311636c2b1e83e4a823353fc6ea",
+            "ced31f7e0dae5bb5c043e246b29473e2fd39512ead4569eee3e3803314aba7a3",
+        ],
+        entropy_input_pr: [
+            "ff80b7d26a05bc8a7abe53286b0eeb733b715a205bfa4ff63703deadb6ea0ef4",
+            "c73832534681ede37e03846d3c841767297d246c689241d2e775be7ec996293d",
+        ],
+        returned_bits: concat!(
+            "60c234cfafb468033bf195e578ce266e1465326a96a9e03f8b893670ef62754d",
+            "5e80d553a1f84950208b9343079f2ef856e9c570618597b5dc82a2daeaa3fd9b",
+            "2fd2a0d71bc62935ccb83da0679805a0e31efee4f0e513b08317faca935e3829",
+            "48d272db763e6df32510ff1b99fff8c60eb0dd292ebcbbc80a016ed3b00e4eab",
+        ),
+    },
+];
+
+const CTR_AES256: &[Vector] = &[
+    Vector {
+        entropy_input: "c18081a65d44021619b3f180b1c920026a546f0c7081498b6ea662526d51b1cb",
+        nonce: "d254fcff021e69d229c9cfad85fa486c",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: [
+            "583bfad5375ffbc9ff46d219c7223e95459d82e1e7229f633169d26b57474fa3",
+            "37c9981c0bfb91314d55b9e91c5a5ee49392cfc52312d5562c4a6effdc10d068",
+        ],
+        returned_bits: "34011656b429008f3563ecb5f2590723",
+    },
+    Vector {
+        entropy_input: "a7f38c750bd6ff41c4e79f5b7dd3024d58ca3f1f4c096486c4a73c4f74a2410c",
+        nonce: "7be87545266dadd1d73546c0927afc8d",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: [
+            "4c9c5143eb8c09df842ba4427f385bbf65c350b0bf2c87242c7a23c8c2e0e419",
+            "e44e500c250f6bc0dc25ec0ce929c4ad5ffb7a87950c618f8cee1af4831b4b8e",
+        ],
+        returned_bits: "d5b1da77f36ce58510b75dfde71dbd5d",
+    },
+    Vector {
+        entropy_input: "545a783ae97d827ed0b81d9752ad0f7e965f511b1f5dae0f872e9ec37cfe63af",
+        nonce: "e09f65dcffc0d3a4d84bacc41617a4e4",
+        personalization_string: "6ce5184eca011049ab657566f728e4aa28315ffac166ebe50e1269b01c95b3a2",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: [
+            "86c1d15e153887989b605773b16ad5505e65f617cfa8ef46547c4c3f9d0c4fd0",
+            "b6e1cff5ca0f1929266fe43ba8f45ad664cfe5e90903a9cb722b42ae8989c148",
+        ],
+        returned_bits: "1e77d7cc18775fef9a3d3e00903da01b",
+    },
+    Vector {
+        entropy_input: "dde6c0850fe642602eb222ca7371213c598cef8c3e71e0593ea8edb54e1bed13",
+        nonce: "056cd44c8847d89da05fbef95e9660d5",
+        personalization_string: "89046b0c02f9b42c17fd8b069f831c73cd896005ec080113589b6f07be6e42ea",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: ["", ""],
+        entropy_input_pr: [
+            "0b9b0aebe0893093b950c52f56eb9b338aa4bd01dae030515726ece1bf751660",
+            "b4a3602da6400e4b94edebba646b5c3d4e64ceea1c4f14b7a19f0142783247df",
+        ],
+        returned_bits: "a790ab939e63555d02ea1e9696051725",
+    },
+    Vector {
+        entropy_input: "82c80d922c47bbec0f664dd623e22a11a3b84d308351e45e30ee286e89547d22",
+        nonce: "14051b57277bc3d3bbae51bdecfb9f5d",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "b70e7c1c4b8e0f1770e05b29a93f9d7a6540f23ab84136b05b161d85e5f19251",
+            "5a737c128bd69f927f8f3ad68f93f6356d5f4ec0e36b6b50ced43dcd5c44dbc2",
+        ],
+        entropy_input_pr: [
+            "c43e17b3ca0fa08f77eef1001ba696932e9ee890e7aac4661c138e5b5ce36773",
+            "d3120c35f8c94e0a78ffbf407a63ca435392e17c07461522fdc1f63f037aacff",
+        ],
+        returned_bits: "a4e6c754194a09614994b36ecce33b55",
+    },
+    Vector {
+        entropy_input: "952f3f179cbbda27ebd30f4fc31bf96baccb2adbaa9c090bc0f37044a44e85b3",
+        nonce: "4526b268128ea35f8558b4e1d08388f2",
+        personalization_string: "",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "6b167c7cebea2e585ab974b60c4d305a113102ca8c3dc87651665728c4c675ad",
+            "a038f1ca1f420eae449791f13be4901bfb91e41e052e02635b1f1817bd8969b1",
+        ],
+        entropy_input_pr: [
+            "bc668cd3533faaf56b5da9242844d65733f7ac1f55c38b175749b88e18d19672",
+            "b7bdab54e0ababdd4519fb07e0c25578f64ad40d0beb0a26275d5e2f4906aa70",
+        ],
+        returned_bits: "745ec376282e20fd1f9151f7040ed94a",
+    },
+    Vector {
+        entropy_input: "6168fc1af0b5956b85099b743f1378493b85ec93133ba94f96ab2ce4c88fdd6a",
+        nonce: "add2bbbab76589c3216c55332b36ffa4",
+        personalization_string: "6ecae72072d3845a32d34b2472c4632b9d12240c23268e8316370bd1064f686d",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "7e084abbe3217cc923d2f8b07398ba847423ab068ae222d37bce9bd24a76b8de",
+            "946bc99fab8dc5ec71881d008c8968e4c8077736176d7978c7064e99042829c3",
+        ],
+        entropy_input_pr: [
+            "0b23afdff162d7d34397f87704a84220bdf60fc1172f9f54bb561786680ebaa9",
+            "bf6c592a0d440fae9a5e0373d8a6e1cf25613824869e53e8a4df56f406079c0f",
+        ],
+        returned_bits: "224ab4b8b6ee7db19ec9f9a0d9e29700",
+    },
+    Vector {
+        entropy_input: "4db8e8a27fe7a0378e37d4cc01b6a465d34be91f48c52fdc1023ef2ea1241082",
+        nonce: "8964ebde61f0c4e23f8e91244ae9682e",
+        personalization_string: "d0b17e424edd4c025b461a2d209a538583f29465df3f89cf04f703b771ff5c90",
+        entropy_input_reseed: "",
+        additional_input_reseed: "",
+        additional_input: [
+            "5eb3fb44784f181852d80fcf7c2e3b8414ae797f7b9b013b59cf86b9d3a19006",
+            "3eec358f7f9e789e4ad5a78dd73987addbf3ae5b06d826cec2d54425289dc9af",
+        ],
+        entropy_input_pr: [
+            "f522805bc8777fda6c10e3d441b58f648edcd7d4df3df8c8a398d7b005c4fd6f",
+            "41c9b033bd38fc5f577069251529b58273f6a9175feb3978798fdeb78a043232",
+        ],
+        returned_bits: "9a66c015d2550e3f78c44b901075fabb",
+    },
+];
+
+#[test]
+fn hmac_sha256() {
+    check::<HmacSha256>(HMAC_SHA256);
+}
+
+#[test]
+fn hash_sha256() {
+    check::<HashSha256>(HASH_SHA256);
+}
+
+#[test]
+fn ctr_aes256() {
+    check::<CtrAes256>(CTR_AES256);
+}
+
+#[test]
+fn reseed_required() {
+    let mut drbg = drbg::HmacDrbg::new(&[0; 32], &[0; 16], &[]);
+    drbg.set_reseed_interval(2);
+    let mut buf = [0; 16];
+    drbg.generate(&mut buf, &[]).unwrap();
+    drbg.generate(&mut buf, &[]).unwrap();
+    let error = drbg.generate(&mut buf, &[]).unwrap_err();
+    assert_eq!(error.code(), error::RESEED_REQUIRED);
+    drbg.reseed(&[1; 32], &[]);
+    drbg.generate(&mut buf, &[]).unwrap();
+    assert_eq!(drbg.reseed_counter(), 2);
+}
+
+#[test]
+fn long_request() {
+    let mut drbg = drbg::HashDrbg::new(&[0; 32], &[0; 16], &[]);
+    drbg.set_reseed_interval(2);
+    let mut buf = vec![0; drbg::MAX_REQUEST_LEN * 2 + 1];
+    let error = drbg.generate(&mut buf, &[]).unwrap_err();
+    assert_eq!(error.code(), error::RESEED_REQUIRED);
+    assert_eq!(drbg.reseed_counter(), 1);
+    drbg.generate(&mut buf[1..], &[]).unwrap();
+    assert_eq!(drbg.reseed_counter(), 3);
+}
+
+#[cfg(feature = "rand_core_0_9")]
+#[test]
+fn prediction_resistant() {
+    /// Entropy source replaying the given bytes.
+    struct Replay(Vec<u8>);
+    impl rand_core_0_9::RngCore for Replay {
+        fn next_u32(&mut self) -> u32 {
+            rand_core_0_9::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core_0_9::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            let rest = self.0.split_off(dst.len());
+            dst.copy_from_slice(&self.0);
+            self.0 = rest;
+        }
+    }
+
+    // `PredictionResistant` doesn't take additional input.
+    for v in CTR_AES256.iter().filter(|v| v.additional_input == ["", ""]) {
+        let drbg = Drbg::<CtrAes256>::new(
+            &hex(v.entropy_input),
+            &hex(v.nonce),
+            &hex(v.personalization_string),
+        );
+        let entropy = [hex(v.entropy_input_pr[0]), hex(v.entropy_input_pr[1])].concat();
+        let mut rng =
+            drbg::PredictionResistant::new(drbg, rand_core_compat::Rng09(Replay(entropy)));
+        let mut returned_bits = vec![0; v.returned_bits.len() / 2];
+        rand_core_0_9::TryRngCore::try_fill_bytes(&mut rng, &mut returned_bits).unwrap();
+        rand_core_0_9::TryRngCore::try_fill_bytes(&mut rng, &mut returned_bits).unwrap();
+        assert_eq!(returned_bits, hex(v.returned_bits));
+    }
+}
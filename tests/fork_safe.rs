@@ -0,0 +1,73 @@
+//! Tests of `ForkSafe` forking actual child processes.
+#![cfg(all(feature = "std", target_os = "linux", feature = "rand_core_0_9"))]
+
+mod common;
+
+use common::Counter;
+use rand_core_0_9::RngCore;
+use rand_core_0_9::TryRngCore;
+use rand_core_compat::ForkSafe;
+
+/// Fork, run `child` in the child process and return the number it wrote.
+fn in_child(child: impl FnOnce() -> u64) -> u64 {
+    let mut fds = [0; 2];
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+    let pid = unsafe { libc::fork() };
+    assert!(pid >= 0);
+    if pid == 0 {
+        let value = child().to_le_bytes();
+        unsafe {
+            libc::write(fds[1], value.as_ptr().cast(), value.len());
+            libc::_exit(0);
+        }
+    }
+    let mut value = [0u8; 8];
+    let mut status = 0;
+    unsafe {
+        libc::close(fds[1]);
+        assert_eq!(
+            libc::read(fds[0], value.as_mut_ptr().cast(), value.len()),
+            8
+        );
+        libc::close(fds[0]);
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+    }
+    assert!(libc::WIFEXITED(status) && libc::WEXITSTATUS(status) == 0);
+    u64::from_le_bytes(value)
+}
+
+#[test]
+fn reseeds_in_child() {
+    let mut rng = ForkSafe::new(Counter(1), Counter(1000));
+    assert_eq!(rng.try_next_u64().unwrap(), 1);
+
+    let child = in_child(|| rng.clone().try_next_u64().unwrap());
+    // The child seeds a new `Counter` from the entropy source, which yields
+    // 1000 as the seed.
+    assert_eq!(child, 1000);
+    assert_eq!(rng.try_next_u64().unwrap(), 2);
+    assert_eq!(rng.inner(), (&Counter(3), &Counter(1000)));
+}
+
+#[test]
+fn reseeds_in_grandchild() {
+    let mut rng = ForkSafe::new(Counter(1), Counter(1000));
+    assert_eq!(rng.try_next_u64().unwrap(), 1);
+
+    let grandchild = in_child(|| {
+        let mut rng = rng.clone();
+        assert_eq!(rng.try_next_u64().unwrap(), 1000);
+        in_child(|| rng.clone().try_next_u64().unwrap())
+    });
+    // The child takes the seed 1000 from its entropy source, so the
+    // grandchild gets the next one.
+    assert_eq!(grandchild, 1001);
+    assert_eq!(rng.try_next_u64().unwrap(), 2);
+}
+
+#[test]
+fn without_fork_safe() {
+    let mut rng = Counter(0);
+    let child = in_child(|| rng.clone().next_u64());
+    assert_eq!(child, rng.next_u64());
+}
@@ -0,0 +1,165 @@
+//! Tests of the Fortuna accumulator and generator.
+//!
+//! The expected output is computed by a straightforward model of the
+//! generator of "Cryptography Engineering", using the `aes` and `sha2` crates.
+#![cfg(all(feature = "fortuna", feature = "rand_core_0_9"))]
+
+use aes::Aes256;
+use aes::cipher::BlockEncrypt;
+use aes::cipher::KeyInit;
+use rand_core_0_9::TryRngCore;
+use rand_core_compat::Fortuna;
+use rand_core_compat::error;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Wait until the next reseed is allowed.
+fn wait() {
+    #[cfg(feature = "std")]
+    std::thread::sleep(std::time::Duration::from_millis(100));
+}
+
+/// Add events of 32 bytes from `count` fresh sources starting at `first`, so
+/// that all of them end up in pool 0.
+fn fill_pool0(rng: &mut Fortuna, first: u8, count: u8) {
+    for source_id in first..first + count {
+        rng.add_entropy(source_id, &[source_id; 32]);
+    }
+}
+
+/// Model of the generator, returning the output of the requests of lengths
+/// `lens` after reseeding from `pool0` with the key and counter being 0.
+fn model(pool0: &[u8], lens: &[usize]) -> Vec<Vec<u8>> {
+    let pool0 = Sha256::digest(Sha256::digest(pool0));
+    let mut key: [u8; 32] = Sha256::digest(Sha256::digest([[0; 32], pool0.into()].concat())).into();
+    let mut counter = 1u128;
+    let mut block = |cipher: &Aes256| {
+        let mut block = counter.to_le_bytes().into();
+        cipher.encrypt_block(&mut block);
+        counter += 1;
+        block
+    };
+    let mut outputs = Vec::new();
+    for &len in lens {
+        let cipher = Aes256::new(&key.into());
+        let mut output = Vec::new();
+        while output.len() < len {
+            output.extend_from_slice(&block(&cipher));
+        }
+        output.truncate(len);
+        outputs.push(output);
+        key = [block(&cipher), block(&cipher)]
+            .concat()
+            .try_into()
+            .unwrap();
+    }
+    outputs
+}
+
+#[test]
+fn not_seeded() {
+    let mut rng = Fortuna::new();
+    let error = rng.try_next_u32().unwrap_err();
+    assert_eq!(error.code(), error::NOT_SEEDED);
+
+    // 62 bytes in pool 0 are not enough.
+    rng.add_entropy(0, &[0; 29]);
+    rng.add_entropy(1, &[0; 29]);
+    assert_eq!(
+        rng.try_fill_bytes(&mut [0; 16]).unwrap_err().code(),
+        error::NOT_SEEDED
+    );
+    assert!(!rng.is_seeded());
+
+    // Neither is any amount in pools 1 to 31.
+    for _ in 1..32 {
+        rng.add_entropy(0, &[0; 32]);
+    }
+    assert_eq!(rng.try_next_u64().unwrap_err().code(), error::NOT_SEEDED);
+    assert_eq!(rng.reseed_count(), 0);
+
+    // Pool 0 reaches 64 bytes.
+    rng.add_entropy(2, &[0; 1]);
+    assert!(rng.try_next_u32().is_ok());
+    assert!(rng.is_seeded());
+    assert_eq!(rng.reseed_count(), 1);
+}
+
+#[test]
+fn rekey_after_request() {
+    let mut rng = Fortuna::new();
+    fill_pool0(&mut rng, 1, 2);
+    let pool0 = [&[1, 32][..], &[1; 32], &[2, 32], &[2; 32]].concat();
+
+    let expected = model(&pool0, &[20, 4, 8, 100]);
+    let mut output = [0; 20];
+    rng.try_fill_bytes(&mut output).unwrap();
+    assert_eq!(output[..], expected[0]);
+    assert_eq!(
+        rng.try_next_u32().unwrap(),
+        u32::from_le_bytes(expected[1][..].try_into().unwrap())
+    );
+    assert_eq!(
+        rng.try_next_u64().unwrap(),
+        u64::from_le_bytes(expected[2][..].try_into().unwrap())
+    );
+    let mut output = [0; 100];
+    rng.try_fill_bytes(&mut output).unwrap();
+    assert_eq!(output[..], expected[3]);
+
+    // Without rekeying, splitting a request wouldn't change the output.
+    let mut rng = Fortuna::new();
+    fill_pool0(&mut rng, 1, 2);
+    let mut output = [0; 16];
+    rng.try_fill_bytes(&mut output).unwrap();
+    rng.try_fill_bytes(&mut output).unwrap();
+    assert_eq!(output[..], model(&pool0, &[16, 16])[1]);
+    assert_ne!(output[..], model(&pool0, &[32])[0][16..]);
+}
+
+#[test]
+fn pool_schedule() {
+    // Pool `i` takes part in every 2^i-th reseed, so events in pool 2 only
+    // change the output from the fourth reseed on.
+    let mut a = Fortuna::new();
+    let mut b = Fortuna::new();
+    for rng in [&mut a, &mut b] {
+        rng.add_entropy(0, &[0; 32]);
+        rng.add_entropy(0, &[1; 32]);
+    }
+    a.add_entropy(0, &[2; 32]);
+    b.add_entropy(0, &[3; 32]);
+
+    for reseed in 1..=8 {
+        wait();
+        for rng in [&mut a, &mut b] {
+            fill_pool0(rng, 2 * reseed, 2);
+        }
+        let output_a = a.try_next_u64().unwrap();
+        let output_b = b.try_next_u64().unwrap();
+        assert_eq!(a.reseed_count(), u32::from(reseed));
+        assert_eq!(output_a == output_b, reseed < 4, "reseed {reseed}");
+    }
+}
+
+#[test]
+fn pool0_required() {
+    let mut rng = Fortuna::new();
+    fill_pool0(&mut rng, 1, 2);
+    rng.try_next_u32().unwrap();
+    assert_eq!(rng.reseed_count(), 1);
+
+    // The first event of source 0 goes to pool 0, the others fill pools 1 to
+    // 31.
+    wait();
+    rng.add_entropy(0, &[0; 1]);
+    for _ in 1..32 {
+        rng.add_entropy(0, &[0; 32]);
+    }
+    rng.try_next_u32().unwrap();
+    assert_eq!(rng.reseed_count(), 1);
+
+    fill_pool0(&mut rng, 3, 2);
+    rng.try_next_u32().unwrap();
+    assert_eq!(rng.reseed_count(), 2);
+}
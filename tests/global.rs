@@ -0,0 +1,91 @@
+//! Tests of the global RNG.
+//!
+//! The global RNG is shared by all tests of this file, so a single test goes
+//! through the whole life cycle.
+#![cfg(all(feature = "critical-section", feature = "rand_core_0_9"))]
+
+mod common;
+
+use common::Counter;
+use rand_core_compat::Rng09;
+use rand_core_compat::error;
+use rand_core_compat::global;
+use rand_core_compat::global::Global;
+
+fn counter(start: u64) -> &'static mut Rng09<Counter> {
+    Box::leak(Box::new(Rng09(Counter(start))))
+}
+
+/// Check the outputs of `Global` through every selected version, starting at
+/// `start`, returning the next output.
+fn check_outputs(start: u64) -> u64 {
+    let mut expected = start;
+    let mut next = || {
+        expected += 1;
+        expected - 1
+    };
+    assert_eq!(
+        rand_core_0_9::TryRngCore::try_next_u64(&mut Global).unwrap(),
+        next()
+    );
+    let mut dst = [0; 8];
+    rand_core_0_9::TryRngCore::try_fill_bytes(&mut Global, &mut dst).unwrap();
+    assert_eq!(dst, next().to_le_bytes());
+    #[cfg(feature = "rand_core_0_5")]
+    assert_eq!(rand_core_0_5::RngCore::next_u32(&mut Global), next() as u32);
+    #[cfg(feature = "rand_core_0_6")]
+    assert_eq!(rand_core_0_6::RngCore::next_u64(&mut Global), next());
+    #[cfg(feature = "rand_core_0_10")]
+    assert_eq!(
+        rand_core_0_10::TryRng::try_next_u32(&mut Global).unwrap(),
+        next() as u32
+    );
+    global::with_rng(|rng| {
+        let a = rand_core_0_9::TryRngCore::try_next_u64(rng).unwrap();
+        let b = rand_core_0_9::TryRngCore::try_next_u64(rng).unwrap();
+        assert_eq!((a, b), (next(), next()));
+    });
+    next()
+}
+
+#[test]
+fn life_cycle() {
+    // Nothing is installed yet.
+    let code = global::with_rng(|rng| {
+        rand_core_0_9::TryRngCore::try_next_u32(rng)
+            .unwrap_err()
+            .code()
+    });
+    assert_eq!(code, error::NO_GLOBAL_RNG);
+    #[cfg(feature = "rand_core_0_5")]
+    assert_eq!(
+        rand_core_0_5::RngCore::try_fill_bytes(&mut Global, &mut [0; 4])
+            .unwrap_err()
+            .code(),
+        Some(error::NO_GLOBAL_RNG)
+    );
+    #[cfg(feature = "rand_core_0_6")]
+    assert_eq!(
+        rand_core_0_6::RngCore::try_fill_bytes(&mut Global, &mut [0; 4])
+            .unwrap_err()
+            .code(),
+        Some(error::NO_GLOBAL_RNG)
+    );
+    #[cfg(feature = "rand_core_0_10")]
+    assert_eq!(
+        rand_core_0_10::TryRng::try_next_u64(&mut Global)
+            .unwrap_err()
+            .code(),
+        error::NO_GLOBAL_RNG
+    );
+
+    assert!(global::install(counter(0)).is_none());
+    let next = check_outputs(0);
+
+    // A second install replaces the RNG and returns the previous one.
+    let previous = global::install(counter(1000)).unwrap();
+    check_outputs(1000);
+    let mut dst = [0; 8];
+    previous.try_fill_bytes(&mut dst).unwrap();
+    assert_eq!(dst, next.to_le_bytes());
+}
@@ -0,0 +1,206 @@
+//! Tests of the continuous health tests of `HealthTested`.
+//!
+//! The sources are wrapped in `Rng09`, so that the health tested RNG can be
+//! used through the fallible API of every selected version.
+#![cfg(feature = "rand_core_0_9")]
+
+use core::num::NonZeroU32;
+use rand_core_0_9::RngCore;
+use rand_core_compat::HealthTested;
+use rand_core_compat::Rng09;
+use rand_core_compat::error;
+
+/// Non-random source returning `stuck` copies of the byte 0xaa, followed by
+/// the repeated bytes 0 to 255, or, if `biased`, by the bytes 0, 1, 0, 2, 0,
+/// 3, ..., i.e. every other byte being 0.
+#[derive(Clone, Debug)]
+struct Source {
+    stuck: usize,
+    biased: bool,
+    pos: usize,
+}
+
+impl Source {
+    fn stuck(stuck: usize) -> Rng09<Source> {
+        Rng09(Source {
+            stuck,
+            biased: false,
+            pos: 0,
+        })
+    }
+
+    fn biased() -> Rng09<Source> {
+        Rng09(Source {
+            stuck: 0,
+            biased: true,
+            pos: 0,
+        })
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        let pos = self.pos;
+        self.pos += 1;
+        if pos < self.stuck {
+            0xaa
+        } else if self.biased {
+            if pos % 2 == 0 {
+                0
+            } else {
+                (pos / 2 % 255 + 1) as u8
+            }
+        } else {
+            (pos - self.stuck) as u8
+        }
+    }
+}
+
+impl RngCore for Source {
+    fn next_u32(&mut self) -> u32 {
+        rand_core_0_9::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core_0_9::impls::next_u64_via_fill(self)
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for byte in dst {
+            *byte = self.next_byte();
+        }
+    }
+}
+
+/// Fill `dst` using the `rand_core 0.9`/`rand 0.9` fallible API, returning
+/// the error code on failure.
+fn fill09(rng: &mut HealthTested<Rng09<Source>>, dst: &mut [u8]) -> Result<(), NonZeroU32> {
+    rand_core_0_9::TryRngCore::try_fill_bytes(rng, dst).map_err(|e| e.code())
+}
+
+#[test]
+fn repetition_count_test() {
+    // With 8 bits of min-entropy per byte, the cutoff is 4 repetitions.
+    let mut rng = HealthTested::new(Source::stuck(4), 8);
+    let mut dst = [0xff; 3];
+    assert_eq!(fill09(&mut rng, &mut dst), Ok(()));
+    assert_eq!(dst, [0xaa; 3]);
+    let mut dst = [0xff; 1];
+    assert_eq!(fill09(&mut rng, &mut dst), Err(error::HEALTH_TEST_FAILED));
+    assert_eq!(dst, [0]);
+    assert!(rng.is_failed());
+
+    // Three repetitions pass.
+    let mut rng = HealthTested::new(Source::stuck(3), 8);
+    assert_eq!(fill09(&mut rng, &mut [0; 1000]), Ok(()));
+
+    // The cutoff for 1 bit of min-entropy per byte is 21 repetitions.
+    let mut rng = HealthTested::new(Source::stuck(20), 1);
+    assert_eq!(fill09(&mut rng, &mut [0; 20]), Ok(()));
+    let mut rng = HealthTested::new(Source::stuck(21), 1);
+    assert_eq!(fill09(&mut rng, &mut [0; 20]), Ok(()));
+    assert_eq!(
+        fill09(&mut rng, &mut [0; 1]),
+        Err(error::HEALTH_TEST_FAILED)
+    );
+}
+
+#[test]
+fn adaptive_proportion_test() {
+    // With 8 bits of min-entropy per byte, the cutoff is 13 occurrences of
+    // the first byte of the window. The 13th 0 is the 25th byte.
+    let mut rng = HealthTested::new(Source::biased(), 8);
+    assert_eq!(fill09(&mut rng, &mut [0; 24]), Ok(()));
+    assert_eq!(
+        fill09(&mut rng, &mut [0; 1]),
+        Err(error::HEALTH_TEST_FAILED)
+    );
+
+    // The cutoff for 4 bits of min-entropy per byte is 62 occurrences, still
+    // within the first window of 512 bytes.
+    let mut rng = HealthTested::new(Source::biased(), 4);
+    assert_eq!(fill09(&mut rng, &mut [0; 122]), Ok(()));
+    assert_eq!(
+        fill09(&mut rng, &mut [0; 1]),
+        Err(error::HEALTH_TEST_FAILED)
+    );
+
+    // Every byte occurs twice per window of unbiased output.
+    let mut rng = HealthTested::new(Source::stuck(0), 8);
+    assert_eq!(fill09(&mut rng, &mut [0; 10000]), Ok(()));
+}
+
+#[test]
+fn latched_failure() {
+    let mut rng = HealthTested::new(Source::stuck(4), 8);
+    assert_eq!(
+        fill09(&mut rng, &mut [0; 4]),
+        Err(error::HEALTH_TEST_FAILED)
+    );
+    // The source recovered, but the failure stays until reset.
+    for _ in 0..3 {
+        let mut dst = [0xff; 16];
+        assert_eq!(fill09(&mut rng, &mut dst), Err(error::HEALTH_TEST_FAILED));
+        assert!(rng.is_failed());
+    }
+    rng.reset();
+    assert!(!rng.is_failed());
+    let mut dst = [0; 4];
+    assert_eq!(fill09(&mut rng, &mut dst), Ok(()));
+    assert_ne!(dst, [0; 4]);
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+
+    let mut rng = HealthTested::new(Source::stuck(4), 8);
+    assert!(rng.try_fill_bytes(&mut [0; 3]).is_ok());
+    for _ in 0..2 {
+        let error = rng.try_fill_bytes(&mut [0; 1]).unwrap_err();
+        assert_eq!(error.code(), Some(error::HEALTH_TEST_FAILED));
+    }
+    rng.reset();
+    assert!(rng.try_fill_bytes(&mut [0; 8]).is_ok());
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+
+    let mut rng = HealthTested::new(Source::stuck(4), 8);
+    assert!(rng.try_fill_bytes(&mut [0; 3]).is_ok());
+    for _ in 0..2 {
+        let error = rng.try_fill_bytes(&mut [0; 1]).unwrap_err();
+        assert_eq!(error.code(), Some(error::HEALTH_TEST_FAILED));
+    }
+    rng.reset();
+    assert!(rng.try_fill_bytes(&mut [0; 8]).is_ok());
+}
+
+#[test]
+fn rand_core_0_9() {
+    use rand_core_0_9::TryRngCore;
+
+    let mut rng = HealthTested::new(Source::stuck(4), 8);
+    assert!(rng.try_fill_bytes(&mut [0; 3]).is_ok());
+    for _ in 0..2 {
+        let error = rng.try_next_u32().unwrap_err();
+        assert_eq!(error.code(), error::HEALTH_TEST_FAILED);
+    }
+    rng.reset();
+    assert!(rng.try_next_u64().is_ok());
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::TryRng;
+
+    let mut rng = HealthTested::new(Source::stuck(4), 8);
+    assert!(rng.try_fill_bytes(&mut [0; 3]).is_ok());
+    for _ in 0..2 {
+        let error = rng.try_next_u32().unwrap_err();
+        assert_eq!(error.code(), error::HEALTH_TEST_FAILED);
+    }
+    rng.reset();
+    assert!(rng.try_next_u64().is_ok());
+}
@@ -0,0 +1,329 @@
+//! Test vectors for the generators of `rand 0.5`/`rand 0.6`.
+//!
+//! The vectors were generated using `rand 0.5.6` (`rand::prng`) and checked
+//! against `rand_isaac 0.1.1`, `rand_hc 0.1.0` and `rand_xorshift 0.1.1`, the
+//! crates behind `rand 0.6.5`. The `Hc128Rng` vector seeded with `SEED` is
+//! also the output of `StdRng` of both `rand` versions.
+//!
+//! Each vector seeds the RNG and calls `next_u32`, `next_u64` and `fill_bytes`
+//! in a fixed order, covering partially used words and the block boundaries of
+//! the block RNGs. The output of the large `fill_bytes` call is only checked
+//! for its last 16 bytes.
+#![cfg(feature = "legacy_algorithms")]
+
+use rand_core_compat::legacy::Hc128Rng;
+use rand_core_compat::legacy::Isaac64Rng;
+use rand_core_compat::legacy::IsaacRng;
+use rand_core_compat::legacy::XorShiftRng;
+
+const SEED: [u8; 32] = [
+    1, 0, 0, 0, 23, 0, 0, 0, 200, 1, 0, 0, 210, 30, 0, 0, 0x2a, 0x3b, 0x4c, 0x5d, 0x6e, 0x7f, 0x80,
+    0x91, 0xa2, 0xb3, 0xc4, 0xd5, 0xe6, 0xf7, 0x08, 0x19,
+];
+const STATE: u64 = 0x0123_4567_89ab_cdef;
+
+#[derive(Clone, Copy)]
+enum Algorithm {
+    Isaac,
+    Isaac64,
+    Hc128,
+    XorShift,
+}
+
+/// How the RNG is seeded: `from_seed` with `SEED`, truncated to the seed size,
+/// or with zeros, or `seed_from_u64` with `STATE`.
+#[derive(Clone, Copy)]
+enum Seed {
+    Bytes,
+    Zero,
+    U64,
+}
+
+struct Vector {
+    algorithm: Algorithm,
+    seed: Seed,
+    fill_len: usize,
+    output: &'static str,
+}
+
+const VECTORS: &[Vector] = &[
+    Vector {
+        algorithm: Algorithm::Isaac,
+        seed: Seed::Bytes,
+        fill_len: 964,
+        output: concat!(
+            "d40d5e931810b5be4ebc57ce9c4a6e8e95d088dfab24ea74a2988ac3eda17275",
+            "50330055e9e50f6cfce8323ce84ba364da61fd9bc964e5025569c40e798242be",
+            "732ff5d4880e4c7f3f2c5b1f5b3fd8b01a7f317d2d2606856094888bd2205ef0",
+            "2137d4df2ceacbc8eba34269d4da628678adde747d05122c76f4640c4b2ba4",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::Isaac,
+        seed: Seed::U64,
+        fill_len: 964,
+        output: concat!(
+            "4cf2d25c3da45454ec8604d97ae76741a391d881073c488ca7a2de134870f1ae",
+            "ee644539041651e914355b5eb090dc3c36cb0f0b3d547dbf96d6fbb33e7ee40d",
+            "dfb32dfbfcf51ac9739c1379878d8366690944b1ae4e442e3017ee840f35bbe8",
+            "312467be4423d920da353b9f2b90bf68295541800a8e979e0d11cadf5a0c99",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::Isaac64,
+        seed: Seed::Bytes,
+        fill_len: 1976,
+        output: concat!(
+            "b443d971e42cd5d5447e0586f4a60a5854ffe515d571520c0575f52ee8b13d7f",
+            "f7eec12184378c9ed29e7cc08c1880ef5b9d1c035cf2b54b4e582f928e54ec6f",
+            "3883b4fbe245207e5ff9aad8195bad837c2461212a2c1000b1844e2b269335a5",
+            "073b9ae535017d03c12bbb8214d83b67b7dd18a2f2b5f792a0e676d2022c83",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::Isaac64,
+        seed: Seed::U64,
+        fill_len: 1976,
+        output: concat!(
+            "4846d26a0ef42cc6e2d9464a26921dec2c2a1661a785969f49b5aad93c8e75db",
+            "8d3e703553de9dd2821ef43e7daab374d429d803548e4806f0ef96b4618511f5",
+            "a6854b1d4ee4aa25c32bd53d686e73377ecd65686a9cd76f3b06aa1f91a215e3",
+            "752f1ffab8503e6f88aeb3592411c1cfc4cccc2301a7de4012a08e60594dd2",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::Hc128,
+        seed: Seed::Bytes,
+        fill_len: 68,
+        output: concat!(
+            "322f4e2999f0fd7874a1ec16c8317a8638caf925e62ffe611be9321da57ef2d7",
+            "ebb33dfd4dd178c0022e0527e715b66e3c2e5d56163ea5cc0d7ad8b3b2df61b1",
+            "47e65a1caa42464168fca5c2d2c070ed1c31070813db817d0e5edd83ebd60bb5",
+            "5688351426dd312829a85f1e58fa268b71a09e6249f5394a26c9588e94b132",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::Hc128,
+        seed: Seed::U64,
+        fill_len: 68,
+        output: concat!(
+            "66963b7d8139b699a9d090bb233deb6b322388b0670c02bd4f92ab91748e270e",
+            "1a786d93bc691be8708ebc2e39c40300913faa126080812023003a6688e40567",
+            "7de381365710a19abcda99f5a53bfc189505b7a65d66c170f8d94ea23ecef62d",
+            "200a348b4715ec205e107b53724ad72fd070130a13b3641452bc7a8eb3f8bd",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::XorShift,
+        seed: Seed::Bytes,
+        fill_len: 68,
+        output: concat!(
+            "db16000074ae0000fde10e00a099f800aae14e00d99c38058a944e72fa36bfb6",
+            "36d28bc18a409400d66e780605803f4976f1ba3b6b887410846b3c4fe1c785d9",
+            "652d98d5f43bfba6a4109b12e367214a4997d291922ef6662d05e8073c64f698",
+            "e6d19ec955e91c2f2546dd84be150a787ce11bd025f04d1c1e73a128753506",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::XorShift,
+        seed: Seed::U64,
+        fill_len: 68,
+        output: concat!(
+            "37b6a10fe99dbe870b1eecfb2e270ddc96221fde69bd3dad83f2ba362d973b83",
+            "4e1e17a4502981e4a9f14e05d28c935aa00c6a94664cab336d498394262b9147",
+            "3b9c433bbb654b91678d83f2ee5a4bdda2bee9b260648f202f3d674b708cfbad",
+            "8d38e70800f54b79623fc5bd5ca95d40e7df7ed35dc99db9c8dba3cb2480b4",
+        ),
+    },
+    Vector {
+        algorithm: Algorithm::XorShift,
+        seed: Seed::Zero,
+        fill_len: 68,
+        output: concat!(
+            "4333966aca52ad0b643f966aed5ead0bf0787ad0375b20b1683f966ae15ead0b",
+            "9c1a13084a0159bb3604ef609c04d401c03983eb29a3e2df83dfb8be56e3d74e",
+            "b03b95bd8df117e735745391cfef9ec2a51ed6d946194d909930bfa05a435f3f",
+            "d4507ce27af8fb14803fc0e954ec854799e77f33d3f047f9c4f37b729c5f9c",
+        ),
+    },
+];
+
+/// The RNG methods, implemented using the traits of one version.
+trait Generator {
+    fn next_u32(&mut self) -> u32;
+    fn next_u64(&mut self) -> u64;
+    fn fill_bytes(&mut self, dst: &mut [u8]);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn record(rng: &mut dyn Generator, fill_len: usize) -> String {
+    let mut output = Vec::new();
+    for _ in 0..5 {
+        output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    }
+    for _ in 0..3 {
+        output.extend_from_slice(&rng.next_u64().to_le_bytes());
+    }
+    let mut bytes = [0; 7];
+    rng.fill_bytes(&mut bytes);
+    output.extend_from_slice(&bytes);
+    output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    let mut bytes = vec![0; fill_len];
+    rng.fill_bytes(&mut bytes);
+    output.extend_from_slice(&bytes[fill_len - 16..]);
+    for _ in 0..4 {
+        output.extend_from_slice(&rng.next_u64().to_le_bytes());
+    }
+    output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    output.extend_from_slice(&rng.next_u64().to_le_bytes());
+    for _ in 0..3 {
+        output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    }
+    hex(&output)
+}
+
+fn check(new: impl Fn(Algorithm, Seed) -> Box<dyn Generator>) {
+    for (i, v) in VECTORS.iter().enumerate() {
+        let mut rng = new(v.algorithm, v.seed);
+        assert_eq!(record(&mut *rng, v.fill_len), v.output, "vector {}", i);
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+    use rand_core_0_5::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: RngCore> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|algorithm, seed| match (algorithm, seed) {
+        (Algorithm::Isaac, Seed::U64) => Box::new(Wrap(IsaacRng::seed_from_u64(STATE))),
+        (Algorithm::Isaac, _) => Box::new(Wrap(IsaacRng::from_seed(SEED))),
+        (Algorithm::Isaac64, Seed::U64) => Box::new(Wrap(Isaac64Rng::seed_from_u64(STATE))),
+        (Algorithm::Isaac64, _) => Box::new(Wrap(Isaac64Rng::from_seed(SEED))),
+        (Algorithm::Hc128, Seed::U64) => Box::new(Wrap(Hc128Rng::seed_from_u64(STATE))),
+        (Algorithm::Hc128, _) => Box::new(Wrap(Hc128Rng::from_seed(SEED))),
+        (Algorithm::XorShift, Seed::U64) => Box::new(Wrap(XorShiftRng::seed_from_u64(STATE))),
+        (Algorithm::XorShift, Seed::Zero) => Box::new(Wrap(XorShiftRng::from_seed([0; 16]))),
+        (Algorithm::XorShift, _) => {
+            Box::new(Wrap(XorShiftRng::from_seed(SEED[..16].try_into().unwrap())))
+        }
+    });
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+    use rand_core_0_6::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: RngCore> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|algorithm, seed| match (algorithm, seed) {
+        (Algorithm::Isaac, Seed::U64) => Box::new(Wrap(IsaacRng::seed_from_u64(STATE))),
+        (Algorithm::Isaac, _) => Box::new(Wrap(IsaacRng::from_seed(SEED))),
+        (Algorithm::Isaac64, Seed::U64) => Box::new(Wrap(Isaac64Rng::seed_from_u64(STATE))),
+        (Algorithm::Isaac64, _) => Box::new(Wrap(Isaac64Rng::from_seed(SEED))),
+        (Algorithm::Hc128, Seed::U64) => Box::new(Wrap(Hc128Rng::seed_from_u64(STATE))),
+        (Algorithm::Hc128, _) => Box::new(Wrap(Hc128Rng::from_seed(SEED))),
+        (Algorithm::XorShift, Seed::U64) => Box::new(Wrap(XorShiftRng::seed_from_u64(STATE))),
+        (Algorithm::XorShift, Seed::Zero) => Box::new(Wrap(XorShiftRng::from_seed([0; 16]))),
+        (Algorithm::XorShift, _) => {
+            Box::new(Wrap(XorShiftRng::from_seed(SEED[..16].try_into().unwrap())))
+        }
+    });
+}
+
+#[cfg(feature = "rand_core_0_9")]
+#[test]
+fn rand_core_0_9() {
+    use rand_core_0_9::RngCore;
+    use rand_core_0_9::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: RngCore> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|algorithm, seed| match (algorithm, seed) {
+        (Algorithm::Isaac, Seed::U64) => Box::new(Wrap(IsaacRng::seed_from_u64(STATE))),
+        (Algorithm::Isaac, _) => Box::new(Wrap(IsaacRng::from_seed(SEED))),
+        (Algorithm::Isaac64, Seed::U64) => Box::new(Wrap(Isaac64Rng::seed_from_u64(STATE))),
+        (Algorithm::Isaac64, _) => Box::new(Wrap(Isaac64Rng::from_seed(SEED))),
+        (Algorithm::Hc128, Seed::U64) => Box::new(Wrap(Hc128Rng::seed_from_u64(STATE))),
+        (Algorithm::Hc128, _) => Box::new(Wrap(Hc128Rng::from_seed(SEED))),
+        (Algorithm::XorShift, Seed::U64) => Box::new(Wrap(XorShiftRng::seed_from_u64(STATE))),
+        (Algorithm::XorShift, Seed::Zero) => Box::new(Wrap(XorShiftRng::from_seed([0; 16]))),
+        (Algorithm::XorShift, _) => {
+            Box::new(Wrap(XorShiftRng::from_seed(SEED[..16].try_into().unwrap())))
+        }
+    });
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::Rng;
+    use rand_core_0_10::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: Rng> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|algorithm, seed| match (algorithm, seed) {
+        (Algorithm::Isaac, Seed::U64) => Box::new(Wrap(IsaacRng::seed_from_u64(STATE))),
+        (Algorithm::Isaac, _) => Box::new(Wrap(IsaacRng::from_seed(SEED))),
+        (Algorithm::Isaac64, Seed::U64) => Box::new(Wrap(Isaac64Rng::seed_from_u64(STATE))),
+        (Algorithm::Isaac64, _) => Box::new(Wrap(Isaac64Rng::from_seed(SEED))),
+        (Algorithm::Hc128, Seed::U64) => Box::new(Wrap(Hc128Rng::seed_from_u64(STATE))),
+        (Algorithm::Hc128, _) => Box::new(Wrap(Hc128Rng::from_seed(SEED))),
+        (Algorithm::XorShift, Seed::U64) => Box::new(Wrap(XorShiftRng::seed_from_u64(STATE))),
+        (Algorithm::XorShift, Seed::Zero) => Box::new(Wrap(XorShiftRng::from_seed([0; 16]))),
+        (Algorithm::XorShift, _) => {
+            Box::new(Wrap(XorShiftRng::from_seed(SEED[..16].try_into().unwrap())))
+        }
+    });
+}
@@ -0,0 +1,196 @@
+//! Test vectors for the distributions of the `legacy` module.
+//!
+//! The vectors were generated using `rand_distr 0.2.2`, `rand_distr 0.4.3`,
+//! `rand_distr 0.5.1` and `rand_distr 0.6.0` with their default features, with
+//! `XorShiftRng` seeded with `SEED` as the RNG. Each vector is the output of
+//! the calls in `outputs`, with integers converted to `f64`s.
+#![cfg(all(feature = "legacy_algorithms", feature = "std"))]
+
+mod common;
+
+use common::legacy::Vector;
+use rand_core_compat::legacy::RandVersion;
+use rand_core_compat::legacy::WrappedRng;
+use rand_core_compat::legacy::distr;
+use rand_core_compat::legacy::distr::Binomial;
+use rand_core_compat::legacy::distr::Exp;
+use rand_core_compat::legacy::distr::Gamma;
+use rand_core_compat::legacy::distr::Normal;
+use rand_core_compat::legacy::distr::Poisson;
+
+const VECTORS: &[Vector<f64>] = &[
+    Vector {
+        version: RandVersion::V0_7,
+        output: &[
+            0.8450664105396486,
+            -0.5957927483370282,
+            1.8847333577787548,
+            1.1725257347741804,
+            4.094264423498423,
+            3.1755707536143127,
+            0.3500851480975555,
+            1.1165235624092171,
+            1.5016367923094345,
+            0.0002470578706539393,
+            4.6883244448058194,
+            12.15049592007101,
+            9.69506040195523,
+            4.0,
+            5.0,
+            38.0,
+            54.0,
+            10066.0,
+            3.0,
+            2.0,
+            597.0,
+            591.0,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_8,
+        output: &[
+            0.8450664105396486,
+            -0.5957927483370282,
+            1.8847333577787548,
+            1.1725257347741804,
+            4.094264423498423,
+            3.1755707536143127,
+            0.3500851480975555,
+            1.1165235624092171,
+            1.5016367923094345,
+            0.0002470578706539393,
+            4.6883244448058194,
+            12.15049592007101,
+            9.69506040195523,
+            4.0,
+            5.0,
+            38.0,
+            54.0,
+            10066.0,
+            3.0,
+            2.0,
+            597.0,
+            591.0,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_9,
+        output: &[
+            0.8450664105396486,
+            -0.5957927483370282,
+            1.8847333577787548,
+            1.1725257347741804,
+            4.094264423498423,
+            3.1755707536143127,
+            0.3500851480975555,
+            1.1165235624092171,
+            1.5016367923094345,
+            0.0002470578706539393,
+            4.6883244448058194,
+            12.15049592007101,
+            9.69506040195523,
+            4.0,
+            5.0,
+            54.0,
+            66.0,
+            10057.0,
+            4.0,
+            4.0,
+            582.0,
+            621.0,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_10,
+        output: &[
+            0.8450664105396486,
+            -0.5957927483370282,
+            1.8847333577787548,
+            1.1725257347741804,
+            4.094264423498423,
+            3.1755707536143127,
+            0.3500851480975555,
+            1.1165235624092171,
+            1.5016367923094343,
+            0.0002470578706539393,
+            4.6883244448058194,
+            12.15049592007101,
+            9.69506040195523,
+            4.0,
+            5.0,
+            54.0,
+            66.0,
+            10057.0,
+            4.0,
+            4.0,
+            582.0,
+            621.0,
+        ],
+    },
+];
+
+fn outputs(version: RandVersion, rng: &mut dyn WrappedRng) -> Vec<f64> {
+    let mut output = Vec::new();
+    for _ in 0..2 {
+        output.push(distr::standard_normal(version, rng));
+    }
+    for _ in 0..2 {
+        output.push(distr::exp1(version, rng));
+    }
+    let d = Normal::new(version, 3.0, 2.5).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng)));
+    let d = Exp::new(version, 0.3).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng)));
+    let d = Gamma::new(version, 0.5, 2.0).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng)));
+    let d = Gamma::new(version, 1.0, 2.0).unwrap();
+    output.push(d.sample(rng));
+    let d = Gamma::new(version, 2.5, 2.0).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng)));
+    let d = Poisson::new(version, 5.0).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng)));
+    let d = Poisson::new(version, 50.0).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng)));
+    let d = Poisson::new(version, 1e4).unwrap();
+    output.push(d.sample(rng));
+    let d = Binomial::new(version, 10, 0.3).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng) as f64));
+    let d = Binomial::new(version, 1000, 0.6).unwrap();
+    output.extend((0..2).map(|_| d.sample(rng) as f64));
+    output
+}
+
+#[test]
+fn vectors() {
+    common::legacy::check(VECTORS, outputs);
+}
+
+#[test]
+fn invalid_parameters() {
+    use rand_core_compat::legacy::distr::ExpError;
+    use rand_core_compat::legacy::distr::GammaError;
+    use rand_core_compat::legacy::distr::NormalError;
+    use rand_core_compat::legacy::distr::PoissonError;
+
+    let versions = common::legacy::VERSIONS;
+    let exp: Vec<_> = versions.iter().map(|&v| Exp::new(v, -0.0).err()).collect();
+    let none = None;
+    let lambda = Some(ExpError::LambdaTooSmall);
+    assert_eq!(exp, [lambda, none, none, lambda]);
+    let normal: Vec<_> = versions
+        .iter()
+        .map(|&v| Normal::new(v, 0.0, -1.0).err())
+        .collect();
+    assert_eq!(normal, [Some(NormalError::BadVariance), None, None, None]);
+    let gamma: Vec<_> = versions
+        .iter()
+        .map(|&v| Gamma::new(v, 1.0, f64::INFINITY).err())
+        .collect();
+    assert_eq!(gamma, [Some(GammaError::ScaleTooLarge), None, None, None]);
+    let poisson: Vec<_> = versions
+        .iter()
+        .map(|&v| Poisson::new(v, f64::INFINITY).err())
+        .collect();
+    let non_finite = Some(PoissonError::NonFinite);
+    assert_eq!(poisson, [None, None, non_finite, non_finite]);
+}
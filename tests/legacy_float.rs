@@ -0,0 +1,251 @@
+//! Test vectors for the floating-point sampling of the `legacy` module.
+//!
+//! The vectors were generated using `rand 0.7.3`, `rand 0.8.8`, `rand 0.9.5`
+//! and `rand 0.10.3`, with `XorShiftRng` seeded with `SEED` as the RNG. Each
+//! vector is the output of the calls in `outputs`, with `f32`s converted to
+//! `f64`s and booleans to 0 or 1.
+#![cfg(feature = "legacy_algorithms")]
+
+mod common;
+
+use common::legacy::Vector;
+use rand_core_compat::legacy::RandVersion;
+use rand_core_compat::legacy::WrappedRng;
+use rand_core_compat::legacy::bernoulli;
+use rand_core_compat::legacy::float;
+use rand_core_compat::legacy::uniform;
+use rand_core_compat::legacy::uniform::Uniform;
+
+const VECTORS: &[Vector<f64>] = &[
+    Vector {
+        version: RandVersion::V0_7,
+        output: &[
+            0.6456958651542664,
+            0.5127612039558326,
+            0.3118273615837097,
+            0.9459589683763178,
+            0.32525765895843506,
+            0.041895725417915974,
+            -1513374790.21593,
+            0.25896635104922755,
+            -2.4596924781799316,
+            1.4286718368530273,
+            100.13278554069106,
+            -0.009896636009216309,
+            0.0,
+            0.0,
+            0.0,
+            0.6802036762237549,
+            0.14948744132574376,
+            0.014797031879425049,
+            0.06653362108401029,
+            0.06720077991485596,
+            0.7151281990512518,
+            -6659149412.543034,
+            0.7967104671787684,
+            0.7346432209014893,
+            1.1179827451705933,
+            100.13501817965343,
+            0.7840981483459473,
+            0.0,
+            0.0,
+            1.0,
+            0.8158726692199707,
+            0.7607646883734706,
+            0.11551696062088013,
+            0.5057554298884411,
+            0.2841389775276184,
+            0.38466137641949605,
+            -4670244501.977441,
+            0.5892733461781724,
+            7.200549125671387,
+            1.4963178634643555,
+            100.11987620639971,
+            0.2066713571548462,
+            0.0,
+            0.0,
+            0.0,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_8,
+        output: &[
+            0.6456958651542664,
+            0.5127612039558326,
+            0.3118273615837097,
+            0.9459589683763178,
+            0.32525765895843506,
+            0.041895725417915974,
+            -1513374790.21593,
+            0.25896635104922755,
+            -2.4596924781799316,
+            1.4286718368530273,
+            100.13278554069106,
+            -0.009896636009216309,
+            0.0,
+            0.0,
+            0.0,
+            0.6802036762237549,
+            0.14948744132574376,
+            0.014797031879425049,
+            0.06653362108401029,
+            0.06720077991485596,
+            0.7151281990512518,
+            -6659149412.543034,
+            0.7967104671787684,
+            0.7346432209014893,
+            1.1179827451705933,
+            100.13501817965343,
+            0.7840981483459473,
+            0.0,
+            0.0,
+            1.0,
+            0.8158726692199707,
+            0.7607646883734706,
+            0.11551696062088013,
+            0.5057554298884411,
+            0.2841389775276184,
+            0.38466137641949605,
+            -4670244501.977441,
+            0.5892733461781724,
+            7.200549125671387,
+            1.4963178634643555,
+            100.11987620639971,
+            0.2066713571548462,
+            0.0,
+            0.0,
+            0.0,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_9,
+        output: &[
+            0.6456958651542664,
+            0.5127612039558326,
+            0.3118273615837097,
+            0.9459589683763178,
+            0.32525765895843506,
+            0.041895725417915974,
+            -1513374790.21593,
+            0.2589663510492275,
+            -2.4596924781799316,
+            1.4286718368530273,
+            100.13278554069106,
+            -0.009896636009216309,
+            0.0,
+            0.0,
+            0.0,
+            0.6802036762237549,
+            0.14948744132574376,
+            0.014797031879425049,
+            0.06653362108401029,
+            0.06720077991485596,
+            0.7151281990512518,
+            -6659149412.543034,
+            0.7967104671787681,
+            0.7346432209014893,
+            1.1179826259613037,
+            100.13501817965344,
+            0.7840981483459473,
+            0.0,
+            0.0,
+            1.0,
+            0.8158726692199707,
+            0.7607646883734706,
+            0.11551696062088013,
+            0.5057554298884411,
+            0.2841389775276184,
+            0.38466137641949605,
+            -4670244501.977441,
+            0.5892733461781723,
+            7.200549125671387,
+            1.4963178634643555,
+            100.11987620639972,
+            0.2066713571548462,
+            0.0,
+            0.0,
+            0.0,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_10,
+        output: &[
+            0.6456958651542664,
+            0.5127612039558326,
+            0.3118273615837097,
+            0.9459589683763178,
+            0.32525765895843506,
+            0.041895725417915974,
+            -1513374790.21593,
+            0.2589663510492275,
+            -2.4596924781799316,
+            1.4286718368530273,
+            100.13278554069106,
+            -0.009896636009216309,
+            0.0,
+            0.0,
+            0.0,
+            0.6802036762237549,
+            0.14948744132574376,
+            0.014797031879425049,
+            0.06653362108401029,
+            0.06720077991485596,
+            0.7151281990512518,
+            -6659149412.543034,
+            0.7967104671787681,
+            0.7346432209014893,
+            1.1179826259613037,
+            100.13501817965344,
+            0.7840981483459473,
+            0.0,
+            0.0,
+            1.0,
+            0.8158726692199707,
+            0.7607646883734706,
+            0.11551696062088013,
+            0.5057554298884411,
+            0.2841389775276184,
+            0.38466137641949605,
+            -4670244501.977441,
+            0.5892733461781723,
+            7.200549125671387,
+            1.4963178634643555,
+            100.11987620639972,
+            0.2066713571548462,
+            0.0,
+            0.0,
+            0.0,
+        ],
+    },
+];
+
+fn outputs(version: RandVersion, rng: &mut dyn WrappedRng) -> Vec<f64> {
+    let mut output = Vec::new();
+    for _ in 0..3 {
+        output.push(f64::from(float::standard::<f32, _>(version, rng)));
+        output.push(float::standard::<f64, _>(version, rng));
+        output.push(f64::from(float::open01::<f32, _>(version, rng)));
+        output.push(float::open01::<f64, _>(version, rng));
+        output.push(f64::from(float::open_closed01::<f32, _>(version, rng)));
+        output.push(float::open_closed01::<f64, _>(version, rng));
+        output.push(uniform::gen_range(version, rng, -1e10, 3.5));
+        output.push(uniform::gen_range_inclusive(version, rng, 0.0, 1.0));
+        output.push(f64::from(uniform::gen_range(version, rng, -3.0f32, 7.25)));
+        output.push(f64::from(uniform::gen_range_inclusive(
+            version, rng, 1.0f32, 1.5,
+        )));
+        output.push(Uniform::new(version, 100.0, 100.25).sample(rng));
+        output.push(f64::from(
+            Uniform::new_inclusive(version, -1.0f32, 1.0).sample(rng),
+        ));
+        output.push(u8::from(bernoulli::gen_bool(version, rng, 0.1)).into());
+        output.push(u8::from(bernoulli::gen_bool(version, rng, 0.5)).into());
+        output.push(u8::from(bernoulli::gen_ratio(version, rng, 2, 3)).into());
+    }
+    output
+}
+
+#[test]
+fn vectors() {
+    common::legacy::check(VECTORS, outputs);
+}
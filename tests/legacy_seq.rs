@@ -0,0 +1,151 @@
+//! Test vectors for the sequence operations of the `legacy` module.
+//!
+//! The vectors were generated using `rand 0.7.3`, `rand 0.8.8`, `rand 0.9.5`
+//! and `rand 0.10.3`, with `XorShiftRng` seeded with `SEED` as the RNG. Each
+//! vector is the output of the calls in `outputs`. The calls to `index::sample`
+//! cover Floyd's algorithm with and without shuffling, the partial
+//! Fisher-Yates shuffle and rejection sampling.
+#![cfg(all(feature = "legacy_algorithms", feature = "std"))]
+
+mod common;
+
+use common::legacy::Vector;
+use rand_core_compat::legacy::RandVersion;
+use rand_core_compat::legacy::WrappedRng;
+use rand_core_compat::legacy::seq;
+use rand_core_compat::legacy::seq::index;
+
+const VECTORS: &[Vector<usize>] = &[
+    Vector {
+        version: RandVersion::V0_7,
+        output: &[
+            3, 1, 9, 2, 5, 7, 0, 4, 8, 6, 7, 5, 1, 7, 6, 0, 14, 1, 62, 99, 6, 371, 890, 333, 912,
+            796, 36, 24, 89, 61, 27, 67, 69, 62, 83, 78, 20, 56, 37, 46, 28, 60, 0, 99, 17, 64,
+            40324, 44148, 74615, 54390, 7750, 27731, 44899, 971, 42424, 16833, 55494, 14026, 23583,
+            1262, 47282, 58346, 16474, 99274, 53548, 56304, 47725, 90184, 69942, 92970, 44897,
+            65110, 59946, 8858, 31775, 51916, 56494, 70998, 68637, 98294, 11617, 12849, 71366,
+            38820, 96499, 10657, 33401, 54554, 48062, 1258, 84386, 72962, 13749, 77245, 19257,
+            8064, 75388, 28752, 74548, 90022, 51907, 976210, 253378, 819421, 58550, 688017, 107276,
+            900462, 7345, 743423, 87217, 834585, 869939, 910150, 379778, 560367, 950527, 900521,
+            305250, 377858, 241179, 622452, 968295, 303084, 622035, 782980, 182621, 829772, 650931,
+            897793, 786066, 258783, 997177, 706804, 627019, 121334, 846859, 895417, 404894, 26802,
+            503049, 707456, 920746, 38014, 714102, 862037, 527987, 331270, 600015, 211723, 876682,
+            771821, 533595, 133207, 690202, 771873, 503000, 427550, 332423, 362295, 969448, 39260,
+            571098, 207234, 638825, 805909, 779725, 594752, 419753, 147516, 10851, 591643, 355640,
+            389547, 343932, 378230, 383020, 790270, 804523, 190027, 248172, 550679, 909009, 957187,
+            534926, 808173, 502571, 152412, 157126, 770621, 4697, 8635, 880334, 123380, 502286,
+            177862, 126743, 563963, 743684, 815539, 382776, 57476, 628054, 286961, 769148, 487082,
+            617133, 398871, 583506, 384418, 109769, 600987, 36222, 128933, 949885, 719406, 623332,
+            690556, 118123, 995054, 956005, 26833, 948568, 822882, 751616, 195312, 420737, 979661,
+            458210, 278337, 530840, 142782, 232638, 459619, 859370, 580004, 867900, 904094, 756975,
+            550216, 154347, 333596, 864009, 525239, 731727, 852413, 476698, 300423, 392176, 942,
+            239574, 210072, 486563, 569169, 37799, 26919, 4717, 218920, 344546, 498045, 842819,
+            712949, 311837, 808667, 1444, 659447, 262957, 665587, 374535, 480986, 694439,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_8,
+        output: &[
+            3, 1, 9, 2, 5, 7, 0, 4, 8, 6, 7, 5, 1, 7, 6, 0, 14, 1, 62, 99, 6, 371, 890, 333, 912,
+            796, 36, 24, 89, 61, 27, 67, 69, 62, 83, 78, 20, 56, 37, 46, 28, 60, 0, 99, 17, 64,
+            40324, 44148, 74615, 54390, 7750, 27731, 44899, 971, 42424, 16833, 55494, 14026, 23583,
+            1262, 47282, 58346, 16474, 99274, 53548, 56304, 47725, 90184, 69942, 92970, 44897,
+            65110, 59946, 8858, 31775, 51916, 56494, 70998, 68637, 98294, 11617, 12849, 71366,
+            38820, 96499, 10657, 33401, 54554, 48062, 1258, 84386, 72962, 13749, 77245, 19257,
+            8064, 75388, 28752, 74548, 90022, 51907, 976210, 253378, 819421, 58550, 688017, 107276,
+            900462, 7345, 743423, 87217, 834585, 869939, 910150, 379778, 560367, 950527, 900521,
+            305250, 377858, 241179, 622452, 968295, 303084, 622035, 782980, 182621, 829772, 650931,
+            897793, 786066, 258783, 997177, 706804, 627019, 121334, 846859, 895417, 404894, 26802,
+            503049, 707456, 920746, 38014, 714102, 862037, 527987, 331270, 600015, 211723, 876682,
+            771821, 533595, 133207, 690202, 771873, 503000, 427550, 332423, 362295, 969448, 39260,
+            571098, 207234, 638825, 805909, 779725, 594752, 419753, 147516, 10851, 591643, 355640,
+            389547, 343932, 378230, 383020, 790270, 804523, 190027, 248172, 550679, 909009, 957187,
+            534926, 808173, 502571, 152412, 157126, 770621, 4697, 8635, 880334, 123380, 502286,
+            177862, 126743, 563963, 743684, 815539, 382776, 57476, 628054, 286961, 769148, 487082,
+            617133, 398871, 583506, 384418, 109769, 600987, 36222, 128933, 949885, 719406, 623332,
+            690556, 118123, 995054, 956005, 26833, 948568, 822882, 751616, 195312, 420737, 979661,
+            458210, 278337, 530840, 142782, 232638, 459619, 859370, 580004, 867900, 904094, 756975,
+            550216, 154347, 333596, 864009, 525239, 731727, 852413, 476698, 300423, 392176, 942,
+            239574, 210072, 486563, 569169, 37799, 26919, 4717, 218920, 344546, 498045, 842819,
+            712949, 311837, 808667, 1444, 659447, 262957, 665587, 374535, 480986, 694439,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_9,
+        output: &[
+            0, 4, 9, 5, 3, 2, 7, 6, 1, 8, 7, 2, 5, 1, 4, 8, 31, 49, 4, 66, 84, 518, 258, 52, 856,
+            599, 53, 50, 45, 14, 39, 98, 64, 87, 70, 68, 23, 12, 9, 18, 19, 46, 76, 91, 2, 93,
+            79628, 36416, 23584, 90739, 53980, 89161, 59962, 24159, 65052, 65741, 67446, 59265,
+            81553, 51547, 76046, 11547, 14522, 50556, 28403, 75581, 38453, 16706, 53280, 23966,
+            58909, 99488, 99235, 22128, 47938, 60318, 55664, 97205, 51932, 74639, 47297, 71389,
+            88560, 69965, 11620, 99306, 8860, 51924, 56323, 44912, 10660, 7752, 12853, 72986,
+            98327, 13753, 27740, 13375, 971, 55513, 93002, 140313, 772699, 651305, 900495, 599635,
+            125724, 349636, 535630, 116081, 273462, 68888, 375430, 754087, 545681, 560357, 55441,
+            565085, 583602, 526959, 12629, 844051, 477357, 791646, 81685, 317814, 544005, 44938,
+            334076, 902000, 449065, 441551, 80653, 745580, 388253, 368930, 403286, 168352, 424280,
+            639080, 164758, 784192, 287540, 235848, 480649, 710010, 120362, 152283, 12585, 686390,
+            192574, 964990, 197489, 154910, 445343, 786401, 557249, 910125, 542531, 809965, 493556,
+            473853, 913577, 913974, 354575, 312522, 722143, 502704, 943146, 666806, 879931, 921678,
+            541192, 744620, 275916, 210362, 888485, 648477, 951295, 81778, 413167, 853429, 412829,
+            26118, 272991, 299261, 362974, 155789, 453859, 855800, 845962, 942341, 18702, 446922,
+            177332, 211366, 496287, 255792, 256173, 592539, 552270, 68666, 954139, 901348, 405512,
+            843431, 189999, 140508, 225267, 718014, 597714, 472102, 101530, 815411, 341284, 947604,
+            18670, 135934, 525140, 771178, 988613, 730967, 254056, 856110, 532377, 214488, 223808,
+            698598, 457464, 23673, 387176, 418033, 991656, 500038, 77079, 348482, 301736, 851212,
+            68265, 976210, 253378, 819421, 58550, 688017, 107276, 900462, 7345, 743423, 87217,
+            834585, 869939, 910150, 379778, 560367, 950527, 900521, 305250, 377858, 241179, 622452,
+            968295, 303084, 622035, 782980, 182621, 829772, 650931, 897793, 786066, 258783, 997177,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_10,
+        output: &[
+            0, 4, 9, 5, 3, 2, 7, 6, 1, 8, 7, 2, 5, 1, 4, 8, 31, 49, 4, 66, 84, 518, 258, 52, 856,
+            599, 53, 50, 45, 14, 39, 98, 64, 87, 70, 68, 23, 12, 9, 18, 19, 46, 76, 91, 2, 93,
+            79628, 36416, 23584, 90739, 53980, 89161, 59962, 24159, 65052, 65741, 67446, 59265,
+            81553, 51547, 76046, 11547, 14522, 50556, 28403, 75581, 38453, 16706, 53280, 23966,
+            58909, 99488, 99235, 22128, 47938, 60318, 55664, 97205, 51932, 74639, 47297, 71389,
+            88560, 69965, 11620, 99306, 8860, 51924, 56323, 44912, 10660, 7752, 12853, 72986,
+            98327, 13753, 27740, 13375, 971, 55513, 93002, 140313, 772699, 651305, 900495, 599635,
+            125724, 349636, 535630, 116081, 273462, 68888, 375430, 754087, 545681, 560357, 55441,
+            565085, 583602, 526959, 12629, 844051, 477357, 791646, 81685, 317814, 544005, 44938,
+            334076, 902000, 449065, 441551, 80653, 745580, 388253, 368930, 403286, 168352, 424280,
+            639080, 164758, 784192, 287540, 235848, 480649, 710010, 120362, 152283, 12585, 686390,
+            192574, 964990, 197489, 154910, 445343, 786401, 557249, 910125, 542531, 809965, 493556,
+            473853, 913577, 913974, 354575, 312522, 722143, 502704, 943146, 666806, 879931, 921678,
+            541192, 744620, 275916, 210362, 888485, 648477, 951295, 81778, 413167, 853429, 412829,
+            26118, 272991, 299261, 362974, 155789, 453859, 855800, 845962, 942341, 18702, 446922,
+            177332, 211366, 496287, 255792, 256173, 592539, 552270, 68666, 954139, 901348, 405512,
+            843431, 189999, 140508, 225267, 718014, 597714, 472102, 101530, 815411, 341284, 947604,
+            18670, 135934, 525140, 771178, 988613, 730967, 254056, 856110, 532377, 214488, 223808,
+            698598, 457464, 23673, 387176, 418033, 991656, 500038, 77079, 348482, 301736, 851212,
+            68265, 976210, 253378, 819421, 58550, 688017, 107276, 900462, 7345, 743423, 87217,
+            834585, 869939, 910150, 379778, 560367, 950527, 900521, 305250, 377858, 241179, 622452,
+            968295, 303084, 622035, 782980, 182621, 829772, 650931, 897793, 786066, 258783, 997177,
+        ],
+    },
+];
+
+fn outputs(version: RandVersion, rng: &mut dyn WrappedRng) -> Vec<usize> {
+    let mut output = Vec::new();
+    let mut v: Vec<usize> = (0..10).collect();
+    seq::shuffle(version, rng, &mut v);
+    output.extend_from_slice(&v);
+    for _ in 0..3 {
+        output.push(*seq::choose(version, rng, &v).unwrap());
+    }
+    let (shuffled, _) = seq::partial_shuffle(version, rng, &mut v, 3);
+    output.extend_from_slice(shuffled);
+    let w: Vec<usize> = (0..100).collect();
+    output.extend(seq::choose_multiple(version, rng, &w, 5));
+    output.extend(index::sample(version, rng, 1000, 5));
+    output.extend(index::sample(version, rng, 100, 20));
+    output.extend(index::sample(version, rng, 100_000, 55));
+    output.extend(index::sample(version, rng, 1_000_000, 170));
+    output
+}
+
+#[test]
+fn vectors() {
+    common::legacy::check(VECTORS, outputs);
+}
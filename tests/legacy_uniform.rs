@@ -0,0 +1,266 @@
+//! Test vectors for the integer sampling of `legacy::uniform`.
+//!
+//! The vectors were generated using `rand 0.7.3`, `rand 0.8.8`, `rand 0.9.5`
+//! and `rand 0.10.3`, with `XorShiftRng` seeded with `SEED` as the RNG. Each
+//! vector is the output of the calls in `outputs`. For `rand 0.7`, which had
+//! no single samples from inclusive ranges, the inclusive ranges were sampled
+//! using `Uniform::new_inclusive`. The calls cover ranges with and without
+//! rejections, inclusive ranges including the full width of the type, `usize`
+//! ranges fitting into a `u32` and `Uniform::sample`.
+#![cfg(feature = "legacy_algorithms")]
+
+mod common;
+
+use common::legacy::Vector;
+use rand_core_compat::legacy::RandVersion;
+use rand_core_compat::legacy::WrappedRng;
+use rand_core_compat::legacy::uniform::Uniform;
+use rand_core_compat::legacy::uniform::gen_range;
+use rand_core_compat::legacy::uniform::gen_range_inclusive;
+
+const VECTORS: &[Vector<i128>] = &[
+    Vector {
+        version: RandVersion::V0_7,
+        output: &[
+            6,
+            166,
+            121,
+            975772857,
+            2199645140,
+            8207942880873313219,
+            9608684740718752363,
+            52,
+            2573114201,
+            -10,
+            -130703636493576548,
+            -2531252794430442135,
+            136,
+            1970366125,
+            11,
+            -5,
+            0,
+            99,
+            67,
+            2678390515,
+            1434884400,
+            9797809392594874088,
+            4352794380755953388,
+            540,
+            2576592562,
+            301,
+            -1698274333645568938,
+            -4413112566968738527,
+            23,
+            435827586,
+            35,
+            -1,
+            1,
+            138,
+            176,
+            1767820038,
+            4274246381,
+            2722019905609722300,
+            11129578885379838984,
+            972,
+            3206403364,
+            428,
+            -2769117245217919918,
+            -125101217033412095,
+            17,
+            1689898755,
+            19,
+            -4,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_8,
+        output: &[
+            6,
+            166,
+            121,
+            975772857,
+            2199645140,
+            8207942880873313219,
+            9608684740718752363,
+            52,
+            2573114201,
+            -10,
+            -130703636493576548,
+            -2531252794430442135,
+            136,
+            1970366125,
+            11,
+            -5,
+            0,
+            99,
+            67,
+            2678390515,
+            1434884400,
+            9797809392594874088,
+            4352794380755953388,
+            540,
+            2576592562,
+            301,
+            -1698274333645568938,
+            -4413112566968738527,
+            23,
+            435827586,
+            35,
+            -1,
+            1,
+            138,
+            176,
+            1767820038,
+            4274246381,
+            2722019905609722300,
+            11129578885379838984,
+            972,
+            3206403364,
+            428,
+            -2769117245217919918,
+            -125101217033412095,
+            17,
+            1689898755,
+            19,
+            -4,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_9,
+        output: &[
+            6,
+            166,
+            121,
+            935482187,
+            4062862832,
+            6298269293668208188,
+            9608684740718752363,
+            258,
+            226400402,
+            62,
+            -5088755580703745500,
+            6910119659822620329,
+            197,
+            1866312040,
+            87,
+            2,
+            1,
+            13,
+            226,
+            199600862,
+            1599893555,
+            10979463194187951490,
+            16854623041664489776,
+            796,
+            1564894959,
+            80,
+            -3690181644780656382,
+            -6441209667348539912,
+            131,
+            2024289578,
+            63,
+            0,
+            7,
+            37,
+            133,
+            1517266289,
+            3247342255,
+            2055206537308194650,
+            18357748423972265526,
+            992,
+            950654473,
+            113,
+            -4432375070216454245,
+            8726544744544326947,
+            142,
+            2099315119,
+            20,
+            -5,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_10,
+        output: &[
+            6,
+            166,
+            121,
+            935482187,
+            4062862832,
+            6298269293668208188,
+            9608684740718752363,
+            258,
+            226400402,
+            62,
+            -5088755580703745500,
+            6910119659822620329,
+            197,
+            1866312040,
+            87,
+            2,
+            1,
+            13,
+            226,
+            199600862,
+            1599893555,
+            10979463194187951490,
+            16854623041664489776,
+            796,
+            1564894959,
+            80,
+            -3690181644780656382,
+            -6441209667348539912,
+            131,
+            2024289578,
+            63,
+            0,
+            7,
+            37,
+            133,
+            1517266289,
+            3247342255,
+            2055206537308194650,
+            18357748423972265526,
+            992,
+            950654473,
+            113,
+            -4432375070216454245,
+            8726544744544326947,
+            142,
+            2099315119,
+            20,
+            -5,
+        ],
+    },
+];
+
+fn outputs(version: RandVersion, rng: &mut dyn WrappedRng) -> Vec<i128> {
+    let mut output = Vec::new();
+    for _ in 0..3 {
+        output.push(gen_range(version, rng, 0u8, 10).into());
+        output.push(gen_range_inclusive(version, rng, 10u8, 250).into());
+        output.push(gen_range_inclusive(version, rng, 0u8, 255).into());
+        output.push(gen_range(version, rng, 0u32, 3_000_000_000).into());
+        output.push(gen_range_inclusive(version, rng, 0u32, u32::MAX).into());
+        output.push(gen_range(version, rng, 7u64, u64::MAX / 3 * 2).into());
+        output.push(gen_range_inclusive(version, rng, 0u64, u64::MAX).into());
+        output.push(gen_range(version, rng, 0usize, 1000) as i128);
+        output.push(gen_range_inclusive(version, rng, 5usize, u32::MAX as usize) as i128);
+        output.push(gen_range(version, rng, -1000i64, 1000).into());
+        output.push(gen_range(version, rng, i64::MIN, 0).into());
+        output.push(gen_range_inclusive(version, rng, i64::MIN, i64::MAX).into());
+        output.push(Uniform::new(version, 0u8, 200).sample(rng).into());
+        output.push(
+            Uniform::new(version, 0u32, 3_000_000_000)
+                .sample(rng)
+                .into(),
+        );
+        output.push(Uniform::new(version, 10usize, 100).sample(rng) as i128);
+        output.push(Uniform::new_inclusive(version, -5i64, 5).sample(rng).into());
+    }
+    output
+}
+
+#[test]
+fn vectors() {
+    common::legacy::check(VECTORS, outputs);
+}
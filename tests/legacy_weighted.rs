@@ -0,0 +1,99 @@
+//! Test vectors for the weighted sampling of the `legacy` module.
+//!
+//! The vectors were generated using `rand 0.7.3`, `rand 0.8.8`, `rand 0.9.5`
+//! and `rand 0.10.3`, and the alias tables using `rand_distr 0.4.3`,
+//! `rand_distr 0.5.1` and `rand_distr 0.6.0`, with `XorShiftRng` seeded with
+//! `SEED` as the RNG. Each vector is the output of the calls in `outputs`;
+//! `rand 0.7` didn't sample several weighted indices.
+#![cfg(all(feature = "legacy_algorithms", feature = "std"))]
+
+mod common;
+
+use common::legacy::Vector;
+use rand_core_compat::legacy::RandVersion;
+use rand_core_compat::legacy::WrappedRng;
+use rand_core_compat::legacy::seq;
+use rand_core_compat::legacy::seq::index;
+use rand_core_compat::legacy::weighted::WeightError;
+use rand_core_compat::legacy::weighted::WeightedAliasIndex;
+use rand_core_compat::legacy::weighted::WeightedIndex;
+
+const FLOATS: [f64; 8] = [1e-3, 7.0, 2.0, 2.0, 0.0, 9.5, 1e5, 0.1];
+const INTS: [u32; 4] = [1, 0, 5, 2];
+
+const VECTORS: &[Vector<usize>] = &[
+    Vector {
+        version: RandVersion::V0_7,
+        output: &[
+            6, 6, 6, 6, 6, 3, 2, 2, 0, 3, 6, 6, 6, 6, 6, 0, 2, 3, 2, 2, 6, 6, 6,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_8,
+        output: &[
+            6, 6, 6, 6, 6, 3, 2, 2, 0, 3, 6, 6, 6, 6, 6, 0, 2, 3, 2, 2, 6, 6, 6, 6, 7, 1, 6, 2, 71,
+            49, 59,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_9,
+        output: &[
+            6, 6, 6, 6, 6, 3, 2, 2, 0, 3, 6, 6, 6, 6, 6, 0, 2, 3, 2, 2, 6, 6, 6, 3, 1, 6, 62, 43,
+            1, 5, 57,
+        ],
+    },
+    Vector {
+        version: RandVersion::V0_10,
+        output: &[
+            6, 6, 6, 6, 6, 3, 2, 2, 0, 3, 6, 6, 6, 6, 6, 0, 2, 3, 2, 2, 6, 6, 6, 3, 1, 6, 62, 43,
+            1, 5, 57,
+        ],
+    },
+];
+
+fn outputs(version: RandVersion, rng: &mut dyn WrappedRng) -> Vec<usize> {
+    let mut output = Vec::new();
+    let distr = WeightedIndex::new(version, FLOATS).unwrap();
+    output.extend((0..5).map(|_| distr.sample(rng)));
+    let distr = WeightedIndex::new(version, INTS).unwrap();
+    output.extend((0..5).map(|_| distr.sample(rng)));
+    let distr = WeightedAliasIndex::new(version, FLOATS.to_vec()).unwrap();
+    output.extend((0..5).map(|_| distr.sample(rng)));
+    let distr = WeightedAliasIndex::new(version, INTS.to_vec()).unwrap();
+    output.extend((0..5).map(|_| distr.sample(rng)));
+    let items: Vec<usize> = (0..8).collect();
+    for _ in 0..3 {
+        output.push(*seq::choose_weighted(version, rng, &items, |&i| FLOATS[i]).unwrap());
+    }
+    if version != RandVersion::V0_7 {
+        output.extend(
+            seq::choose_multiple_weighted(version, rng, &items, 3, |&i| FLOATS[i]).unwrap(),
+        );
+        let w: Vec<f64> = (0..100).map(|i| ((i * 37) % 11) as f64 * 0.3).collect();
+        output.extend(index::sample_weighted(version, rng, 100, |i| w[i], 5).unwrap());
+    }
+    output
+}
+
+#[test]
+fn vectors() {
+    common::legacy::check(VECTORS, outputs);
+}
+
+#[test]
+fn invalid_weights() {
+    for version in common::legacy::VERSIONS {
+        assert_eq!(
+            WeightedIndex::new(version, Vec::<f64>::new()).err(),
+            Some(WeightError::InvalidInput)
+        );
+        assert_eq!(
+            WeightedIndex::new(version, [1.0, -1.0]).err(),
+            Some(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            WeightedAliasIndex::new(version, vec![0u32, 0]).err(),
+            Some(WeightError::InsufficientNonZero)
+        );
+    }
+}
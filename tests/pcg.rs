@@ -0,0 +1,328 @@
+//! Tests of moving the state of a PCG RNG between `rand_pcg` versions.
+//!
+//! Each RNG is advanced, so that its state differs from the one it was
+//! created with, before moving its state to the next selected version, which
+//! must continue with the same output. The `advance` of the states is checked
+//! against the `advance` of `rand_pcg`, which `rand_pcg 0.2` doesn't have.
+#![cfg(any(
+    feature = "rand_pcg_0_2",
+    feature = "rand_pcg_0_3",
+    feature = "rand_pcg_0_9",
+    feature = "rand_pcg_0_10",
+))]
+
+use core::fmt::Debug;
+use rand_core_compat::Pcg32State;
+use rand_core_compat::Pcg64McgState;
+use rand_core_compat::Pcg64State;
+
+const STATE: u128 = 0xcafe_f00d_d15e_a5e5_0123_4567_89ab_cdef;
+const STREAM: u128 = 0x0a02_bdbf_7bb3_c0a7_ac28_fa16_a64a_bf96;
+
+/// Number of outputs generated before moving the state.
+const SKIP: usize = 7;
+
+/// Number of outputs compared after moving the state.
+const LEN: usize = 20;
+
+/// Steps for checking `advance`, including ones wrapping around the period.
+const DELTAS: [u128; 5] = [0, 1, 1000, 0xdead_beef_1234_5678, u128::MAX];
+
+/// PCG RNG of one `rand_pcg` version.
+trait Generator<S> {
+    fn next_u64(&mut self) -> u64;
+    fn state(&self) -> S;
+}
+
+/// Restore an RNG of one `rand_pcg` version from the state `S`.
+type Restore<S> = fn(S) -> Box<dyn Generator<S>>;
+
+#[cfg(feature = "rand_pcg_0_2")]
+struct Wrap05<R>(R);
+
+#[cfg(feature = "rand_pcg_0_2")]
+impl<S, R> Generator<S> for Wrap05<R>
+where
+    R: rand_core_0_5::RngCore,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_2")]
+fn restore05<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_5::RngCore + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap05(R::from(state)))
+}
+
+#[cfg(feature = "rand_pcg_0_3")]
+struct Wrap06<R>(R);
+
+#[cfg(feature = "rand_pcg_0_3")]
+impl<S, R> Generator<S> for Wrap06<R>
+where
+    R: rand_core_0_6::RngCore,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_3")]
+fn restore06<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_6::RngCore + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap06(R::from(state)))
+}
+
+#[cfg(feature = "rand_pcg_0_9")]
+struct Wrap09<R>(R);
+
+#[cfg(feature = "rand_pcg_0_9")]
+impl<S, R> Generator<S> for Wrap09<R>
+where
+    R: rand_core_0_9::RngCore,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_9")]
+fn restore09<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_9::RngCore + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap09(R::from(state)))
+}
+
+#[cfg(feature = "rand_pcg_0_10")]
+struct Wrap010<R>(R);
+
+#[cfg(feature = "rand_pcg_0_10")]
+impl<S, R> Generator<S> for Wrap010<R>
+where
+    R: rand_core_0_10::Rng,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_10")]
+fn restore010<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_10::Rng + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap010(R::from(state)))
+}
+
+/// Start with the first of `versions` at `start` and move the state to each
+/// following version after generating some output, checking that it continues
+/// with the output of the previous version.
+fn check<S: Clone + Debug + PartialEq>(start: S, versions: &[(&str, Restore<S>)]) {
+    let (mut name, restore) = versions[0];
+    let mut rng = restore(start);
+    for _ in 0..SKIP {
+        rng.next_u64();
+    }
+    for &(next_name, restore) in &versions[1..] {
+        let mut next = restore(rng.state());
+        assert_eq!(next.state(), rng.state(), "{name} to {next_name}");
+        let expected: Vec<u64> = (0..LEN).map(|_| rng.next_u64()).collect();
+        let output: Vec<u64> = (0..LEN).map(|_| next.next_u64()).collect();
+        assert_eq!(output, expected, "{name} to {next_name}");
+        assert_eq!(next.state(), rng.state(), "{name} to {next_name}");
+        for _ in 0..SKIP {
+            next.next_u64();
+        }
+        (name, rng) = (next_name, next);
+    }
+}
+
+#[test]
+fn pcg32() {
+    let versions: &[(&str, Restore<Pcg32State>)] = &[
+        #[cfg(feature = "rand_pcg_0_2")]
+        ("0.2", restore05::<_, rand_pcg_0_2::Lcg64Xsh32>),
+        #[cfg(feature = "rand_pcg_0_3")]
+        ("0.3", restore06::<_, rand_pcg_0_3::Lcg64Xsh32>),
+        #[cfg(feature = "rand_pcg_0_9")]
+        ("0.9", restore09::<_, rand_pcg_0_9::Lcg64Xsh32>),
+        #[cfg(feature = "rand_pcg_0_10")]
+        ("0.10", restore010::<_, rand_pcg_0_10::Lcg64Xsh32>),
+    ];
+    check(Pcg32State::new(STATE as u64, STREAM as u64), versions);
+}
+
+#[test]
+fn pcg64() {
+    let versions: &[(&str, Restore<Pcg64State>)] = &[
+        #[cfg(feature = "rand_pcg_0_2")]
+        ("0.2", restore05::<_, rand_pcg_0_2::Lcg128Xsl64>),
+        #[cfg(feature = "rand_pcg_0_3")]
+        ("0.3", restore06::<_, rand_pcg_0_3::Lcg128Xsl64>),
+        #[cfg(feature = "rand_pcg_0_9")]
+        ("0.9", restore09::<_, rand_pcg_0_9::Lcg128Xsl64>),
+        #[cfg(feature = "rand_pcg_0_10")]
+        ("0.10", restore010::<_, rand_pcg_0_10::Lcg128Xsl64>),
+    ];
+    check(Pcg64State::new(STATE, STREAM), versions);
+}
+
+#[test]
+fn pcg64_mcg() {
+    let versions: &[(&str, Restore<Pcg64McgState>)] = &[
+        #[cfg(feature = "rand_pcg_0_2")]
+        ("0.2", restore05::<_, rand_pcg_0_2::Mcg128Xsl64>),
+        #[cfg(feature = "rand_pcg_0_3")]
+        ("0.3", restore06::<_, rand_pcg_0_3::Mcg128Xsl64>),
+        #[cfg(feature = "rand_pcg_0_9")]
+        ("0.9", restore09::<_, rand_pcg_0_9::Mcg128Xsl64>),
+        #[cfg(feature = "rand_pcg_0_10")]
+        ("0.10", restore010::<_, rand_pcg_0_10::Mcg128Xsl64>),
+    ];
+    check(Pcg64McgState::new(STATE), versions);
+}
+
+#[cfg(feature = "rand_pcg_0_2")]
+#[test]
+fn rand_pcg_0_2() {
+    use rand_core_0_5::RngCore;
+    use rand_pcg_0_2::Lcg64Xsh32;
+    use rand_pcg_0_2::Lcg128Xsl64;
+    use rand_pcg_0_2::Mcg128Xsl64;
+
+    // Without `advance` upstream, compare with stepping the RNGs.
+    let mut rng = Lcg64Xsh32::new(STATE as u64, STREAM as u64);
+    let mut state = Pcg32State::from(&rng);
+    state.advance(1000);
+    for _ in 0..1000 {
+        rng.next_u32();
+    }
+    assert_eq!(Pcg32State::from(&rng), state);
+
+    let mut rng = Lcg128Xsl64::new(STATE, STREAM);
+    let mut state = Pcg64State::from(&rng);
+    state.advance(1000);
+    for _ in 0..1000 {
+        rng.next_u64();
+    }
+    assert_eq!(Pcg64State::from(&rng), state);
+
+    let mut rng = Mcg128Xsl64::new(STATE);
+    let mut state = Pcg64McgState::from(&rng);
+    state.advance(1000);
+    for _ in 0..1000 {
+        rng.next_u64();
+    }
+    assert_eq!(Pcg64McgState::from(&rng), state);
+}
+
+#[cfg(feature = "rand_pcg_0_3")]
+#[test]
+fn rand_pcg_0_3() {
+    use rand_pcg_0_3::Lcg64Xsh32;
+    use rand_pcg_0_3::Lcg128Xsl64;
+    use rand_pcg_0_3::Mcg128Xsl64;
+
+    for delta in DELTAS {
+        let mut rng = Lcg64Xsh32::new(STATE as u64, STREAM as u64);
+        let mut state = Pcg32State::from(&rng);
+        rng.advance(delta as u64);
+        state.advance(delta as u64);
+        assert_eq!(Pcg32State::from(&rng), state);
+
+        let mut rng = Lcg128Xsl64::new(STATE, STREAM);
+        let mut state = Pcg64State::from(&rng);
+        rng.advance(delta);
+        state.advance(delta);
+        assert_eq!(Pcg64State::from(&rng), state);
+
+        let mut rng = Mcg128Xsl64::new(STATE);
+        let mut state = Pcg64McgState::from(&rng);
+        rng.advance(delta);
+        state.advance(delta);
+        assert_eq!(Pcg64McgState::from(&rng), state);
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_9")]
+#[test]
+fn rand_pcg_0_9() {
+    use rand_pcg_0_9::Lcg64Xsh32;
+    use rand_pcg_0_9::Lcg128Xsl64;
+    use rand_pcg_0_9::Mcg128Xsl64;
+
+    for delta in DELTAS {
+        let mut rng = Lcg64Xsh32::new(STATE as u64, STREAM as u64);
+        let mut state = Pcg32State::from(&rng);
+        rng.advance(delta as u64);
+        state.advance(delta as u64);
+        assert_eq!(Pcg32State::from(&rng), state);
+
+        let mut rng = Lcg128Xsl64::new(STATE, STREAM);
+        let mut state = Pcg64State::from(&rng);
+        rng.advance(delta);
+        state.advance(delta);
+        assert_eq!(Pcg64State::from(&rng), state);
+
+        let mut rng = Mcg128Xsl64::new(STATE);
+        let mut state = Pcg64McgState::from(&rng);
+        rng.advance(delta);
+        state.advance(delta);
+        assert_eq!(Pcg64McgState::from(&rng), state);
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_10")]
+#[test]
+fn rand_pcg_0_10() {
+    use rand_pcg_0_10::Lcg64Xsh32;
+    use rand_pcg_0_10::Lcg128Xsl64;
+    use rand_pcg_0_10::Mcg128Xsl64;
+
+    for delta in DELTAS {
+        let mut rng = Lcg64Xsh32::new(STATE as u64, STREAM as u64);
+        let mut state = Pcg32State::from(&rng);
+        rng.advance(delta as u64);
+        state.advance(delta as u64);
+        assert_eq!(Pcg32State::from(&rng), state);
+
+        let mut rng = Lcg128Xsl64::new(STATE, STREAM);
+        let mut state = Pcg64State::from(&rng);
+        rng.advance(delta);
+        state.advance(delta);
+        assert_eq!(Pcg64State::from(&rng), state);
+
+        let mut rng = Mcg128Xsl64::new(STATE);
+        let mut state = Pcg64McgState::from(&rng);
+        rng.advance(delta);
+        state.advance(delta);
+        assert_eq!(Pcg64McgState::from(&rng), state);
+    }
+}
@@ -0,0 +1,63 @@
+//! Tests of hiding the state of wrapped crypto RNGs.
+#![cfg(feature = "rand_core_0_9")]
+
+use rand_core_0_9::RngCore;
+use rand_core_compat::Redacted;
+use rand_core_compat::Rng09;
+
+/// Non-random RNG pretending to be a crypto RNG, returning its key.
+#[derive(Clone, Debug)]
+struct Secret {
+    key: [u8; 16],
+}
+
+impl RngCore for Secret {
+    fn next_u32(&mut self) -> u32 {
+        rand_core_0_9::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core_0_9::impls::next_u64_via_fill(self)
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for (byte, key) in dst.iter_mut().zip(self.key.iter().cycle()) {
+            *byte = *key;
+        }
+    }
+}
+
+impl rand_core_0_9::CryptoRng for Secret {}
+
+const KEY: [u8; 16] = [0xa5; 16];
+
+#[test]
+fn debug() {
+    let rng = Rng09(Secret { key: KEY });
+    // The wrapper itself prints the key.
+    assert!(format!("{rng:?}").contains("165"));
+    let redacted = Redacted(rng);
+    assert_eq!(format!("{redacted:?}"), "Redacted { .. }");
+    assert!(!format!("{redacted:#?}").contains("165"));
+}
+
+#[test]
+fn forwards() {
+    let mut rng = Redacted(Rng09(Secret { key: KEY }));
+    let mut dst = [0; 20];
+    rng.fill_bytes(&mut dst);
+    assert_eq!(dst, [0xa5; 20]);
+    assert_eq!(rng.next_u64(), u64::from_le_bytes([0xa5; 8]));
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_compat::Rng06;
+
+    fn assert_crypto<R: rand_core_0_6::CryptoRng>(_rng: &R) {}
+
+    let rng = Redacted(Rng09(Secret { key: KEY }));
+    assert_crypto(&rng);
+    let mut rng = Redacted(Rng06(rng));
+    assert_eq!(format!("{rng:?}"), "Redacted { .. }");
+    assert_eq!(rand_core_0_6::RngCore::next_u32(&mut rng), 0xa5a5a5a5);
+}
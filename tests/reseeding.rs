@@ -0,0 +1,164 @@
+//! Tests of `Reseeding`.
+//!
+//! The RNG returns consecutive numbers starting at its seed, and the reseeder
+//! returns the seeds 1000, 2000, ..., so the output shows when a reseed
+//! happened.
+#![cfg(feature = "rand_core_0_9")]
+
+mod common;
+
+use common::Counter;
+use rand_core_0_9::RngCore;
+use rand_core_0_9::TryRngCore;
+use rand_core_compat::Reseeding;
+use rand_core_compat::Rng09;
+use rand_core_compat::TryRng09;
+use rand_core_compat::error::Error;
+
+/// Non-random reseeder returning the seeds 1000, 2000, ...
+#[derive(Clone, Debug)]
+struct Seeds(u64);
+
+impl RngCore for Seeds {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 += 1000;
+        self.0
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core_0_9::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+/// Reseeder failing every request.
+#[derive(Debug)]
+struct Failing(&'static str);
+
+impl TryRngCore for Failing {
+    type Error = &'static str;
+    fn try_next_u32(&mut self) -> Result<u32, &'static str> {
+        Err(self.0)
+    }
+    fn try_next_u64(&mut self) -> Result<u64, &'static str> {
+        Err(self.0)
+    }
+    fn try_fill_bytes(&mut self, _dst: &mut [u8]) -> Result<(), &'static str> {
+        Err(self.0)
+    }
+}
+
+/// Check that `error` is the error of the `Failing` reseeder named `name`.
+///
+/// Without `std`, only the error code `UNKNOWN` is kept.
+fn assert_failed(error: Error, name: &str) {
+    #[cfg(feature = "std")]
+    assert_eq!(error.to_string(), name);
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = name;
+        assert_eq!(error.code(), rand_core_compat::error::UNKNOWN);
+    }
+}
+
+fn reseeding(threshold: u64) -> Reseeding<Rng09<Counter>, Rng09<Seeds>> {
+    Reseeding::new(Rng09(Counter(0)), threshold, Rng09(Seeds(0)))
+}
+
+#[test]
+fn threshold() {
+    let mut rng = reseeding(16);
+    assert_eq!(rng.try_next_u64().unwrap(), 0);
+    assert_eq!(rng.try_next_u32().unwrap(), 1);
+    assert_eq!(rng.try_next_u32().unwrap(), 2);
+    // 16 bytes have been generated.
+    assert_eq!(rng.try_next_u64().unwrap(), 1000);
+    assert_eq!(rng.try_next_u64().unwrap(), 1001);
+    assert_eq!(rng.try_next_u64().unwrap(), 2000);
+
+    // A request exceeding the threshold is completed before reseeding.
+    let mut dst = [0; 24];
+    rng.try_fill_bytes(&mut dst).unwrap();
+    assert_eq!(dst[..8], 2001u64.to_le_bytes());
+    assert_eq!(dst[16..], 2003u64.to_le_bytes());
+    assert_eq!(rng.try_next_u64().unwrap(), 3000);
+    assert_eq!(rng.inner().1.0.0, 3000);
+}
+
+#[test]
+fn on_demand() {
+    let mut rng = reseeding(16);
+    assert_eq!(rng.try_next_u64().unwrap(), 0);
+    rng.schedule_reseed();
+    assert_eq!(rng.try_next_u64().unwrap(), 1000);
+    // The threshold starts over after the reseed.
+    assert_eq!(rng.try_next_u64().unwrap(), 1001);
+    assert_eq!(rng.try_next_u64().unwrap(), 2000);
+
+    // With a threshold of 0, the RNG is only reseeded on demand.
+    let mut rng = reseeding(0);
+    let mut dst = vec![0; 1 << 16];
+    rng.try_fill_bytes(&mut dst).unwrap();
+    assert_eq!(rng.try_next_u64().unwrap(), 1 << 13);
+    rng.schedule_reseed();
+    assert_eq!(rng.try_next_u64().unwrap(), 1000);
+    let (Rng09(rng), Rng09(reseeder)) = rng.into_inner();
+    assert_eq!(rng.0, 1001);
+    assert_eq!(reseeder.0, 1000);
+}
+
+#[test]
+fn reseed_error() {
+    let mut rng = Reseeding::new(Rng09(Counter(0)), 8, TryRng09(Failing("reseed")));
+    assert_eq!(rng.try_next_u64().unwrap(), 0);
+    assert_failed(rng.try_next_u32().unwrap_err(), "reseed");
+    assert_failed(rng.try_next_u64().unwrap_err(), "reseed");
+    assert_failed(rng.try_fill_bytes(&mut [0; 8]).unwrap_err(), "reseed");
+    // No output is generated by the old RNG.
+    assert_eq!(rng.inner().0.0.0, 1);
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+
+    let mut rng = reseeding(8);
+    assert_eq!(rng.next_u64(), 0);
+    assert_eq!(rng.next_u32(), 1000);
+    rng.schedule_reseed();
+    assert_eq!(rng.next_u64(), 2000);
+
+    let mut rng = Reseeding::new(Rng09(Counter(0)), 8, TryRng09(Failing("reseed")));
+    assert!(RngCore::try_fill_bytes(&mut rng, &mut [0; 8]).is_ok());
+    assert!(RngCore::try_fill_bytes(&mut rng, &mut [0; 8]).is_err());
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+
+    let mut rng = reseeding(8);
+    assert_eq!(rng.next_u64(), 0);
+    assert_eq!(rng.next_u32(), 1000);
+    rng.schedule_reseed();
+    assert_eq!(rng.next_u64(), 2000);
+
+    let mut rng = Reseeding::new(Rng09(Counter(0)), 8, TryRng09(Failing("reseed")));
+    assert!(RngCore::try_fill_bytes(&mut rng, &mut [0; 8]).is_ok());
+    assert!(RngCore::try_fill_bytes(&mut rng, &mut [0; 8]).is_err());
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::TryRng;
+
+    let mut rng = reseeding(8);
+    assert_eq!(TryRng::try_next_u64(&mut rng).unwrap(), 0);
+    assert_eq!(TryRng::try_next_u32(&mut rng).unwrap(), 1000);
+    rng.schedule_reseed();
+    assert_eq!(TryRng::try_next_u64(&mut rng).unwrap(), 2000);
+}
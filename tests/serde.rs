@@ -0,0 +1,93 @@
+//! Tests of the serialization of the wrapper structs, the version-independent
+//! states and `Versioned`, using JSON.
+#![cfg(feature = "serde")]
+
+use rand_core_compat::Version;
+use rand_core_compat::Versioned;
+use serde::de::IgnoredAny;
+
+#[test]
+fn version() {
+    let versioned = Versioned::new(Version::V0_6, 42u8);
+    let json = serde_json::to_string(&versioned).unwrap();
+    assert_eq!(json, "[[0,6],42]");
+    let loaded: Versioned<u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, versioned);
+
+    // The version can be read without knowing the type of the state.
+    let json = r#"[[0,10],{"state":[1,2,3]}]"#;
+    let peeked: Versioned<IgnoredAny> = serde_json::from_str(json).unwrap();
+    assert_eq!(peeked.version(), Version::V0_10);
+
+    for version in [Version::V0_5, Version::V0_6, Version::V0_9, Version::V0_10] {
+        let json = serde_json::to_string(&Versioned::new(version, ())).unwrap();
+        let peeked: Versioned<IgnoredAny> = serde_json::from_str(&json).unwrap();
+        assert_eq!(peeked.version(), version);
+    }
+}
+
+#[test]
+fn unknown_version() {
+    let error = serde_json::from_str::<Versioned<u8>>("[[0,7],42]").unwrap_err();
+    assert!(error.to_string().contains("unknown rand_core version"));
+    assert!(serde_json::from_str::<Versioned<IgnoredAny>>("[[0,7],42]").is_err());
+    assert!(serde_json::from_str::<Versioned<IgnoredAny>>("[[1,0],42]").is_err());
+}
+
+#[cfg(feature = "rand_chacha_0_3")]
+#[test]
+fn chacha_state() {
+    use rand_chacha_0_3::ChaCha20Rng;
+    use rand_core_0_6::RngCore;
+    use rand_core_0_6::SeedableRng;
+    use rand_core_compat::ChaChaState;
+
+    let mut rng = ChaCha20Rng::from_seed([7; 32]);
+    rng.set_stream(3);
+    for _ in 0..21 {
+        rng.next_u32();
+    }
+    let versioned = Versioned::new(Version::V0_6, ChaChaState::from(&rng));
+    let json = serde_json::to_string(&versioned).unwrap();
+    let peeked: Versioned<IgnoredAny> = serde_json::from_str(&json).unwrap();
+    assert_eq!(peeked.version(), Version::V0_6);
+    let loaded: Versioned<ChaChaState> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, versioned);
+    assert_eq!(loaded.state().word_pos(), 21);
+
+    let mut restored = ChaCha20Rng::from(loaded.into_state());
+    for _ in 0..50 {
+        assert_eq!(restored.next_u32(), rng.next_u32());
+    }
+}
+
+#[cfg(feature = "rand_pcg_0_3")]
+#[test]
+fn wrapper() {
+    use rand_core_0_6::RngCore;
+    use rand_core_compat::Rng06;
+    use rand_core_compat::TryRng06;
+    use rand_pcg_0_3::Pcg32;
+
+    let mut rng = Rng06(Pcg32::new(0xcafef00dd15ea5e5, 0xa02bdbf7bb3c0a7));
+    rng.0.next_u32();
+    // The wrapper serializes like the inner RNG.
+    let json = serde_json::to_string(&rng).unwrap();
+    assert_eq!(json, serde_json::to_string(&rng.0).unwrap());
+
+    let mut loaded: Rng06<Pcg32> = serde_json::from_str(&json).unwrap();
+    let mut inner: Pcg32 = serde_json::from_str(&json).unwrap();
+    let mut try_loaded: TryRng06<Pcg32> = serde_json::from_str(&json).unwrap();
+    for _ in 0..20 {
+        let expected = rng.0.next_u64();
+        assert_eq!(loaded.0.next_u64(), expected);
+        assert_eq!(inner.next_u64(), expected);
+        assert_eq!(try_loaded.0.next_u64(), expected);
+    }
+
+    let versioned = Versioned::new(Version::V0_6, loaded);
+    let json = serde_json::to_string(&versioned).unwrap();
+    let loaded: Versioned<Rng06<Pcg32>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.version(), Version::V0_6);
+    assert_eq!(loaded.state().0, versioned.state().0);
+}
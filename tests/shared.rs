@@ -0,0 +1,93 @@
+//! Tests of `Shared` and `SharedStatic` used from several threads.
+//!
+//! The shared RNG returns consecutive numbers, so every number must be drawn
+//! exactly once, whichever thread draws it.
+#![cfg(all(
+    feature = "rand_core_0_9",
+    any(feature = "std", feature = "critical-section")
+))]
+
+mod common;
+
+use common::Counter;
+use rand_core_0_9::RngCore;
+
+const THREADS: u64 = 4;
+const DRAWS: u64 = 1000;
+
+/// Draw from `rng` in several threads, checking that every number is drawn
+/// exactly once.
+fn check_threads<R: RngCore + Clone + Send + 'static>(rng: R) {
+    let threads: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let mut rng = rng.clone();
+            std::thread::spawn(move || (0..DRAWS).map(|_| rng.next_u64()).collect::<Vec<_>>())
+        })
+        .collect();
+    let mut drawn: Vec<u64> = threads
+        .into_iter()
+        .flat_map(|thread| thread.join().unwrap())
+        .collect();
+    drawn.sort_unstable();
+    assert_eq!(drawn, (0..THREADS * DRAWS).collect::<Vec<_>>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn shared() {
+    use rand_core_compat::Rng09;
+    use rand_core_compat::Shared;
+
+    let rng = Shared::new(Rng09(Counter(0)));
+    check_threads(rng.clone());
+    // Handles and references to them share the RNG.
+    assert_eq!(rng.clone().next_u64(), THREADS * DRAWS);
+    assert_eq!((&rng).next_u64(), THREADS * DRAWS + 1);
+
+    // The RNG doesn't need to be `'static`.
+    let mut counter = Counter(7);
+    let mut rng = Shared::new(Rng09(&mut counter));
+    assert_eq!(rng.next_u64(), 7);
+    drop(rng);
+    assert_eq!(counter.0, 8);
+}
+
+#[cfg(feature = "critical-section")]
+#[test]
+fn shared_static() {
+    use rand_core_compat::Rng09;
+    use rand_core_compat::SharedStatic;
+
+    static RNG: SharedStatic<Rng09<Counter>> = SharedStatic::new();
+    let rng = RNG.init(Rng09(Counter(0)));
+    check_threads(rng);
+    assert_eq!((&RNG).next_u64(), THREADS * DRAWS);
+
+    // `init` replaces the RNG.
+    RNG.init(Rng09(Counter(100)));
+    assert_eq!((&RNG).next_u64(), 100);
+}
+
+#[cfg(all(feature = "std", feature = "rand_core_0_6"))]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+    use rand_core_compat::Rng09;
+    use rand_core_compat::Shared;
+
+    let mut rng = Shared::new(Rng09(Counter(0)));
+    assert_eq!(RngCore::next_u64(&mut rng), 0);
+    assert_eq!(RngCore::next_u32(&mut &rng), 1);
+}
+
+#[cfg(all(feature = "std", feature = "rand_core_0_10"))]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::TryRng;
+    use rand_core_compat::Rng09;
+    use rand_core_compat::Shared;
+
+    let mut rng = Shared::new(Rng09(Counter(0)));
+    assert_eq!(rng.try_next_u64().unwrap(), 0);
+    assert_eq!((&rng).try_next_u32().unwrap(), 1);
+}
@@ -0,0 +1,311 @@
+//! Test vectors for the `SmallRng` types on 64-bit targets.
+//!
+//! The vectors were generated using the `SmallRng` of `rand 0.7.3`,
+//! `rand 0.8.8`, `rand 0.9.5` and `rand 0.10.3` on x86_64, in the same way as
+//! those of `tests/legacy_algorithms.rs`: each vector seeds the RNG and calls
+//! `next_u32`, `next_u64` and `fill_bytes` in a fixed order, covering
+//! partially used outputs. The output of the larger `fill_bytes` call is only
+//! checked for its last 16 bytes.
+#![cfg(all(target_pointer_width = "64", not(target_os = "emscripten")))]
+
+use rand_core_compat::SmallRng07;
+use rand_core_compat::SmallRng08;
+use rand_core_compat::SmallRng09;
+use rand_core_compat::SmallRng010;
+
+const SEED: [u8; 32] = [
+    1, 0, 0, 0, 23, 0, 0, 0, 200, 1, 0, 0, 210, 30, 0, 0, 0x2a, 0x3b, 0x4c, 0x5d, 0x6e, 0x7f, 0x80,
+    0x91, 0xa2, 0xb3, 0xc4, 0xd5, 0xe6, 0xf7, 0x08, 0x19,
+];
+const STATE: u64 = 0x0123_4567_89ab_cdef;
+
+/// The `rand` version whose `SmallRng` is reproduced.
+#[derive(Clone, Copy)]
+enum Version {
+    V07,
+    V08,
+    V09,
+    V010,
+}
+
+/// How the RNG is seeded: `from_seed` with `SEED`, truncated to the seed size, or `seed_from_u64`
+/// with `STATE`.
+#[derive(Clone, Copy)]
+enum Seed {
+    Bytes,
+    U64,
+}
+
+struct Vector {
+    version: Version,
+    seed: Seed,
+    fill_len: usize,
+    output: &'static str,
+}
+
+const VECTORS: &[Vector] = &[
+    Vector {
+        version: Version::V07,
+        seed: Seed::Bytes,
+        fill_len: 68,
+        output: concat!(
+            "941446912e21e77d836256967d4eb34b0c18040cfb3d3c958ec0925b3162239a",
+            "c3ca72e0794eccf746473894937df244a2d7b8de94609ec5f54aa9d00c33b8dd",
+            "265e045b7ffbc49c6c6331776bc36f62e66ee1c639a6431ff2bcb03805b1e81e",
+            "8f77de50253f8b438d3dbc2f43ea35451b905217873b2613532c99bb8f14b6",
+        ),
+    },
+    Vector {
+        version: Version::V07,
+        seed: Seed::U64,
+        fill_len: 68,
+        output: concat!(
+            "d0ba687d8d5648c94e8afadadac6357adebebd19e512e12ea1c4e7b742af5fe9",
+            "2c9c6ca69983ca8b0a2f33e7e00a95e2d06a29eb6357859d661724d051494823",
+            "7c96e861b09ce61591851c67c6cf1085e09635b913e1227f2042c7df537e8013",
+            "be5833a82c8c27d377ee54545989193d7c4e6f53d1f11ef13de02d542e7a65",
+        ),
+    },
+    Vector {
+        version: Version::V08,
+        seed: Seed::Bytes,
+        fill_len: 68,
+        output: concat!(
+            "70e2eafeca5e823b56cb62701da3cfc97ca246eb1517de5f4118069ad2c2fca9",
+            "a8ab8d60f3b71b885778ee514453da074a8164b1c7579e1f3b2eb3dd832a568e",
+            "50ef389993a25a0d21265ccc781a5c270a9920463642b3bb047c7f52418e60e8",
+            "2e984db6e0023e8ede91fd58e570365f43f1d08a63b55ffbb292ea825d58bf",
+        ),
+    },
+    Vector {
+        version: Version::V08,
+        seed: Seed::U64,
+        fill_len: 68,
+        output: concat!(
+            "6ffbe323fb54c934d84ad842a46b806a2da462a30f198b70852fcc96a8472127",
+            "276199f1918b7ea7fcacae1a8e44186ee23637c645e178f1a22140a357bd21f1",
+            "76744d97d10f7a4fe515270378a62cdd812c5c916103ab1451842368117fcd30",
+            "6333086cbcb647ca133b8d93a0c525b69838933c47ddf7bd0c4c31f14efa4d",
+        ),
+    },
+    Vector {
+        version: Version::V09,
+        seed: Seed::Bytes,
+        fill_len: 68,
+        output: concat!(
+            "70e2eafeca5e823b56cb62701da3cfc97ca246eb1517de5f4118069ad2c2fca9",
+            "a8ab8d60f3b71b885778ee514453da074a8164b1c7579e1f3b2eb3dd832a568e",
+            "50ef389993a25a0d21265ccc781a5c270a9920463642b3bb047c7f52418e60e8",
+            "2e984db6e0023e8ede91fd58e570365f43f1d08a63b55ffbb292ea825d58bf",
+        ),
+    },
+    Vector {
+        version: Version::V09,
+        seed: Seed::U64,
+        fill_len: 68,
+        output: concat!(
+            "10a3f2b2466240b5a9a4ac87242e3df142dbafb7c7d0504f4e8ca6c3ae8505b4",
+            "c200de5b8a8ef174d92d7eb2eaa4df8dc2d54d59d342b68adaa6bf3d4c83268b",
+            "ca1edc1e9cc4f29ef2eeddcfbbc870fedc559f595f81ecadc73b69485d810c25",
+            "9790510a05698b79f71285e9e41c6729396c4fa43151c8136bf332f414e2ce",
+        ),
+    },
+    Vector {
+        version: Version::V010,
+        seed: Seed::Bytes,
+        fill_len: 68,
+        output: concat!(
+            "70e2eafeca5e823b56cb62701da3cfc97ca246eb1517de5f4118069ad2c2fca9",
+            "a8ab8d60f3b71b885778ee514453da074a8164b1c7579e1f3b2eb3dd832a568e",
+            "50ef38d3dd08a10d21265ccc781a5c270a9920463642b3bb047c7f52418e60e8",
+            "2e984db6e0023e8ede91fd58e570365f43f1d08a63b55ffbb292ea825d58bf",
+        ),
+    },
+    Vector {
+        version: Version::V010,
+        seed: Seed::U64,
+        fill_len: 68,
+        output: concat!(
+            "10a3f2b2466240b5a9a4ac87242e3df142dbafb7c7d0504f4e8ca6c3ae8505b4",
+            "c200de5b8a8ef174d92d7eb2eaa4df8dc2d54d59d342b68adaa6bf3d4c83268b",
+            "ca1edc4462c9569ef2eeddcfbbc870fedc559f595f81ecadc73b69485d810c25",
+            "9790510a05698b79f71285e9e41c6729396c4fa43151c8136bf332f414e2ce",
+        ),
+    },
+];
+
+/// The RNG methods, implemented using the traits of one version.
+trait Generator {
+    fn next_u32(&mut self) -> u32;
+    fn next_u64(&mut self) -> u64;
+    fn fill_bytes(&mut self, dst: &mut [u8]);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn record(rng: &mut dyn Generator, fill_len: usize) -> String {
+    let mut output = Vec::new();
+    for _ in 0..5 {
+        output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    }
+    for _ in 0..3 {
+        output.extend_from_slice(&rng.next_u64().to_le_bytes());
+    }
+    let mut bytes = [0; 7];
+    rng.fill_bytes(&mut bytes);
+    output.extend_from_slice(&bytes);
+    output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    let mut bytes = vec![0; fill_len];
+    rng.fill_bytes(&mut bytes);
+    output.extend_from_slice(&bytes[fill_len - 16..]);
+    for _ in 0..4 {
+        output.extend_from_slice(&rng.next_u64().to_le_bytes());
+    }
+    output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    output.extend_from_slice(&rng.next_u64().to_le_bytes());
+    for _ in 0..3 {
+        output.extend_from_slice(&rng.next_u32().to_le_bytes());
+    }
+    hex(&output)
+}
+
+fn check(new: impl Fn(Version, Seed) -> Box<dyn Generator>) {
+    for (i, v) in VECTORS.iter().enumerate() {
+        let mut rng = new(v.version, v.seed);
+        assert_eq!(record(&mut *rng, v.fill_len), v.output, "vector {}", i);
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+    use rand_core_0_5::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: RngCore> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|version, seed| match (version, seed) {
+        (Version::V07, Seed::Bytes) => {
+            Box::new(Wrap(SmallRng07::from_seed(SEED[..16].try_into().unwrap())))
+        }
+        (Version::V07, Seed::U64) => Box::new(Wrap(SmallRng07::seed_from_u64(STATE))),
+        (Version::V08, Seed::Bytes) => Box::new(Wrap(SmallRng08::from_seed(SEED))),
+        (Version::V08, Seed::U64) => Box::new(Wrap(SmallRng08::seed_from_u64(STATE))),
+        (Version::V09, Seed::Bytes) => Box::new(Wrap(SmallRng09::from_seed(SEED))),
+        (Version::V09, Seed::U64) => Box::new(Wrap(SmallRng09::seed_from_u64(STATE))),
+        (Version::V010, Seed::Bytes) => Box::new(Wrap(SmallRng010::from_seed(SEED))),
+        (Version::V010, Seed::U64) => Box::new(Wrap(SmallRng010::seed_from_u64(STATE))),
+    });
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+    use rand_core_0_6::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: RngCore> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|version, seed| match (version, seed) {
+        (Version::V07, Seed::Bytes) => {
+            Box::new(Wrap(SmallRng07::from_seed(SEED[..16].try_into().unwrap())))
+        }
+        (Version::V07, Seed::U64) => Box::new(Wrap(SmallRng07::seed_from_u64(STATE))),
+        (Version::V08, Seed::Bytes) => Box::new(Wrap(SmallRng08::from_seed(SEED))),
+        (Version::V08, Seed::U64) => Box::new(Wrap(SmallRng08::seed_from_u64(STATE))),
+        (Version::V09, Seed::Bytes) => Box::new(Wrap(SmallRng09::from_seed(SEED))),
+        (Version::V09, Seed::U64) => Box::new(Wrap(SmallRng09::seed_from_u64(STATE))),
+        (Version::V010, Seed::Bytes) => Box::new(Wrap(SmallRng010::from_seed(SEED))),
+        (Version::V010, Seed::U64) => Box::new(Wrap(SmallRng010::seed_from_u64(STATE))),
+    });
+}
+
+#[cfg(feature = "rand_core_0_9")]
+#[test]
+fn rand_core_0_9() {
+    use rand_core_0_9::RngCore;
+    use rand_core_0_9::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: RngCore> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|version, seed| match (version, seed) {
+        (Version::V07, Seed::Bytes) => {
+            Box::new(Wrap(SmallRng07::from_seed(SEED[..16].try_into().unwrap())))
+        }
+        (Version::V07, Seed::U64) => Box::new(Wrap(SmallRng07::seed_from_u64(STATE))),
+        (Version::V08, Seed::Bytes) => Box::new(Wrap(SmallRng08::from_seed(SEED))),
+        (Version::V08, Seed::U64) => Box::new(Wrap(SmallRng08::seed_from_u64(STATE))),
+        (Version::V09, Seed::Bytes) => Box::new(Wrap(SmallRng09::from_seed(SEED))),
+        (Version::V09, Seed::U64) => Box::new(Wrap(SmallRng09::seed_from_u64(STATE))),
+        (Version::V010, Seed::Bytes) => Box::new(Wrap(SmallRng010::from_seed(SEED))),
+        (Version::V010, Seed::U64) => Box::new(Wrap(SmallRng010::seed_from_u64(STATE))),
+    });
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::Rng;
+    use rand_core_0_10::SeedableRng;
+
+    struct Wrap<R>(R);
+    impl<R: Rng> Generator for Wrap<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst)
+        }
+    }
+
+    check(|version, seed| match (version, seed) {
+        (Version::V07, Seed::Bytes) => {
+            Box::new(Wrap(SmallRng07::from_seed(SEED[..16].try_into().unwrap())))
+        }
+        (Version::V07, Seed::U64) => Box::new(Wrap(SmallRng07::seed_from_u64(STATE))),
+        (Version::V08, Seed::Bytes) => Box::new(Wrap(SmallRng08::from_seed(SEED))),
+        (Version::V08, Seed::U64) => Box::new(Wrap(SmallRng08::seed_from_u64(STATE))),
+        (Version::V09, Seed::Bytes) => Box::new(Wrap(SmallRng09::from_seed(SEED))),
+        (Version::V09, Seed::U64) => Box::new(Wrap(SmallRng09::seed_from_u64(STATE))),
+        (Version::V010, Seed::Bytes) => Box::new(Wrap(SmallRng010::from_seed(SEED))),
+        (Version::V010, Seed::U64) => Box::new(Wrap(SmallRng010::seed_from_u64(STATE))),
+    });
+}
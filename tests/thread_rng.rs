@@ -0,0 +1,142 @@
+//! Tests of `thread_rng`.
+//!
+//! The output is random, so the tests only check that outputs don't repeat.
+#![cfg(all(
+    feature = "thread_rng",
+    any(
+        feature = "rand_core_0_5",
+        feature = "rand_core_0_6",
+        feature = "rand_core_0_9",
+        feature = "rand_core_0_10"
+    )
+))]
+
+use rand_core_compat::ThreadRng;
+use rand_core_compat::thread_rng;
+
+/// Fill `dst` using the `rand_core 0.10`/`rand 0.10` traits if selected, else
+/// the `rand_core 0.9`/`rand 0.9` ones, and so on.
+fn fill(rng: &mut ThreadRng, dst: &mut [u8]) {
+    #[cfg(feature = "rand_core_0_10")]
+    rand_core_0_10::TryRng::try_fill_bytes(rng, dst).unwrap();
+    #[cfg(all(feature = "rand_core_0_9", not(feature = "rand_core_0_10")))]
+    rand_core_0_9::TryRngCore::try_fill_bytes(rng, dst).unwrap();
+    #[cfg(all(
+        feature = "rand_core_0_6",
+        not(any(feature = "rand_core_0_9", feature = "rand_core_0_10"))
+    ))]
+    rand_core_0_6::RngCore::fill_bytes(rng, dst);
+    #[cfg(all(
+        feature = "rand_core_0_5",
+        not(any(
+            feature = "rand_core_0_6",
+            feature = "rand_core_0_9",
+            feature = "rand_core_0_10"
+        ))
+    ))]
+    rand_core_0_5::RngCore::fill_bytes(rng, dst);
+}
+
+fn draw(rng: &mut ThreadRng) -> [u8; 32] {
+    let mut dst = [0; 32];
+    fill(rng, &mut dst);
+    dst
+}
+
+#[test]
+fn handles() {
+    // Handles of the same thread share the stream.
+    let a = draw(&mut thread_rng());
+    let b = draw(&mut thread_rng());
+    let mut rng = thread_rng();
+    let c = draw(&mut rng.clone());
+    let d = draw(&mut rng);
+    assert_ne!(a, [0; 32]);
+    for (i, x) in [a, b, c, d].iter().enumerate() {
+        for y in &[a, b, c, d][i + 1..] {
+            assert_ne!(x, y);
+        }
+    }
+}
+
+#[test]
+fn threads() {
+    let mut outputs: Vec<[u8; 32]> = (0..8)
+        .map(|_| std::thread::spawn(|| draw(&mut thread_rng())))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|thread| thread.join().unwrap())
+        .collect();
+    outputs.push(draw(&mut thread_rng()));
+    outputs.sort_unstable();
+    outputs.dedup();
+    assert_eq!(outputs.len(), 9);
+}
+
+#[test]
+fn reseed() {
+    // Output continues past the reseed threshold of 64 KiB.
+    let mut rng = thread_rng();
+    let mut dst = vec![0; 100 * 1024];
+    for _ in 0..3 {
+        let previous = dst.clone();
+        fill(&mut rng, &mut dst);
+        assert_ne!(dst, previous);
+    }
+}
+
+#[cfg(feature = "rand_core_0_5")]
+#[test]
+fn rand_core_0_5() {
+    use rand_core_0_5::RngCore;
+
+    let mut rng = thread_rng();
+    assert_ne!(rng.next_u64(), rng.next_u64());
+    let mut a = [0; 16];
+    let mut b = [0; 16];
+    rng.fill_bytes(&mut a);
+    rng.try_fill_bytes(&mut b).unwrap();
+    assert_ne!(a, b);
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn rand_core_0_6() {
+    use rand_core_0_6::RngCore;
+
+    let mut rng = thread_rng();
+    assert_ne!(rng.next_u64(), rng.next_u64());
+    let mut a = [0; 16];
+    let mut b = [0; 16];
+    rng.fill_bytes(&mut a);
+    rng.try_fill_bytes(&mut b).unwrap();
+    assert_ne!(a, b);
+}
+
+#[cfg(feature = "rand_core_0_9")]
+#[test]
+fn rand_core_0_9() {
+    use rand_core_0_9::TryRngCore;
+
+    let mut rng = thread_rng();
+    assert_ne!(rng.try_next_u64().unwrap(), rng.try_next_u64().unwrap());
+    let mut a = [0; 16];
+    let mut b = [0; 16];
+    rng.try_fill_bytes(&mut a).unwrap();
+    rng.try_fill_bytes(&mut b).unwrap();
+    assert_ne!(a, b);
+}
+
+#[cfg(feature = "rand_core_0_10")]
+#[test]
+fn rand_core_0_10() {
+    use rand_core_0_10::TryRng;
+
+    let mut rng = thread_rng();
+    assert_ne!(rng.try_next_u64().unwrap(), rng.try_next_u64().unwrap());
+    let mut a = [0; 16];
+    let mut b = [0; 16];
+    rng.try_fill_bytes(&mut a).unwrap();
+    rng.try_fill_bytes(&mut b).unwrap();
+    assert_ne!(a, b);
+}
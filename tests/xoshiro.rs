@@ -0,0 +1,441 @@
+//! Tests of moving the state of a xoshiro RNG between `rand_xoshiro` versions.
+//!
+//! Each RNG generates some output before moving its state to the next
+//! selected version, which must continue with the same output. The `jump` and
+//! `long_jump` of the states are checked against the ones of `rand_xoshiro`.
+#![cfg(any(
+    feature = "rand_xoshiro_0_4",
+    feature = "rand_xoshiro_0_6",
+    feature = "rand_xoshiro_0_7",
+    feature = "rand_xoshiro_0_8",
+))]
+
+use core::fmt::Debug;
+use rand_core_compat::Xoshiro128State;
+use rand_core_compat::Xoshiro256State;
+
+const SEED: [u8; 32] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe,
+    0x2a, 0x3b, 0x4c, 0x5d, 0x6e, 0x7f, 0x80, 0x91, 0xa2, 0xb3, 0xc4, 0xd5, 0xe6, 0xf7, 0x08, 0x19,
+];
+
+/// Number of outputs generated before moving the state.
+const SKIP: usize = 7;
+
+/// Number of outputs compared after moving the state.
+const LEN: usize = 20;
+
+/// xoshiro RNG of one `rand_xoshiro` version.
+trait Generator<S> {
+    fn next_u64(&mut self) -> u64;
+    fn state(&self) -> S;
+}
+
+/// Restore an RNG of one `rand_xoshiro` version from the state `S`.
+type Restore<S> = fn(S) -> Box<dyn Generator<S>>;
+
+#[cfg(feature = "rand_xoshiro_0_4")]
+struct Wrap05<R>(R);
+
+#[cfg(feature = "rand_xoshiro_0_4")]
+impl<S, R> Generator<S> for Wrap05<R>
+where
+    R: rand_core_0_5::RngCore,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_4")]
+fn restore05<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_5::RngCore + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap05(R::from(state)))
+}
+
+#[cfg(feature = "rand_xoshiro_0_6")]
+struct Wrap06<R>(R);
+
+#[cfg(feature = "rand_xoshiro_0_6")]
+impl<S, R> Generator<S> for Wrap06<R>
+where
+    R: rand_core_0_6::RngCore,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_6")]
+fn restore06<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_6::RngCore + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap06(R::from(state)))
+}
+
+#[cfg(feature = "rand_xoshiro_0_7")]
+struct Wrap09<R>(R);
+
+#[cfg(feature = "rand_xoshiro_0_7")]
+impl<S, R> Generator<S> for Wrap09<R>
+where
+    R: rand_core_0_9::RngCore,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_7")]
+fn restore09<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_9::RngCore + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap09(R::from(state)))
+}
+
+#[cfg(feature = "rand_xoshiro_0_8")]
+struct Wrap010<R>(R);
+
+#[cfg(feature = "rand_xoshiro_0_8")]
+impl<S, R> Generator<S> for Wrap010<R>
+where
+    R: rand_core_0_10::Rng,
+    for<'a> S: From<&'a R>,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn state(&self) -> S {
+        S::from(&self.0)
+    }
+}
+
+#[cfg(feature = "rand_xoshiro_0_8")]
+fn restore010<S, R>(state: S) -> Box<dyn Generator<S>>
+where
+    R: rand_core_0_10::Rng + From<S> + 'static,
+    for<'a> S: From<&'a R>,
+{
+    Box::new(Wrap010(R::from(state)))
+}
+
+/// Start with the first of `versions` at `start` and move the state to each
+/// following version after generating some output, checking that it continues
+/// with the output of the previous version.
+fn check<S: Clone + Debug + PartialEq>(start: S, versions: &[(&str, Restore<S>)]) {
+    let (mut name, restore) = versions[0];
+    let mut rng = restore(start);
+    for _ in 0..SKIP {
+        rng.next_u64();
+    }
+    for &(next_name, restore) in &versions[1..] {
+        let mut next = restore(rng.state());
+        assert_eq!(next.state(), rng.state(), "{name} to {next_name}");
+        let expected: Vec<u64> = (0..LEN).map(|_| rng.next_u64()).collect();
+        let output: Vec<u64> = (0..LEN).map(|_| next.next_u64()).collect();
+        assert_eq!(output, expected, "{name} to {next_name}");
+        assert_eq!(next.state(), rng.state(), "{name} to {next_name}");
+        for _ in 0..SKIP {
+            next.next_u64();
+        }
+        (name, rng) = (next_name, next);
+    }
+}
+
+/// Check that `jump` of `rng` and `state_jump` of its state agree.
+fn check_jump<S, R>(mut rng: R, jump: fn(&mut R), state_jump: fn(&mut S))
+where
+    S: Debug + PartialEq,
+    for<'a> S: From<&'a R>,
+{
+    let mut state = S::from(&rng);
+    for _ in 0..3 {
+        jump(&mut rng);
+        state_jump(&mut state);
+        assert_eq!(S::from(&rng), state);
+    }
+}
+
+fn state256() -> Xoshiro256State {
+    let s = SEED
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+    let mut words = [0; 4];
+    for (word, s) in words.iter_mut().zip(s) {
+        *word = s;
+    }
+    Xoshiro256State::new(words)
+}
+
+fn state128() -> Xoshiro128State {
+    let s = SEED
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()));
+    let mut words = [0; 4];
+    for (word, s) in words.iter_mut().zip(s) {
+        *word = s;
+    }
+    Xoshiro128State::new(words)
+}
+
+#[test]
+fn xoshiro128_plus() {
+    let versions: &[(&str, Restore<Xoshiro128State>)] = &[
+        #[cfg(feature = "rand_xoshiro_0_4")]
+        ("0.4", restore05::<_, rand_xoshiro_0_4::Xoshiro128Plus>),
+        #[cfg(feature = "rand_xoshiro_0_6")]
+        ("0.6", restore06::<_, rand_xoshiro_0_6::Xoshiro128Plus>),
+        #[cfg(feature = "rand_xoshiro_0_7")]
+        ("0.7", restore09::<_, rand_xoshiro_0_7::Xoshiro128Plus>),
+        #[cfg(feature = "rand_xoshiro_0_8")]
+        ("0.8", restore010::<_, rand_xoshiro_0_8::Xoshiro128Plus>),
+    ];
+    check(state128(), versions);
+}
+
+#[test]
+fn xoshiro128_plus_plus() {
+    let versions: &[(&str, Restore<Xoshiro128State>)] = &[
+        #[cfg(feature = "rand_xoshiro_0_4")]
+        ("0.4", restore05::<_, rand_xoshiro_0_4::Xoshiro128PlusPlus>),
+        #[cfg(feature = "rand_xoshiro_0_6")]
+        ("0.6", restore06::<_, rand_xoshiro_0_6::Xoshiro128PlusPlus>),
+        #[cfg(feature = "rand_xoshiro_0_7")]
+        ("0.7", restore09::<_, rand_xoshiro_0_7::Xoshiro128PlusPlus>),
+        #[cfg(feature = "rand_xoshiro_0_8")]
+        ("0.8", restore010::<_, rand_xoshiro_0_8::Xoshiro128PlusPlus>),
+    ];
+    check(state128(), versions);
+}
+
+#[test]
+fn xoshiro128_star_star() {
+    let versions: &[(&str, Restore<Xoshiro128State>)] = &[
+        #[cfg(feature = "rand_xoshiro_0_4")]
+        ("0.4", restore05::<_, rand_xoshiro_0_4::Xoshiro128StarStar>),
+        #[cfg(feature = "rand_xoshiro_0_6")]
+        ("0.6", restore06::<_, rand_xoshiro_0_6::Xoshiro128StarStar>),
+        #[cfg(feature = "rand_xoshiro_0_7")]
+        ("0.7", restore09::<_, rand_xoshiro_0_7::Xoshiro128StarStar>),
+        #[cfg(feature = "rand_xoshiro_0_8")]
+        ("0.8", restore010::<_, rand_xoshiro_0_8::Xoshiro128StarStar>),
+    ];
+    check(state128(), versions);
+}
+
+#[test]
+fn xoshiro256_plus() {
+    let versions: &[(&str, Restore<Xoshiro256State>)] = &[
+        #[cfg(feature = "rand_xoshiro_0_4")]
+        ("0.4", restore05::<_, rand_xoshiro_0_4::Xoshiro256Plus>),
+        #[cfg(feature = "rand_xoshiro_0_6")]
+        ("0.6", restore06::<_, rand_xoshiro_0_6::Xoshiro256Plus>),
+        #[cfg(feature = "rand_xoshiro_0_7")]
+        ("0.7", restore09::<_, rand_xoshiro_0_7::Xoshiro256Plus>),
+        #[cfg(feature = "rand_xoshiro_0_8")]
+        ("0.8", restore010::<_, rand_xoshiro_0_8::Xoshiro256Plus>),
+    ];
+    check(state256(), versions);
+}
+
+#[test]
+fn xoshiro256_plus_plus() {
+    let versions: &[(&str, Restore<Xoshiro256State>)] = &[
+        #[cfg(feature = "rand_xoshiro_0_4")]
+        ("0.4", restore05::<_, rand_xoshiro_0_4::Xoshiro256PlusPlus>),
+        #[cfg(feature = "rand_xoshiro_0_6")]
+        ("0.6", restore06::<_, rand_xoshiro_0_6::Xoshiro256PlusPlus>),
+        #[cfg(feature = "rand_xoshiro_0_7")]
+        ("0.7", restore09::<_, rand_xoshiro_0_7::Xoshiro256PlusPlus>),
+        #[cfg(feature = "rand_xoshiro_0_8")]
+        ("0.8", restore010::<_, rand_xoshiro_0_8::Xoshiro256PlusPlus>),
+    ];
+    check(state256(), versions);
+}
+
+#[test]
+fn xoshiro256_star_star() {
+    let versions: &[(&str, Restore<Xoshiro256State>)] = &[
+        #[cfg(feature = "rand_xoshiro_0_4")]
+        ("0.4", restore05::<_, rand_xoshiro_0_4::Xoshiro256StarStar>),
+        #[cfg(feature = "rand_xoshiro_0_6")]
+        ("0.6", restore06::<_, rand_xoshiro_0_6::Xoshiro256StarStar>),
+        #[cfg(feature = "rand_xoshiro_0_7")]
+        ("0.7", restore09::<_, rand_xoshiro_0_7::Xoshiro256StarStar>),
+        #[cfg(feature = "rand_xoshiro_0_8")]
+        ("0.8", restore010::<_, rand_xoshiro_0_8::Xoshiro256StarStar>),
+    ];
+    check(state256(), versions);
+}
+
+#[cfg(feature = "rand_xoshiro_0_4")]
+#[test]
+fn rand_xoshiro_0_4() {
+    use rand_core_0_5::SeedableRng;
+    use rand_xoshiro_0_4::Xoshiro128Plus;
+    use rand_xoshiro_0_4::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_4::Xoshiro128StarStar;
+    use rand_xoshiro_0_4::Xoshiro256Plus;
+    use rand_xoshiro_0_4::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_4::Xoshiro256StarStar;
+
+    let seed128 = SEED[..16].try_into().unwrap();
+    let jump = Xoshiro128State::jump;
+    let long_jump = Xoshiro128State::long_jump;
+    check_jump(
+        Xoshiro128Plus::from_seed(seed128),
+        Xoshiro128Plus::jump,
+        jump,
+    );
+    let rng = Xoshiro128PlusPlus::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro128PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro128StarStar::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128StarStar::jump, jump);
+    check_jump(rng, Xoshiro128StarStar::long_jump, long_jump);
+
+    let jump = Xoshiro256State::jump;
+    let long_jump = Xoshiro256State::long_jump;
+    let rng = Xoshiro256Plus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256Plus::jump, jump);
+    check_jump(rng, Xoshiro256Plus::long_jump, long_jump);
+    let rng = Xoshiro256PlusPlus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro256PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro256StarStar::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256StarStar::jump, jump);
+    check_jump(rng, Xoshiro256StarStar::long_jump, long_jump);
+}
+
+#[cfg(feature = "rand_xoshiro_0_6")]
+#[test]
+fn rand_xoshiro_0_6() {
+    use rand_core_0_6::SeedableRng;
+    use rand_xoshiro_0_6::Xoshiro128Plus;
+    use rand_xoshiro_0_6::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_6::Xoshiro128StarStar;
+    use rand_xoshiro_0_6::Xoshiro256Plus;
+    use rand_xoshiro_0_6::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_6::Xoshiro256StarStar;
+
+    let seed128 = SEED[..16].try_into().unwrap();
+    let jump = Xoshiro128State::jump;
+    let long_jump = Xoshiro128State::long_jump;
+    check_jump(
+        Xoshiro128Plus::from_seed(seed128),
+        Xoshiro128Plus::jump,
+        jump,
+    );
+    let rng = Xoshiro128PlusPlus::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro128PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro128StarStar::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128StarStar::jump, jump);
+    check_jump(rng, Xoshiro128StarStar::long_jump, long_jump);
+
+    let jump = Xoshiro256State::jump;
+    let long_jump = Xoshiro256State::long_jump;
+    let rng = Xoshiro256Plus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256Plus::jump, jump);
+    check_jump(rng, Xoshiro256Plus::long_jump, long_jump);
+    let rng = Xoshiro256PlusPlus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro256PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro256StarStar::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256StarStar::jump, jump);
+    check_jump(rng, Xoshiro256StarStar::long_jump, long_jump);
+}
+
+#[cfg(feature = "rand_xoshiro_0_7")]
+#[test]
+fn rand_xoshiro_0_7() {
+    use rand_core_0_9::SeedableRng;
+    use rand_xoshiro_0_7::Xoshiro128Plus;
+    use rand_xoshiro_0_7::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_7::Xoshiro128StarStar;
+    use rand_xoshiro_0_7::Xoshiro256Plus;
+    use rand_xoshiro_0_7::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_7::Xoshiro256StarStar;
+
+    let seed128 = SEED[..16].try_into().unwrap();
+    let jump = Xoshiro128State::jump;
+    let long_jump = Xoshiro128State::long_jump;
+    // Unlike the previous versions, 0.7 has `long_jump` for `Xoshiro128Plus`.
+    let rng = Xoshiro128Plus::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128Plus::jump, jump);
+    check_jump(rng, Xoshiro128Plus::long_jump, long_jump);
+    let rng = Xoshiro128PlusPlus::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro128PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro128StarStar::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128StarStar::jump, jump);
+    check_jump(rng, Xoshiro128StarStar::long_jump, long_jump);
+
+    let jump = Xoshiro256State::jump;
+    let long_jump = Xoshiro256State::long_jump;
+    let rng = Xoshiro256Plus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256Plus::jump, jump);
+    check_jump(rng, Xoshiro256Plus::long_jump, long_jump);
+    let rng = Xoshiro256PlusPlus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro256PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro256StarStar::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256StarStar::jump, jump);
+    check_jump(rng, Xoshiro256StarStar::long_jump, long_jump);
+}
+
+#[cfg(feature = "rand_xoshiro_0_8")]
+#[test]
+fn rand_xoshiro_0_8() {
+    use rand_core_0_10::SeedableRng;
+    use rand_xoshiro_0_8::Xoshiro128Plus;
+    use rand_xoshiro_0_8::Xoshiro128PlusPlus;
+    use rand_xoshiro_0_8::Xoshiro128StarStar;
+    use rand_xoshiro_0_8::Xoshiro256Plus;
+    use rand_xoshiro_0_8::Xoshiro256PlusPlus;
+    use rand_xoshiro_0_8::Xoshiro256StarStar;
+
+    let seed128 = SEED[..16].try_into().unwrap();
+    let jump = Xoshiro128State::jump;
+    let long_jump = Xoshiro128State::long_jump;
+    let rng = Xoshiro128Plus::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128Plus::jump, jump);
+    check_jump(rng, Xoshiro128Plus::long_jump, long_jump);
+    let rng = Xoshiro128PlusPlus::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro128PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro128StarStar::from_seed(seed128);
+    check_jump(rng.clone(), Xoshiro128StarStar::jump, jump);
+    check_jump(rng, Xoshiro128StarStar::long_jump, long_jump);
+
+    let jump = Xoshiro256State::jump;
+    let long_jump = Xoshiro256State::long_jump;
+    let rng = Xoshiro256Plus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256Plus::jump, jump);
+    check_jump(rng, Xoshiro256Plus::long_jump, long_jump);
+    let rng = Xoshiro256PlusPlus::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256PlusPlus::jump, jump);
+    check_jump(rng, Xoshiro256PlusPlus::long_jump, long_jump);
+    let rng = Xoshiro256StarStar::from_seed(SEED);
+    check_jump(rng.clone(), Xoshiro256StarStar::jump, jump);
+    check_jump(rng, Xoshiro256StarStar::long_jump, long_jump);
+}
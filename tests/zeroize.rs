@@ -0,0 +1,156 @@
+//! Tests of zeroizing the crypto RNGs of this crate.
+//!
+//! A zeroized RNG must have lost its secret state, so it generates the same
+//! output as one created from an all-zero key or from different secrets.
+#![cfg(all(feature = "zeroize", feature = "rand_core_0_9"))]
+
+use rand_core_0_9::RngCore;
+use rand_core_0_9::SeedableRng;
+use rand_core_compat::Redacted;
+use rand_core_compat::Rng09;
+use rand_core_compat::StdRng07;
+use rand_core_compat::StdRng08;
+use rand_core_compat::StdRng09;
+use rand_core_compat::StdRng010;
+use zeroize::Zeroize;
+use zeroize::ZeroizeOnDrop;
+
+const SEED: [u8; 32] = [0xa5; 32];
+
+fn assert_zeroize_on_drop<R: ZeroizeOnDrop>(_rng: &R) {}
+
+/// Return the next 100 bytes of output.
+fn output(rng: &mut impl RngCore) -> [u8; 100] {
+    let mut dst = [0; 100];
+    rng.fill_bytes(&mut dst);
+    dst
+}
+
+#[test]
+fn std_rng() {
+    fn check<R: RngCore + SeedableRng<Seed = [u8; 32]> + Zeroize + ZeroizeOnDrop>() {
+        let mut rng = R::from_seed(SEED);
+        assert_zeroize_on_drop(&rng);
+        rng.next_u32();
+        rng.zeroize();
+        assert_eq!(output(&mut rng), output(&mut R::from_seed([0; 32])));
+    }
+
+    check::<StdRng07>();
+    check::<StdRng08>();
+    check::<StdRng09>();
+    check::<StdRng010>();
+}
+
+#[test]
+fn wrapped() {
+    let mut rng = Rng09(StdRng09::from_seed(SEED));
+    assert_zeroize_on_drop(&rng);
+    rng.next_u32();
+    rng.zeroize();
+    assert_eq!(output(&mut rng), output(&mut StdRng09::from_seed([0; 32])));
+
+    let mut rng = Redacted(Rng09(StdRng09::from_seed(SEED)));
+    assert_zeroize_on_drop(&rng);
+    rng.next_u32();
+    rng.zeroize();
+    assert_eq!(output(&mut rng), output(&mut StdRng09::from_seed([0; 32])));
+}
+
+#[cfg(feature = "rand_core_0_6")]
+#[test]
+fn wrapped_rand_core_0_6() {
+    use rand_core_compat::Rng06;
+
+    let mut rng = Redacted(Rng06(Rng09(StdRng09::from_seed(SEED))));
+    assert_zeroize_on_drop(&rng);
+    rand_core_0_6::RngCore::next_u32(&mut rng);
+    rng.zeroize();
+    assert_eq!(
+        output(&mut rng.0.0),
+        output(&mut StdRng09::from_seed([0; 32]))
+    );
+}
+
+#[cfg(feature = "drbg")]
+#[test]
+fn drbg() {
+    use rand_core_0_9::TryRngCore;
+    use rand_core_compat::drbg::Drbg;
+    use rand_core_compat::drbg::Mechanism;
+    use rand_core_compat::drbg::Seed;
+
+    fn check<M: Mechanism + Zeroize + ZeroizeOnDrop>() {
+        let mut a = Drbg::<M>::new(&[1; 32], &[2; 16], b"a");
+        let mut b = Drbg::<M>::new(&[3; 32], &[4; 16], b"b");
+        assert_zeroize_on_drop(&a);
+        let mut dst = [0; 64];
+        a.try_fill_bytes(&mut dst).unwrap();
+        b.try_fill_bytes(&mut dst).unwrap();
+        a.zeroize();
+        b.zeroize();
+        let mut dst_a = [0; 64];
+        let mut dst_b = [0; 64];
+        a.try_fill_bytes(&mut dst_a).unwrap();
+        b.try_fill_bytes(&mut dst_b).unwrap();
+        assert_eq!(dst_a, dst_b);
+    }
+
+    check::<rand_core_compat::drbg::CtrAes256>();
+    check::<rand_core_compat::drbg::HashSha256>();
+    check::<rand_core_compat::drbg::HmacSha256>();
+
+    let mut seed = Seed([0xa5; 48]);
+    assert_zeroize_on_drop(&seed);
+    seed.zeroize();
+    assert_eq!(seed.0, [0; 48]);
+}
+
+#[cfg(feature = "fortuna")]
+#[test]
+fn fortuna() {
+    use rand_core_0_9::TryRngCore;
+    use rand_core_compat::Fortuna;
+
+    let mut rng = Fortuna::new();
+    assert_zeroize_on_drop(&rng);
+    // Each source cycles through the 32 pools, so this fills the first one.
+    for _ in 0..64 {
+        rng.add_entropy(0, &[0xa5; 32]);
+    }
+    rng.try_next_u32().unwrap();
+    assert!(rng.is_seeded());
+    rng.zeroize();
+    assert!(!rng.is_seeded());
+    assert_eq!(rng.reseed_count(), 0);
+    assert!(rng.try_next_u32().is_err());
+}
+
+#[cfg(feature = "derivation")]
+#[test]
+fn derivation_key() {
+    use rand_core_compat::DerivationKey;
+
+    let mut key = DerivationKey::new(SEED);
+    assert_zeroize_on_drop(&key);
+    key.zeroize();
+    assert_eq!(key, DerivationKey::new([0; 32]));
+}
+
+#[cfg(feature = "conditioning")]
+#[test]
+fn conditioned() {
+    use rand_core_0_9::TryRngCore;
+    use rand_core_compat::Conditioned;
+
+    let mut rng = Conditioned::new(Rng09(StdRng09::from_seed(SEED)), 64);
+    assert_zeroize_on_drop(&rng);
+    rng.try_next_u32().unwrap();
+    rng.zeroize();
+    let mut expected = Conditioned::new(Rng09(StdRng09::from_seed([0; 32])), 64);
+    let mut dst = [0; 100];
+    let mut expected_dst = [0; 100];
+    rng.try_fill_bytes(&mut dst).unwrap();
+    expected.try_fill_bytes(&mut expected_dst).unwrap();
+    assert_eq!(dst, expected_dst);
+}